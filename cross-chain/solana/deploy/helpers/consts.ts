@@ -44,3 +44,5 @@ export const WRAPPED_TMEWC_MINT_TESTNET =
 // deriveWrappedMintKey("wormDTUJ6AWPNvk59vGQbDvGJmqbDTdgWgAqcLBCgUb", 2, "0x18084fbA666a33d37592fA2633fD49a74DD93a88")
 export const WRAPPED_TMEWC_MINT_MAINNET =
   "25rXTx9zDZcHyTav5sRqM6YBvTGu9pPH9yv83uAEqbgG"
+
+export const TMEWC_ASSET_ID = 0
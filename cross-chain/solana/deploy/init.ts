@@ -1,6 +1,6 @@
 import * as anchor from "@coral-xyz/anchor"
 import fs from "fs"
-import { PublicKey, Keypair } from "@solana/web3.js"
+import { PublicKey, Keypair, SystemProgram } from "@solana/web3.js"
 import dotenv from "dotenv"
 import { Program } from "@coral-xyz/anchor"
 import { Tmewc } from "../target/types/tmewc"
@@ -40,6 +40,16 @@ async function run(): Promise<void> {
     tmewcProgram.programId
   )[0]
 
+  const tmewcAuditLog = PublicKey.findProgramAddressSync(
+    [Buffer.from("audit-log")],
+    tmewcProgram.programId
+  )[0]
+
+  const tmewcEventAuthority = PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority")],
+    tmewcProgram.programId
+  )[0]
+
   const tmewcMetadata = PublicKey.findProgramAddressSync(
     [Buffer.from("metadata"), METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
     METADATA_PROGRAM_ID
@@ -49,15 +59,23 @@ async function run(): Promise<void> {
 
   // Initalize tmewc program
   await tmewcProgram.methods
-    .initialize()
+    .initialize({
+      assetId: consts.TMEWC_ASSET_ID,
+      name: "tMEWC",
+      symbol: "tMEWC",
+    })
     .accounts({
       mint,
       config,
       guardians,
       minters,
+      auditLog: tmewcAuditLog,
+      tmewcConfig: null,
       authority,
       tmewcMetadata,
       mplTokenMetadataProgram,
+      eventAuthority: tmewcEventAuthority,
+      program: tmewcProgram.programId,
     })
     .rpc()
 
@@ -83,6 +101,21 @@ async function run(): Promise<void> {
     wormholeGatewayProgram.programId
   )[0]
 
+  const gatewayAuditLog = PublicKey.findProgramAddressSync(
+    [Buffer.from("audit-log")],
+    wormholeGatewayProgram.programId
+  )[0]
+
+  const gatewayEventAuthority = PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority")],
+    wormholeGatewayProgram.programId
+  )[0]
+
+  const gatewayRegistry = PublicKey.findProgramAddressSync(
+    [Buffer.from("gateway-registry")],
+    wormholeGatewayProgram.programId
+  )[0]
+
   // NOTE: It might happen on mainnet that tmewc won't be initialized if running this
   // script in one shot.
   // The simplest solution is just to wait a bit and then proceed with wormhole_gateway
@@ -94,10 +127,14 @@ async function run(): Promise<void> {
     .accounts({
       authority,
       custodian: minter,
+      auditLog: gatewayAuditLog,
+      gatewayRegistry,
       tmewcMint: mint,
       wrappedTmewcMint: WRAPPED_TMEWC_MINT,
       wrappedTmewcToken: gatewayWrappedTmewcToken,
       tokenBridgeSender,
+      eventAuthority: gatewayEventAuthority,
+      program: wormholeGatewayProgram.programId,
     })
     .rpc()
 
@@ -113,10 +150,13 @@ async function run(): Promise<void> {
     .addMinter()
     .accounts({
       config,
+      auditLog: tmewcAuditLog,
       authority,
       minters,
       minterInfo,
       minter,
+      eventAuthority: tmewcEventAuthority,
+      program: tmewcProgram.programId,
     })
     .rpc()
 
@@ -153,8 +193,13 @@ async function run(): Promise<void> {
     .updateGatewayAddress(arbiArgs)
     .accounts({
       custodian: minter,
+      auditLog: gatewayAuditLog,
       gatewayInfo: gatewayArbiInfo,
+      gatewayRegistry,
       authority,
+      systemProgram: SystemProgram.programId,
+      eventAuthority: gatewayEventAuthority,
+      program: wormholeGatewayProgram.programId,
     })
     .rpc()
 
@@ -180,8 +225,13 @@ async function run(): Promise<void> {
     .updateGatewayAddress(optiArgs)
     .accounts({
       custodian: minter,
+      auditLog: gatewayAuditLog,
       gatewayInfo: gatewayOptiInfo,
+      gatewayRegistry,
       authority,
+      systemProgram: SystemProgram.programId,
+      eventAuthority: gatewayEventAuthority,
+      program: wormholeGatewayProgram.programId,
     })
     .rpc()
 
@@ -207,8 +257,13 @@ async function run(): Promise<void> {
     .updateGatewayAddress(polyArgs)
     .accounts({
       custodian: minter,
+      auditLog: gatewayAuditLog,
       gatewayInfo: gatewayPolyInfo,
+      gatewayRegistry,
       authority,
+      systemProgram: SystemProgram.programId,
+      eventAuthority: gatewayEventAuthority,
+      program: wormholeGatewayProgram.programId,
     })
     .rpc()
 
@@ -234,8 +289,13 @@ async function run(): Promise<void> {
     .updateGatewayAddress(baseArgs)
     .accounts({
       custodian: minter,
+      auditLog: gatewayAuditLog,
       gatewayInfo: gatewayBaseInfo,
+      gatewayRegistry,
       authority,
+      systemProgram: SystemProgram.programId,
+      eventAuthority: gatewayEventAuthority,
+      program: wormholeGatewayProgram.programId,
     })
     .rpc()
 
@@ -261,8 +321,13 @@ async function run(): Promise<void> {
     .updateGatewayAddress(solanaArgs)
     .accounts({
       custodian: minter,
+      auditLog: gatewayAuditLog,
       gatewayInfo: gatewaySolanaInfo,
+      gatewayRegistry,
       authority,
+      systemProgram: SystemProgram.programId,
+      eventAuthority: gatewayEventAuthority,
+      program: wormholeGatewayProgram.programId,
     })
     .rpc()
 
@@ -1,4 +1,59 @@
-use anchor_lang::prelude::constant;
+use anchor_lang::{prelude::constant, solana_program::pubkey, solana_program::pubkey::Pubkey};
 
 #[constant]
 pub const SEED_PREFIX_TMEWC_MINT: &[u8] = b"tmewc-mint";
+
+/// Seed of the system-owned PDA that signs admin instructions authorized by the Ethereum
+/// authority. Set it as `Config.authority` to hand admin control to an Ethereum address.
+#[constant]
+pub const SEED_PREFIX_ETH_AUTHORITY: &[u8] = b"eth-authority";
+
+/// Solana cluster this build targets. Off-chain signed messages commit to it, so that a signature
+/// meant for one cluster cannot be replayed on another where the program has the same ID.
+#[cfg(feature = "mainnet")]
+pub const CLUSTER: &[u8] = b"mainnet-beta";
+
+#[cfg(feature = "solana-devnet")]
+pub const CLUSTER: &[u8] = b"devnet";
+
+/// Prefix of the message the Ethereum authority signs.
+pub const ETH_AUTHORITY_DOMAIN: &[u8] = b"tmewc eth authority";
+
+/// Prefix Ethereum wallets put in front of a 32-byte message signed with `personal_sign` (EIP-191),
+/// so that the Ethereum authority can sign with any wallet.
+pub const ETH_SIGNED_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n32";
+
+/// Prefix of the pause message guardians sign off-chain for `pause_with_signature`.
+pub const PAUSE_SIGNATURE_DOMAIN: &[u8] = b"tmewc pause";
+
+/// Seed of the system-owned PDA that signs admin instructions carried by governance VAAs. Set it
+/// as `Config.authority` to hand admin control to the governance contract.
+#[constant]
+pub const SEED_PREFIX_GOVERNANCE_AUTHORITY: &[u8] = b"governance-authority";
+
+/// Wormhole chain ID of Ethereum, where the governance contract lives.
+pub const GOVERNANCE_CHAIN: u16 = 2;
+
+/// Wormhole chain ID of Solana, which governance messages have to target.
+pub const SOLANA_CHAIN: u16 = 1;
+
+/// Module of governance messages for this program, "TMEWC" left-padded to 32 bytes.
+pub const GOVERNANCE_MODULE: [u8; 32] =
+    *b"\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0TMEWC";
+
+/// Asset ID of tMEWC, the asset minted by the Wormhole Gateway.
+#[constant]
+pub const TMEWC_ASSET_ID: u16 = 0;
+
+/// Wormhole Gateway program, whose custodian's minted amount is recorded in supply checkpoints.
+/// Must match the gateway's program ID on the cluster this build targets.
+#[cfg(feature = "mainnet")]
+pub const WORMHOLE_GATEWAY_PROGRAM_ID: Pubkey =
+    pubkey!("8H9F5JGbEMyERycwaGuzLS5MQnV7dn2wm2h6egJ3Leiu");
+
+#[cfg(feature = "solana-devnet")]
+pub const WORMHOLE_GATEWAY_PROGRAM_ID: Pubkey =
+    pubkey!("8H9F5JGbEMyERycwaGuzLS5MQnV7dn2wm2h6egJ3Leiu");
+
+/// Seed of the Wormhole Gateway's custodian PDA.
+pub const SEED_PREFIX_GATEWAY_CUSTODIAN: &[u8] = b"redeemer";
@@ -29,9 +29,84 @@ pub enum TmewcError {
     #[msg("Caller is not a minter")]
     SignerNotMinter = 0x44,
 
+    #[msg("Minter must request optimistic mints")]
+    MinterIsOptimistic = 0x46,
+
+    #[msg("Minter must mint instantly")]
+    MinterIsNotOptimistic = 0x48,
+
+    #[msg("Minters must be provided for the minter role only")]
+    InvalidMinters = 0x4a,
+
     #[msg("Program is paused")]
     IsPaused = 0x50,
 
     #[msg("Program is not paused")]
     IsNotPaused = 0x52,
+
+    #[msg("Too early to record another supply checkpoint")]
+    CheckpointTooEarly = 0x60,
+
+    #[msg("Gateway custodian must sign for tMEWC only")]
+    InvalidGatewayCustodian = 0x62,
+
+    #[msg("Optimistic minting delay has not elapsed")]
+    MintRequestNotFinalizable = 0x70,
+
+    #[msg("Optimistic minting delay must be set")]
+    NoOptimisticMintingDelay = 0x72,
+
+    #[msg("Mint authority has already been transferred")]
+    MintAuthorityTransferred = 0x80,
+
+    #[msg("Successor authority must be a program derived address")]
+    InvalidSuccessorAuthority = 0x82,
+
+    #[msg("No mint authority transfer scheduled")]
+    NoMintAuthorityTransferScheduled = 0x84,
+
+    #[msg("Mint authority transfer delay has not elapsed")]
+    MintAuthorityTransferNotExecutable = 0x86,
+
+    #[msg("No Ethereum authority set")]
+    NoEthAuthority = 0x90,
+
+    #[msg("Missing or invalid Ethereum authority signature")]
+    InvalidEthAuthorization = 0x92,
+
+    #[msg("No governance emitter registered")]
+    NoGovernanceEmitter = 0xa0,
+
+    #[msg("VAA was not emitted by the governance emitter")]
+    InvalidGovernanceEmitter = 0xa2,
+
+    #[msg("Governance message is not meant for this program")]
+    InvalidGovernanceMessage = 0xa4,
+
+    #[msg("Accounts do not match the governance message")]
+    GovernanceAccountsMismatch = 0xa6,
+
+    #[msg("Missing or invalid guardian pause signature")]
+    InvalidPauseSignature = 0xb0,
+
+    #[msg("Pause signature has expired")]
+    PauseSignatureExpired = 0xb2,
+
+    #[msg("Pause signature nonce does not match")]
+    InvalidPauseNonce = 0xb4,
+
+    #[msg("Reserve attestation must be provided")]
+    ReserveAttestationRequired = 0xc0,
+
+    #[msg("Not valid reserve attester")]
+    IsNotReserveAttester = 0xc2,
+
+    #[msg("Reserve attestation is stale")]
+    ReserveAttestationStale = 0xc4,
+
+    #[msg("Mint would exceed the attested reserve")]
+    ReserveExceeded = 0xc6,
+
+    #[msg("Reserve observation must be newer than the last one and not in the future")]
+    InvalidReserveObservation = 0xc8,
 }
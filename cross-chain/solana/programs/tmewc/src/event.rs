@@ -1,21 +1,236 @@
+use crate::state::Role;
 use anchor_lang::prelude::*;
 
 #[event]
 pub struct MinterAdded {
+    pub asset_id: u16,
     pub minter: Pubkey,
 }
 
 #[event]
 pub struct MinterRemoved {
+    pub asset_id: u16,
+    pub minter: Pubkey,
+}
+
+#[event]
+pub struct MinterRenounced {
+    pub asset_id: u16,
     pub minter: Pubkey,
 }
 
 #[event]
 pub struct GuardianAdded {
+    pub asset_id: u16,
     pub guardian: Pubkey,
 }
 
 #[event]
 pub struct GuardianRemoved {
+    pub asset_id: u16,
     pub guardian: Pubkey,
 }
+
+#[event]
+pub struct GuardianRenounced {
+    pub asset_id: u16,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct AutoPaused {
+    pub asset_id: u16,
+    pub minter: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AnomalyGuardUpdated {
+    pub asset_id: u16,
+    pub max_mint_amount: u64,
+    pub mint_window_limit: u64,
+    pub mint_window_duration: u32,
+}
+
+#[event]
+pub struct Initialized {
+    pub asset_id: u16,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct AuthorityChangeStaged {
+    pub asset_id: u16,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityChangeCancelled {
+    pub asset_id: u16,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityChanged {
+    pub asset_id: u16,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct Paused {
+    pub asset_id: u16,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct Unpaused {
+    pub asset_id: u16,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct Minted {
+    pub asset_id: u16,
+    pub minter: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub supply: u64,
+}
+
+#[event]
+pub struct MinterModeUpdated {
+    pub asset_id: u16,
+    pub minter: Pubkey,
+    pub optimistic: bool,
+}
+
+#[event]
+pub struct OptimisticMintingDelayUpdated {
+    pub asset_id: u16,
+    pub delay: u32,
+}
+
+#[event]
+pub struct MintRequested {
+    pub asset_id: u16,
+    pub id: u64,
+    pub minter: Pubkey,
+    pub recipient_token: Pubkey,
+    pub amount: u64,
+    pub finalizable_at: i64,
+}
+
+#[event]
+pub struct MintRequestCancelled {
+    pub asset_id: u16,
+    pub id: u64,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct MintRequestFinalized {
+    pub asset_id: u16,
+    pub id: u64,
+}
+
+#[event]
+pub struct MintAuthorityTransferScheduled {
+    pub asset_id: u16,
+    pub successor_authority: Pubkey,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct MintAuthorityTransferCancelled {
+    pub asset_id: u16,
+    pub successor_authority: Pubkey,
+}
+
+#[event]
+pub struct MintAuthorityTransferred {
+    pub asset_id: u16,
+    pub successor_authority: Pubkey,
+}
+
+#[event]
+pub struct EthAuthorityUpdated {
+    pub asset_id: u16,
+    pub eth_authority: Option<[u8; 20]>,
+}
+
+#[event]
+pub struct EthAuthorityExecuted {
+    pub asset_id: u16,
+    pub nonce: u64,
+}
+
+#[event]
+pub struct GovernanceEmitterUpdated {
+    pub asset_id: u16,
+    pub governance_emitter: [u8; 32],
+}
+
+#[event]
+pub struct GovernanceVaaExecuted {
+    pub asset_id: u16,
+    pub sequence: u64,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub asset_id: u16,
+    pub role: Role,
+    pub member: Pubkey,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub asset_id: u16,
+    pub role: Role,
+    pub member: Pubkey,
+}
+
+#[event]
+pub struct RoleRenounced {
+    pub asset_id: u16,
+    pub role: Role,
+    pub member: Pubkey,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub asset_id: u16,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct ReserveAttesterUpdated {
+    pub asset_id: u16,
+    pub reserve_attester: Option<Pubkey>,
+    pub max_reserve_staleness: u32,
+}
+
+#[event]
+pub struct ReserveAttested {
+    pub asset_id: u16,
+    pub reserve: u64,
+    pub attested_at: i64,
+}
+
+#[event]
+pub struct TokenAccountFrozen {
+    pub asset_id: u16,
+    pub token_account: Pubkey,
+}
+
+#[event]
+pub struct TokenAccountThawed {
+    pub asset_id: u16,
+    pub token_account: Pubkey,
+}
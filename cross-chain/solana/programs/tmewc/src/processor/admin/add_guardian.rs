@@ -1,25 +1,33 @@
 use crate::{
     error::TmewcError,
-    state::{Config, GuardianInfo, Guardians},
+    state::{AuditAction, AuditLog, Config, GuardianInfo, Guardians},
 };
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AddGuardian<'info> {
     #[account(
         mut,
-        seeds = [Config::SEED_PREFIX],
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
         bump,
         has_one = authority @ TmewcError::IsNotAuthority
     )]
     config: Account<'info, Config>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     #[account(mut)]
     authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [Guardians::SEED_PREFIX],
+        seeds = [Guardians::SEED_PREFIX, &config.asset_seed()],
         bump = guardians.bump,
         realloc = Guardians::compute_size(guardians.keys.len() + 1),
         realloc::payer = authority,
@@ -31,7 +39,11 @@ pub struct AddGuardian<'info> {
         init,
         payer = authority,
         space = 8 + GuardianInfo::INIT_SPACE,
-        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        seeds = [
+            GuardianInfo::SEED_PREFIX,
+            &config.asset_seed(),
+            guardian.key().as_ref()
+        ],
         bump
     )]
     guardian_info: Account<'info, GuardianInfo>,
@@ -50,15 +62,26 @@ pub fn add_guardian(ctx: Context<AddGuardian>) -> Result<()> {
     ctx.accounts.guardian_info.set_inner(GuardianInfo {
         bump: ctx.bumps["guardian_info"],
         guardian,
+        pause_nonce: 0,
+        epoch: ctx.accounts.config.guardian_epoch,
     });
 
     // Push pubkey to guardians account.
     ctx.accounts.guardians.push(guardian);
 
     // Update config.
-    ctx.accounts.config.num_guardians += 1;
+    let config = &mut ctx.accounts.config;
+    config.num_guardians += 1;
+    config.guardian_epoch += 1;
 
-    emit!(crate::event::GuardianAdded { guardian });
+    emit_cpi!(crate::event::GuardianAdded {
+        asset_id: ctx.accounts.config.asset_id,
+        guardian
+    });
 
-    Ok(())
+    ctx.accounts.audit_log.record(
+        AuditAction::AddGuardian,
+        ctx.accounts.authority.key(),
+        guardian,
+    )
 }
@@ -1,25 +1,33 @@
 use crate::{
     error::TmewcError,
-    state::{Config, MinterInfo, Minters},
+    state::{AuditAction, AuditLog, Config, MinterInfo, Minters},
 };
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AddMinter<'info> {
     #[account(
         mut,
-        seeds = [Config::SEED_PREFIX],
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
         bump,
         has_one = authority @ TmewcError::IsNotAuthority
     )]
     config: Account<'info, Config>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     #[account(mut)]
     authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [Minters::SEED_PREFIX],
+        seeds = [Minters::SEED_PREFIX, &config.asset_seed()],
         bump = minters.bump,
         realloc = Minters::compute_size(minters.keys.len() + 1),
         realloc::payer = authority,
@@ -31,7 +39,7 @@ pub struct AddMinter<'info> {
         init,
         payer = authority,
         space = 8 + MinterInfo::INIT_SPACE,
-        seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
+        seeds = [MinterInfo::SEED_PREFIX, &config.asset_seed(), minter.key().as_ref()],
         bump
     )]
     minter_info: Account<'info, MinterInfo>,
@@ -42,6 +50,19 @@ pub struct AddMinter<'info> {
     system_program: Program<'info, System>,
 }
 
+impl<'info> AddMinter<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        // A member holding the minter role is listed already.
+        require!(
+            !ctx.accounts.minters.contains(&ctx.accounts.minter.key()),
+            TmewcError::MinterAlreadyExists
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(AddMinter::constraints(&ctx))]
 pub fn add_minter(ctx: Context<AddMinter>) -> Result<()> {
     let minter = ctx.accounts.minter.key();
 
@@ -49,6 +70,7 @@ pub fn add_minter(ctx: Context<AddMinter>) -> Result<()> {
     ctx.accounts.minter_info.set_inner(MinterInfo {
         bump: ctx.bumps["minter_info"],
         minter,
+        optimistic: false,
     });
 
     // Push pubkey to minters account.
@@ -57,7 +79,12 @@ pub fn add_minter(ctx: Context<AddMinter>) -> Result<()> {
     // Update config.
     ctx.accounts.config.num_minters += 1;
 
-    emit!(crate::event::MinterAdded { minter });
+    emit_cpi!(crate::event::MinterAdded {
+        asset_id: ctx.accounts.config.asset_id,
+        minter
+    });
 
-    Ok(())
+    ctx.accounts
+        .audit_log
+        .record(AuditAction::AddMinter, ctx.accounts.authority.key(), minter)
 }
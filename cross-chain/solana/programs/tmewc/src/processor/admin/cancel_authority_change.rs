@@ -1,22 +1,45 @@
-use crate::{error::TmewcError, state::Config};
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config},
+};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 
 pub struct CancelAuthorityChange<'info> {
     #[account(
         mut,
-        seeds = [Config::SEED_PREFIX],
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
         bump,
         has_one = authority @ TmewcError::IsNotAuthority,
         constraint = config.pending_authority.is_some() @ TmewcError::NoPendingAuthorityChange
     )]
     config: Account<'info, Config>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     authority: Signer<'info>,
 }
 
 pub fn cancel_authority_change(ctx: Context<CancelAuthorityChange>) -> Result<()> {
-    ctx.accounts.config.pending_authority = None;
-    Ok(())
+    // It is safe to unwrap because the account constraint guarantees a pending authority.
+    let pending_authority = ctx.accounts.config.pending_authority.take().unwrap();
+
+    emit_cpi!(crate::event::AuthorityChangeCancelled {
+        asset_id: ctx.accounts.config.asset_id,
+        authority: ctx.accounts.authority.key(),
+        pending_authority
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::CancelAuthorityChange,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
 }
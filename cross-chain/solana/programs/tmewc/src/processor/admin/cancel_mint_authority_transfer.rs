@@ -0,0 +1,63 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelMintAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+        has_one = authority @ TmewcError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+}
+
+impl<'info> CancelMintAuthorityTransfer<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        let config = &ctx.accounts.config;
+
+        require!(
+            !config.mint_authority_transferred,
+            TmewcError::MintAuthorityTransferred
+        );
+        require!(
+            config.successor_authority.is_some(),
+            TmewcError::NoMintAuthorityTransferScheduled
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(CancelMintAuthorityTransfer::constraints(&ctx))]
+pub fn cancel_mint_authority_transfer(ctx: Context<CancelMintAuthorityTransfer>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    // It is safe to unwrap because the constraints guarantee a scheduled transfer.
+    let successor_authority = config.successor_authority.take().unwrap();
+    config.mint_authority_transfer_at = 0;
+
+    emit_cpi!(crate::event::MintAuthorityTransferCancelled {
+        asset_id: ctx.accounts.config.asset_id,
+        successor_authority
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::CancelMintAuthorityTransfer,
+        ctx.accounts.authority.key(),
+        successor_authority,
+    )
+}
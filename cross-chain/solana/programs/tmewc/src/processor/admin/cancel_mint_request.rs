@@ -0,0 +1,64 @@
+use crate::state::{AuditAction, AuditLog, Config, GuardianInfo, MintRequest};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelMintRequest<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = minter,
+        close = minter,
+        seeds = [
+            MintRequest::SEED_PREFIX,
+            &config.asset_seed(),
+            &mint_request.id.to_le_bytes()
+        ],
+        bump = mint_request.bump,
+    )]
+    mint_request: Account<'info, MintRequest>,
+
+    /// CHECK: Requesting minter, who receives the mint request's rent back.
+    #[account(mut)]
+    minter: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        has_one = guardian,
+        seeds = [
+            GuardianInfo::SEED_PREFIX,
+            &config.asset_seed(),
+            guardian.key().as_ref()
+        ],
+        bump = guardian_info.bump
+    )]
+    guardian_info: Account<'info, GuardianInfo>,
+
+    guardian: Signer<'info>,
+}
+
+/// Guardians may cancel a mint request at any time before it is finalized.
+pub fn cancel_mint_request(ctx: Context<CancelMintRequest>) -> Result<()> {
+    emit_cpi!(crate::event::MintRequestCancelled {
+        asset_id: ctx.accounts.config.asset_id,
+        id: ctx.accounts.mint_request.id,
+        guardian: ctx.accounts.guardian.key()
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::CancelMintRequest,
+        ctx.accounts.guardian.key(),
+        ctx.accounts.mint_request.key(),
+    )
+}
@@ -1,16 +1,27 @@
-use crate::{error::TmewcError, state::Config};
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config},
+};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ChangeAuthority<'info> {
     #[account(
         mut,
-        seeds = [Config::SEED_PREFIX],
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
         bump,
         has_one = authority @ TmewcError::IsNotAuthority
     )]
     config: Account<'info, Config>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     authority: Signer<'info>,
 
     /// CHECK: New authority.
@@ -19,5 +30,16 @@ pub struct ChangeAuthority<'info> {
 
 pub fn change_authority(ctx: Context<ChangeAuthority>) -> Result<()> {
     ctx.accounts.config.pending_authority = Some(ctx.accounts.new_authority.key());
-    Ok(())
+
+    emit_cpi!(crate::event::AuthorityChangeStaged {
+        asset_id: ctx.accounts.config.asset_id,
+        authority: ctx.accounts.authority.key(),
+        pending_authority: ctx.accounts.new_authority.key()
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::ChangeAuthority,
+        ctx.accounts.authority.key(),
+        ctx.accounts.new_authority.key(),
+    )
 }
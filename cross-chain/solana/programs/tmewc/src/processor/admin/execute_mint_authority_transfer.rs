@@ -0,0 +1,134 @@
+use crate::{
+    constants::SEED_PREFIX_TMEWC_MINT,
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config},
+};
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
+use anchor_spl::{metadata, token_interface};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteMintAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_TMEWC_MINT, &config.asset_seed()],
+        bump = config.mint_bump,
+        mint::authority = config,
+        mint::token_program = token_program,
+    )]
+    mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+        has_one = authority @ TmewcError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+
+    /// CHECK: This account is needed for the MPL Token Metadata program.
+    #[account(mut)]
+    tmewc_metadata: UncheckedAccount<'info>,
+
+    mpl_token_metadata_program: Program<'info, metadata::Metadata>,
+    token_program: Interface<'info, token_interface::TokenInterface>,
+}
+
+impl<'info> ExecuteMintAuthorityTransfer<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        let config = &ctx.accounts.config;
+
+        require!(
+            config.successor_authority.is_some(),
+            TmewcError::NoMintAuthorityTransferScheduled
+        );
+        require_gte!(
+            Clock::get()?.unix_timestamp,
+            config.mint_authority_transfer_at,
+            TmewcError::MintAuthorityTransferNotExecutable
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(ExecuteMintAuthorityTransfer::constraints(&ctx))]
+pub fn execute_mint_authority_transfer(ctx: Context<ExecuteMintAuthorityTransfer>) -> Result<()> {
+    let successor_authority = ctx.accounts.config.successor_authority.unwrap();
+
+    let asset_seed = ctx.accounts.config.asset_seed();
+    let signer_seeds: &[&[u8]] = &[
+        Config::SEED_PREFIX,
+        &asset_seed,
+        &[ctx.accounts.config.bump],
+    ];
+
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::SetAuthority {
+                current_authority: ctx.accounts.config.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        token_interface::spl_token_2022::instruction::AuthorityType::MintTokens,
+        Some(successor_authority),
+    )?;
+
+    // Hand over freezing too, for mints the config can freeze.
+    if ctx.accounts.mint.freeze_authority == COption::Some(ctx.accounts.config.key()) {
+        token_interface::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::SetAuthority {
+                    current_authority: ctx.accounts.config.to_account_info(),
+                    account_or_mint: ctx.accounts.mint.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            token_interface::spl_token_2022::instruction::AuthorityType::FreezeAccount,
+            Some(successor_authority),
+        )?;
+    }
+
+    metadata::update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.mpl_token_metadata_program.to_account_info(),
+            metadata::UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.tmewc_metadata.to_account_info(),
+                update_authority: ctx.accounts.config.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        Some(successor_authority),
+        None,
+        None,
+        None,
+    )?;
+
+    // This program can no longer mint, so pause it for good.
+    let config = &mut ctx.accounts.config;
+    config.paused = true;
+    config.mint_authority_transferred = true;
+
+    emit_cpi!(crate::event::MintAuthorityTransferred {
+        asset_id: ctx.accounts.config.asset_id,
+        successor_authority
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::ExecuteMintAuthorityTransfer,
+        ctx.accounts.authority.key(),
+        successor_authority,
+    )
+}
@@ -0,0 +1,104 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config, Minters, Role, RoleAssignment},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct GrantRole<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump,
+        has_one = authority @ TmewcError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RoleAssignment::INIT_SPACE,
+        seeds = [
+            RoleAssignment::SEED_PREFIX,
+            &config.asset_seed(),
+            &[role as u8],
+            member.key().as_ref()
+        ],
+        bump
+    )]
+    role_assignment: Account<'info, RoleAssignment>,
+
+    /// Required for, and only for, the minter role.
+    #[account(
+        mut,
+        seeds = [Minters::SEED_PREFIX, &config.asset_seed()],
+        bump = minters.bump,
+        realloc = Minters::compute_size(minters.keys.len() + 1),
+        realloc::payer = authority,
+        realloc::zero = true,
+    )]
+    minters: Option<Account<'info, Minters>>,
+
+    /// CHECK: Member the role is granted to. This pubkey lives in `RoleAssignment`.
+    member: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> GrantRole<'info> {
+    fn constraints(ctx: &Context<Self>, role: Role) -> Result<()> {
+        require_eq!(
+            ctx.accounts.minters.is_some(),
+            role == Role::Minter,
+            TmewcError::InvalidMinters
+        );
+
+        if let Some(minters) = &ctx.accounts.minters {
+            require!(
+                !minters.contains(&ctx.accounts.member.key()),
+                TmewcError::MinterAlreadyExists
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(GrantRole::constraints(&ctx, role))]
+pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+    let member = ctx.accounts.member.key();
+
+    // Minters holding the role are listed and counted like any other minter.
+    if let Some(minters) = &mut ctx.accounts.minters {
+        minters.push(member);
+        ctx.accounts.config.num_minters += 1;
+    }
+
+    ctx.accounts.role_assignment.set_inner(RoleAssignment {
+        bump: ctx.bumps["role_assignment"],
+        role,
+        member,
+    });
+
+    emit_cpi!(crate::event::RoleGranted {
+        asset_id: ctx.accounts.config.asset_id,
+        role,
+        member
+    });
+
+    ctx.accounts
+        .audit_log
+        .record(AuditAction::GrantRole, ctx.accounts.authority.key(), member)
+}
@@ -1,29 +1,35 @@
 use crate::{
-    constants::SEED_PREFIX_TMEWC_MINT,
-    state::{Config, Guardians, Minters},
+    constants::{SEED_PREFIX_TMEWC_MINT, TMEWC_ASSET_ID},
+    error::TmewcError,
+    state::{asset_seed, AuditAction, AuditLog, Config, Guardians, Minters},
 };
 use anchor_lang::prelude::*;
-use anchor_spl::{metadata, token};
+use anchor_spl::{metadata, token_interface};
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(args: InitializeArgs)]
 pub struct Initialize<'info> {
     // Use PDA for the mint address
     // so we can sign for it from the program
+    // (also as freeze authority, for `freeze_token_account`)
     #[account(
         init,
-        seeds = [SEED_PREFIX_TMEWC_MINT],
+        seeds = [SEED_PREFIX_TMEWC_MINT, &asset_seed(args.asset_id)],
         bump,
         payer = authority,
         mint::decimals = 8,
         mint::authority = config,
+        mint::freeze_authority = config,
+        mint::token_program = token_program,
     )]
-    mint: Account<'info, token::Mint>,
+    mint: InterfaceAccount<'info, token_interface::Mint>,
 
     #[account(
         init,
         payer = authority,
         space = 8 + Config::INIT_SPACE,
-        seeds = [Config::SEED_PREFIX],
+        seeds = [Config::SEED_PREFIX, &asset_seed(args.asset_id)],
         bump,
     )]
     config: Account<'info, Config>,
@@ -32,7 +38,7 @@ pub struct Initialize<'info> {
         init,
         payer = authority,
         space = Guardians::compute_size(0),
-        seeds = [Guardians::SEED_PREFIX],
+        seeds = [Guardians::SEED_PREFIX, &asset_seed(args.asset_id)],
         bump,
     )]
     guardians: Account<'info, Guardians>,
@@ -41,11 +47,27 @@ pub struct Initialize<'info> {
         init,
         payer = authority,
         space = Minters::compute_size(0),
-        seeds = [Minters::SEED_PREFIX],
+        seeds = [Minters::SEED_PREFIX, &asset_seed(args.asset_id)],
         bump,
     )]
     minters: Account<'info, Minters>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = AuditLog::compute_size(AuditLog::MAX_ENTRIES),
+        seeds = [AuditLog::SEED_PREFIX, &asset_seed(args.asset_id)],
+        bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    /// tMEWC's config, whose authority has to approve the initialization of any other asset.
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = tmewc_config.bump,
+    )]
+    tmewc_config: Option<Account<'info, Config>>,
+
     #[account(mut)]
     authority: Signer<'info>,
 
@@ -57,11 +79,45 @@ pub struct Initialize<'info> {
     rent: UncheckedAccount<'info>,
 
     mpl_token_metadata_program: Program<'info, metadata::Metadata>,
-    token_program: Program<'info, token::Token>,
+    token_program: Interface<'info, token_interface::TokenInterface>,
     system_program: Program<'info, System>,
 }
 
-pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeArgs {
+    asset_id: u16,
+    name: String,
+    symbol: String,
+}
+
+impl<'info> Initialize<'info> {
+    fn constraints(ctx: &Context<Self>, args: &InitializeArgs) -> Result<()> {
+        // Anyone can initialize tMEWC once, but only its authority can add further assets.
+        if args.asset_id != TMEWC_ASSET_ID {
+            let tmewc_config = ctx
+                .accounts
+                .tmewc_config
+                .as_ref()
+                .ok_or(TmewcError::IsNotAuthority)?;
+            require_keys_eq!(
+                tmewc_config.authority,
+                ctx.accounts.authority.key(),
+                TmewcError::IsNotAuthority
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(Initialize::constraints(&ctx, &args))]
+pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
+    let InitializeArgs {
+        asset_id,
+        name,
+        symbol,
+    } = args;
+
     // Set Config account data.
     ctx.accounts.config.set_inner(Config {
         bump: ctx.bumps["config"],
@@ -72,6 +128,23 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         num_minters: 0,
         num_guardians: 0,
         paused: false,
+        max_mint_amount: 0,
+        mint_window_limit: 0,
+        mint_window_duration: 0,
+        mint_window_start: 0,
+        mint_window_amount: 0,
+        optimistic_minting_delay: 0,
+        next_mint_request_id: 0,
+        successor_authority: None,
+        mint_authority_transfer_at: 0,
+        mint_authority_transferred: false,
+        eth_authority: None,
+        eth_authority_nonce: 0,
+        governance_emitter: [0; 32],
+        reserve_attester: None,
+        max_reserve_staleness: 0,
+        asset_id,
+        guardian_epoch: 0,
     });
 
     // Set Guardians account data with empty vec.
@@ -86,7 +159,25 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         keys: Vec::new(),
     });
 
-    // Create metadata for tMEWC.
+    emit_cpi!(crate::event::Initialized {
+        asset_id,
+        authority: ctx.accounts.authority.key(),
+        mint: ctx.accounts.mint.key()
+    });
+
+    // Set AuditLog account data with empty vec and record the initialization.
+    ctx.accounts.audit_log.set_inner(AuditLog {
+        bump: ctx.bumps["audit_log"],
+        next_index: 0,
+        entries: Vec::new(),
+    });
+    ctx.accounts.audit_log.record(
+        AuditAction::Initialize,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )?;
+
+    // Create metadata for the asset.
     metadata::create_metadata_accounts_v3(
         CpiContext::new_with_signer(
             ctx.accounts.mpl_token_metadata_program.to_account_info(),
@@ -99,11 +190,15 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
                 system_program: ctx.accounts.system_program.to_account_info(),
                 rent: ctx.accounts.rent.to_account_info(),
             },
-            &[&[Config::SEED_PREFIX, &[ctx.bumps["config"]]]],
+            &[&[
+                Config::SEED_PREFIX,
+                &asset_seed(asset_id),
+                &[ctx.bumps["config"]],
+            ]],
         ),
         mpl_token_metadata::state::DataV2 {
-            symbol: "tMEWC".to_string(),
-            name: "tMEWC".to_string(),
+            symbol,
+            name,
             uri: "".to_string(),
             seller_fee_basis_points: 0,
             creators: None,
@@ -0,0 +1,93 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config},
+};
+use anchor_lang::{prelude::*, system_program};
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: A config created by an earlier version of this program is too small to deserialize,
+    /// so it is only deserialized (and its authority checked) after reallocation.
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+        owner = crate::ID,
+    )]
+    config: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AuditLog::compute_size(AuditLog::MAX_ENTRIES),
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Bring the tMEWC accounts of a deployment that predates fields appended to `Config` up to date.
+/// The config is grown to its current size, with the new fields zeroed (i.e. set to their
+/// defaults), and the audit log is created if missing. Safe to call more than once.
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config_info = ctx.accounts.config.to_account_info();
+    grow_account(
+        &config_info,
+        8 + Config::INIT_SPACE,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    let config = Account::<Config>::try_from(&config_info)?;
+    require_keys_eq!(
+        config.authority,
+        ctx.accounts.authority.key(),
+        TmewcError::IsNotAuthority
+    );
+
+    let audit_log = &mut ctx.accounts.audit_log;
+    audit_log.bump = ctx.bumps["audit_log"];
+    audit_log.record(
+        AuditAction::MigrateConfig,
+        ctx.accounts.authority.key(),
+        config_info.key(),
+    )
+}
+
+/// Grow an account created by an earlier version of this program to `space` bytes, topping up its
+/// rent from `payer`. The appended bytes are zeroed. Accounts already this large are left as is.
+pub(crate) fn grow_account<'info>(
+    account_info: &AccountInfo<'info>,
+    space: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if account_info.data_len() >= space {
+        return Ok(());
+    }
+
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account_info.lamports());
+    if lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    account_info.realloc(space, true)?;
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::{
+    error::TmewcError,
+    processor::grow_account,
+    state::{AuditAction, AuditLog, Config, GuardianInfo},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateGuardianInfo<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TmewcError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: A guardian info created by an earlier version of this program is too small to
+    /// deserialize, so it is only deserialized after reallocation.
+    #[account(
+        mut,
+        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    guardian_info: UncheckedAccount<'info>,
+
+    /// CHECK: This pubkey lives in `GuardianInfo`.
+    guardian: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Bring a tMEWC guardian info that predates signed pauses up to date. It is grown to its current
+/// size and given a guardian epoch of its own, as if the guardian were added now, with its pause
+/// nonce starting at zero. Safe to call more than once.
+pub fn migrate_guardian_info(ctx: Context<MigrateGuardianInfo>) -> Result<()> {
+    let guardian_info = ctx.accounts.guardian_info.to_account_info();
+    let space = 8 + GuardianInfo::INIT_SPACE;
+    if guardian_info.data_len() < space {
+        grow_account(
+            &guardian_info,
+            space,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+        )?;
+
+        let mut account = Account::<GuardianInfo>::try_from(&guardian_info)?;
+        account.pause_nonce = 0;
+        account.epoch = ctx.accounts.config.guardian_epoch;
+        account.exit(&crate::ID)?;
+
+        ctx.accounts.config.guardian_epoch += 1;
+    }
+
+    ctx.accounts.audit_log.record(
+        AuditAction::MigrateGuardianInfo,
+        ctx.accounts.authority.key(),
+        ctx.accounts.guardian.key(),
+    )
+}
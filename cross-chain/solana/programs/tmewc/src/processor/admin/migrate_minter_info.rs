@@ -0,0 +1,63 @@
+use crate::{
+    error::TmewcError,
+    processor::grow_account,
+    state::{AuditAction, AuditLog, Config, MinterInfo},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateMinterInfo<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TmewcError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: A minter info created by an earlier version of this program is too small to
+    /// deserialize, so it is only deserialized after reallocation.
+    #[account(
+        mut,
+        seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    minter_info: UncheckedAccount<'info>,
+
+    /// CHECK: This pubkey lives in `MinterInfo`.
+    minter: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Bring a tMEWC minter info that predates fields appended to `MinterInfo` up to date. It is grown
+/// to its current size, with the new fields zeroed (i.e. the minter mints instantly). Safe to call
+/// more than once.
+pub fn migrate_minter_info(ctx: Context<MigrateMinterInfo>) -> Result<()> {
+    let minter_info = ctx.accounts.minter_info.to_account_info();
+    grow_account(
+        &minter_info,
+        8 + MinterInfo::INIT_SPACE,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    // Make sure the grown account deserializes.
+    Account::<MinterInfo>::try_from(&minter_info)?;
+
+    ctx.accounts.audit_log.record(
+        AuditAction::MigrateMinterInfo,
+        ctx.accounts.authority.key(),
+        ctx.accounts.minter.key(),
+    )
+}
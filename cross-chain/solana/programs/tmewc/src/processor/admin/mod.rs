@@ -7,23 +7,89 @@ pub use add_minter::*;
 mod cancel_authority_change;
 pub use cancel_authority_change::*;
 
+mod cancel_mint_authority_transfer;
+pub use cancel_mint_authority_transfer::*;
+
+mod cancel_mint_request;
+pub use cancel_mint_request::*;
+
 mod change_authority;
 pub use change_authority::*;
 
+mod execute_mint_authority_transfer;
+pub use execute_mint_authority_transfer::*;
+
+mod freeze_token_account;
+pub use freeze_token_account::*;
+
+mod grant_role;
+pub use grant_role::*;
+
 mod initialize;
 pub use initialize::*;
 
+mod migrate_config;
+pub use migrate_config::*;
+
+mod migrate_guardian_info;
+pub use migrate_guardian_info::*;
+
+mod migrate_minter_info;
+pub use migrate_minter_info::*;
+
 mod pause;
 pub use pause::*;
 
+mod pause_with_signature;
+pub use pause_with_signature::*;
+
 mod remove_guardian;
 pub use remove_guardian::*;
 
 mod remove_minter;
 pub use remove_minter::*;
 
+mod renounce_guardian;
+pub use renounce_guardian::*;
+
+mod renounce_minter;
+pub use renounce_minter::*;
+
+mod renounce_role;
+pub use renounce_role::*;
+
+mod revoke_role;
+pub use revoke_role::*;
+
+mod schedule_mint_authority_transfer;
+pub use schedule_mint_authority_transfer::*;
+
+mod set_eth_authority;
+pub use set_eth_authority::*;
+
 mod take_authority;
 pub use take_authority::*;
 
+mod thaw_token_account;
+pub use thaw_token_account::*;
+
 mod unpause;
 pub use unpause::*;
+
+mod update_anomaly_guard;
+pub use update_anomaly_guard::*;
+
+mod update_governance_emitter;
+pub use update_governance_emitter::*;
+
+mod update_metadata;
+pub use update_metadata::*;
+
+mod update_minter_mode;
+pub use update_minter_mode::*;
+
+mod update_optimistic_minting_delay;
+pub use update_optimistic_minting_delay::*;
+
+mod update_reserve_attester;
+pub use update_reserve_attester::*;
@@ -1,24 +1,47 @@
 use crate::{
     error::TmewcError,
-    state::{Config, GuardianInfo},
+    state::{AuditAction, AuditLog, Config, GuardianInfo, Role, RoleAssignment},
 };
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Pause<'info> {
     #[account(
         mut,
-        seeds = [Config::SEED_PREFIX],
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
         bump,
     )]
     config: Account<'info, Config>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     #[account(
         has_one = guardian,
-        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        seeds = [
+            GuardianInfo::SEED_PREFIX,
+            &config.asset_seed(),
+            guardian.key().as_ref()
+        ],
         bump = guardian_info.bump
     )]
-    guardian_info: Account<'info, GuardianInfo>,
+    guardian_info: Option<Account<'info, GuardianInfo>>,
+
+    #[account(
+        seeds = [
+            RoleAssignment::SEED_PREFIX,
+            &config.asset_seed(),
+            &[Role::Pauser as u8],
+            guardian.key().as_ref()
+        ],
+        bump = role_assignment.bump
+    )]
+    role_assignment: Option<Account<'info, RoleAssignment>>,
 
     guardian: Signer<'info>,
 }
@@ -27,6 +50,12 @@ impl<'info> Pause<'info> {
     fn constraints(ctx: &Context<Self>) -> Result<()> {
         require!(!ctx.accounts.config.paused, TmewcError::IsPaused);
 
+        // The guardian either has a guardian info or holds the pauser role.
+        require!(
+            ctx.accounts.guardian_info.is_some() || ctx.accounts.role_assignment.is_some(),
+            TmewcError::SignerNotGuardian
+        );
+
         Ok(())
     }
 }
@@ -34,5 +63,15 @@ impl<'info> Pause<'info> {
 #[access_control(Pause::constraints(&ctx))]
 pub fn pause(ctx: Context<Pause>) -> Result<()> {
     ctx.accounts.config.paused = true;
-    Ok(())
+
+    emit_cpi!(crate::event::Paused {
+        asset_id: ctx.accounts.config.asset_id,
+        guardian: ctx.accounts.guardian.key()
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::Pause,
+        ctx.accounts.guardian.key(),
+        Pubkey::default(),
+    )
 }
@@ -0,0 +1,157 @@
+use crate::{
+    constants::{CLUSTER, PAUSE_SIGNATURE_DOMAIN},
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config, GuardianInfo},
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        sysvar::{
+            self,
+            instructions::{load_current_index_checked, load_instruction_at_checked},
+        },
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PauseWithSignature<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        mut,
+        seeds = [
+            GuardianInfo::SEED_PREFIX,
+            &config.asset_seed(),
+            guardian_info.guardian.as_ref()
+        ],
+        bump = guardian_info.bump
+    )]
+    guardian_info: Account<'info, GuardianInfo>,
+
+    /// CHECK: Instructions sysvar, used to find the ed25519 signature verification.
+    #[account(address = sysvar::instructions::ID)]
+    instructions: UncheckedAccount<'info>,
+}
+
+impl<'info> PauseWithSignature<'info> {
+    fn constraints(ctx: &Context<Self>, nonce: u64, expiry: i64) -> Result<()> {
+        require!(!ctx.accounts.config.paused, TmewcError::IsPaused);
+
+        require_eq!(
+            nonce,
+            ctx.accounts.guardian_info.pause_nonce,
+            TmewcError::InvalidPauseNonce
+        );
+        require!(
+            Clock::get()?.unix_timestamp < expiry,
+            TmewcError::PauseSignatureExpired
+        );
+
+        Ok(())
+    }
+}
+
+/// Pause with a guardian's off-chain signature over the message built by `pause_message`. The
+/// instruction right before this one must verify the signature with the ed25519 precompile, so any
+/// fee payer can submit it.
+#[access_control(PauseWithSignature::constraints(&ctx, nonce, expiry))]
+pub fn pause_with_signature(
+    ctx: Context<PauseWithSignature>,
+    nonce: u64,
+    expiry: i64,
+) -> Result<()> {
+    let guardian = ctx.accounts.guardian_info.guardian;
+    let message = pause_message(
+        &ctx.accounts.config.key(),
+        ctx.accounts.guardian_info.epoch,
+        nonce,
+        expiry,
+    );
+    verify_guardian_signature(&ctx.accounts.instructions, &guardian, &message)?;
+
+    ctx.accounts.guardian_info.pause_nonce = nonce + 1;
+    ctx.accounts.config.paused = true;
+
+    emit_cpi!(crate::event::Paused {
+        asset_id: ctx.accounts.config.asset_id,
+        guardian
+    });
+
+    ctx.accounts
+        .audit_log
+        .record(AuditAction::Pause, guardian, Pubkey::default())
+}
+
+/// Domain separator, cluster, program ID, config, guardian epoch, nonce and expiry, in that order.
+fn pause_message(config: &Pubkey, epoch: u64, nonce: u64, expiry: i64) -> Vec<u8> {
+    let mut message = Vec::with_capacity(PAUSE_SIGNATURE_DOMAIN.len() + CLUSTER.len() + 88);
+    message.extend_from_slice(PAUSE_SIGNATURE_DOMAIN);
+    message.extend_from_slice(CLUSTER);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(config.as_ref());
+    message.extend_from_slice(&epoch.to_le_bytes());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message.extend_from_slice(&expiry.to_le_bytes());
+    message
+}
+
+/// Check that the previous instruction is an ed25519 precompile verifying a single signature by
+/// `guardian` over `message`, with all of its offsets pointing into its own data.
+fn verify_guardian_signature(
+    instructions: &AccountInfo,
+    guardian: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions)?;
+    require_gt!(current_index, 0, TmewcError::InvalidPauseSignature);
+
+    let index = current_index - 1;
+    let ix = load_instruction_at_checked(index.into(), instructions)?;
+    require_keys_eq!(
+        ix.program_id,
+        ed25519_program::ID,
+        TmewcError::InvalidPauseSignature
+    );
+
+    // Number of signatures and padding, followed by the offsets of the first signature.
+    let data = &ix.data;
+    require!(
+        data.len() >= 16 && data[0] == 1,
+        TmewcError::InvalidPauseSignature
+    );
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+
+    // An instruction index of u16::MAX refers to the precompile instruction itself.
+    require!(
+        [read_u16(4), read_u16(8), read_u16(14)]
+            .iter()
+            .all(|ix_index| *ix_index == u16::MAX || *ix_index == index),
+        TmewcError::InvalidPauseSignature
+    );
+
+    let public_key_offset = usize::from(read_u16(6));
+    let message_offset = usize::from(read_u16(10));
+    let message_size = usize::from(read_u16(12));
+    require!(
+        data.get(public_key_offset..public_key_offset + 32) == Some(guardian.as_ref())
+            && data.get(message_offset..message_offset + message_size) == Some(message),
+        TmewcError::InvalidPauseSignature
+    );
+
+    Ok(())
+}
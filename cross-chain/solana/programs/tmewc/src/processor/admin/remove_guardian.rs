@@ -1,9 +1,10 @@
 use crate::{
     error::TmewcError,
-    state::{Config, GuardianInfo, Guardians},
+    state::{AuditAction, AuditLog, Config, GuardianInfo, Guardians},
 };
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RemoveGuardian<'info> {
     #[account(
@@ -12,12 +13,19 @@ pub struct RemoveGuardian<'info> {
     )]
     config: Account<'info, Config>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     #[account(mut)]
     authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [Guardians::SEED_PREFIX],
+        seeds = [Guardians::SEED_PREFIX, &config.asset_seed()],
         bump = guardians.bump,
         realloc = Guardians::compute_size(guardians.keys.len().saturating_sub(1)),
         realloc::payer = authority,
@@ -29,7 +37,11 @@ pub struct RemoveGuardian<'info> {
         mut,
         has_one = guardian,
         close = authority,
-        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        seeds = [
+            GuardianInfo::SEED_PREFIX,
+            &config.asset_seed(),
+            guardian.key().as_ref()
+        ],
         bump = guardian_info.bump,
     )]
     guardian_info: Account<'info, GuardianInfo>,
@@ -57,7 +69,14 @@ pub fn remove_guardian(ctx: Context<RemoveGuardian>) -> Result<()> {
     // Update config.
     ctx.accounts.config.num_guardians -= 1;
 
-    emit!(crate::event::GuardianRemoved { guardian: removed });
+    emit_cpi!(crate::event::GuardianRemoved {
+        asset_id: ctx.accounts.config.asset_id,
+        guardian: removed
+    });
 
-    Ok(())
+    ctx.accounts.audit_log.record(
+        AuditAction::RemoveGuardian,
+        ctx.accounts.authority.key(),
+        removed,
+    )
 }
@@ -1,25 +1,33 @@
 use crate::{
     error::TmewcError,
-    state::{Config, MinterInfo, Minters},
+    state::{AuditAction, AuditLog, Config, MinterInfo, Minters},
 };
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RemoveMinter<'info> {
     #[account(
         mut,
-        seeds = [Config::SEED_PREFIX],
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
         bump,
         has_one = authority @ TmewcError::IsNotAuthority
     )]
     config: Account<'info, Config>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     #[account(mut)]
     authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [Minters::SEED_PREFIX],
+        seeds = [Minters::SEED_PREFIX, &config.asset_seed()],
         bump = minters.bump,
         realloc = Minters::compute_size(minters.keys.len().saturating_sub(1)),
         realloc::payer = authority,
@@ -31,7 +39,7 @@ pub struct RemoveMinter<'info> {
         mut,
         has_one = minter,
         close = authority,
-        seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
+        seeds = [MinterInfo::SEED_PREFIX, &config.asset_seed(), minter.key().as_ref()],
         bump = minter_info.bump,
     )]
     minter_info: Account<'info, MinterInfo>,
@@ -59,7 +67,14 @@ pub fn remove_minter(ctx: Context<RemoveMinter>) -> Result<()> {
     // Update config.
     ctx.accounts.config.num_minters -= 1;
 
-    emit!(crate::event::MinterRemoved { minter: removed });
+    emit_cpi!(crate::event::MinterRemoved {
+        asset_id: ctx.accounts.config.asset_id,
+        minter: removed
+    });
 
-    Ok(())
+    ctx.accounts.audit_log.record(
+        AuditAction::RemoveMinter,
+        ctx.accounts.authority.key(),
+        removed,
+    )
 }
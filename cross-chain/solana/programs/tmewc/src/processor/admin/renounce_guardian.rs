@@ -0,0 +1,79 @@
+use crate::state::{AuditAction, AuditLog, Config, GuardianInfo, Guardians};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RenounceGuardian<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    /// CHECK: The authority paid for the guardian info, so it gets the rent back.
+    #[account(mut, address = config.authority)]
+    authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [Guardians::SEED_PREFIX, &config.asset_seed()],
+        bump = guardians.bump,
+        realloc = Guardians::compute_size(guardians.keys.len().saturating_sub(1)),
+        realloc::payer = authority,
+        realloc::zero = true,
+    )]
+    guardians: Account<'info, Guardians>,
+
+    #[account(
+        mut,
+        has_one = guardian,
+        close = authority,
+        seeds = [
+            GuardianInfo::SEED_PREFIX,
+            &config.asset_seed(),
+            guardian.key().as_ref()
+        ],
+        bump = guardian_info.bump,
+    )]
+    guardian_info: Account<'info, GuardianInfo>,
+
+    guardian: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Give up the guardian role, signed by the guardian itself.
+pub fn renounce_guardian(ctx: Context<RenounceGuardian>) -> Result<()> {
+    let guardians: &mut Vec<_> = &mut ctx.accounts.guardians;
+    let removed = ctx.accounts.guardian.key();
+
+    // It is safe to unwrap because the key we are removing is guaranteed to exist since there is
+    // a guardian info account for it.
+    let index = guardians
+        .iter()
+        .position(|&guardian| guardian == removed)
+        .unwrap();
+
+    // Remove pubkey from guardians account.
+    guardians.swap_remove(index);
+
+    // Update config.
+    ctx.accounts.config.num_guardians -= 1;
+
+    emit_cpi!(crate::event::GuardianRenounced {
+        asset_id: ctx.accounts.config.asset_id,
+        guardian: removed
+    });
+
+    ctx.accounts
+        .audit_log
+        .record(AuditAction::RenounceGuardian, removed, removed)
+}
@@ -0,0 +1,76 @@
+use crate::state::{AuditAction, AuditLog, Config, MinterInfo, Minters};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RenounceMinter<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    /// CHECK: The authority paid for the minter info, so it gets the rent back.
+    #[account(mut, address = config.authority)]
+    authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [Minters::SEED_PREFIX, &config.asset_seed()],
+        bump = minters.bump,
+        realloc = Minters::compute_size(minters.keys.len().saturating_sub(1)),
+        realloc::payer = authority,
+        realloc::zero = true,
+    )]
+    minters: Account<'info, Minters>,
+
+    #[account(
+        mut,
+        has_one = minter,
+        close = authority,
+        seeds = [MinterInfo::SEED_PREFIX, &config.asset_seed(), minter.key().as_ref()],
+        bump = minter_info.bump,
+    )]
+    minter_info: Account<'info, MinterInfo>,
+
+    minter: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Give up the minter role. Unlike `remove_minter`, this is signed by the minter itself, so a
+/// minter suspecting its key is compromised does not have to wait for the authority.
+pub fn renounce_minter(ctx: Context<RenounceMinter>) -> Result<()> {
+    let minters: &mut Vec<_> = &mut ctx.accounts.minters;
+    let removed = ctx.accounts.minter.key();
+
+    // It is safe to unwrap because the key we are removing is guaranteed to exist since there is
+    // a minter info account for it.
+    let index = minters
+        .iter()
+        .position(|&minter| minter == removed)
+        .unwrap();
+
+    // Remove pubkey from minters account.
+    minters.swap_remove(index);
+
+    // Update config.
+    ctx.accounts.config.num_minters -= 1;
+
+    emit_cpi!(crate::event::MinterRenounced {
+        asset_id: ctx.accounts.config.asset_id,
+        minter: removed
+    });
+
+    ctx.accounts
+        .audit_log
+        .record(AuditAction::RenounceMinter, removed, removed)
+}
@@ -0,0 +1,94 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config, Minters, Role, RoleAssignment},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct RenounceRole<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    /// CHECK: The authority paid for the role assignment, so it gets the rent back.
+    #[account(mut, address = config.authority)]
+    authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = member,
+        close = authority,
+        seeds = [
+            RoleAssignment::SEED_PREFIX,
+            &config.asset_seed(),
+            &[role as u8],
+            member.key().as_ref()
+        ],
+        bump = role_assignment.bump
+    )]
+    role_assignment: Account<'info, RoleAssignment>,
+
+    /// Required for, and only for, the minter role.
+    #[account(
+        mut,
+        seeds = [Minters::SEED_PREFIX, &config.asset_seed()],
+        bump = minters.bump,
+        realloc = Minters::compute_size(minters.keys.len().saturating_sub(1)),
+        realloc::payer = authority,
+        realloc::zero = true,
+    )]
+    minters: Option<Account<'info, Minters>>,
+
+    member: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> RenounceRole<'info> {
+    fn constraints(ctx: &Context<Self>, role: Role) -> Result<()> {
+        require_eq!(
+            ctx.accounts.minters.is_some(),
+            role == Role::Minter,
+            TmewcError::InvalidMinters
+        );
+
+        Ok(())
+    }
+}
+
+/// Give up a role. Unlike `revoke_role`, this is signed by the member itself, so a member
+/// suspecting its key is compromised does not have to wait for the authority. Renouncing the
+/// minter role also delists the member from `Minters`, like `revoke_role` does.
+#[access_control(RenounceRole::constraints(&ctx, role))]
+pub fn renounce_role(ctx: Context<RenounceRole>, role: Role) -> Result<()> {
+    let member = ctx.accounts.member.key();
+
+    if let Some(minters) = &mut ctx.accounts.minters {
+        // It is safe to unwrap because the member was listed when granted the minter role.
+        let index = minters.iter().position(|&minter| minter == member).unwrap();
+        minters.swap_remove(index);
+        ctx.accounts.config.num_minters -= 1;
+    }
+
+    emit_cpi!(crate::event::RoleRenounced {
+        asset_id: ctx.accounts.config.asset_id,
+        role,
+        member
+    });
+
+    ctx.accounts
+        .audit_log
+        .record(AuditAction::RenounceRole, member, member)
+}
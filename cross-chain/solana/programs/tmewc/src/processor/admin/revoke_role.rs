@@ -0,0 +1,94 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config, Minters, Role, RoleAssignment},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct RevokeRole<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump,
+        has_one = authority @ TmewcError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = member,
+        close = authority,
+        seeds = [
+            RoleAssignment::SEED_PREFIX,
+            &config.asset_seed(),
+            &[role as u8],
+            member.key().as_ref()
+        ],
+        bump = role_assignment.bump
+    )]
+    role_assignment: Account<'info, RoleAssignment>,
+
+    /// Required for, and only for, the minter role.
+    #[account(
+        mut,
+        seeds = [Minters::SEED_PREFIX, &config.asset_seed()],
+        bump = minters.bump,
+        realloc = Minters::compute_size(minters.keys.len().saturating_sub(1)),
+        realloc::payer = authority,
+        realloc::zero = true,
+    )]
+    minters: Option<Account<'info, Minters>>,
+
+    /// CHECK: Member the role is revoked from. This pubkey lives in `RoleAssignment`.
+    member: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> RevokeRole<'info> {
+    fn constraints(ctx: &Context<Self>, role: Role) -> Result<()> {
+        require_eq!(
+            ctx.accounts.minters.is_some(),
+            role == Role::Minter,
+            TmewcError::InvalidMinters
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(RevokeRole::constraints(&ctx, role))]
+pub fn revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+    let member = ctx.accounts.member.key();
+
+    if let Some(minters) = &mut ctx.accounts.minters {
+        // It is safe to unwrap because the member was listed when granted the minter role.
+        let index = minters.iter().position(|&minter| minter == member).unwrap();
+        minters.swap_remove(index);
+        ctx.accounts.config.num_minters -= 1;
+    }
+
+    emit_cpi!(crate::event::RoleRevoked {
+        asset_id: ctx.accounts.config.asset_id,
+        role,
+        member
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::RevokeRole,
+        ctx.accounts.authority.key(),
+        member,
+    )
+}
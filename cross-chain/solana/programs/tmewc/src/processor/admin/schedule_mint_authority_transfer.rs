@@ -0,0 +1,68 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ScheduleMintAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+        has_one = authority @ TmewcError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+}
+
+impl<'info> ScheduleMintAuthorityTransfer<'info> {
+    fn constraints(ctx: &Context<Self>, successor_authority: &Pubkey) -> Result<()> {
+        require!(
+            !ctx.accounts.config.mint_authority_transferred,
+            TmewcError::MintAuthorityTransferred
+        );
+
+        // The successor has to be a program's PDA, not a key someone holds.
+        require!(
+            !successor_authority.is_on_curve(),
+            TmewcError::InvalidSuccessorAuthority
+        );
+
+        Ok(())
+    }
+}
+
+/// Scheduling again replaces the successor and restarts the delay.
+#[access_control(ScheduleMintAuthorityTransfer::constraints(&ctx, &successor_authority))]
+pub fn schedule_mint_authority_transfer(
+    ctx: Context<ScheduleMintAuthorityTransfer>,
+    successor_authority: Pubkey,
+) -> Result<()> {
+    let executable_at = Clock::get()?.unix_timestamp + Config::MINT_AUTHORITY_TRANSFER_DELAY;
+
+    let config = &mut ctx.accounts.config;
+    config.successor_authority = Some(successor_authority);
+    config.mint_authority_transfer_at = executable_at;
+
+    emit_cpi!(crate::event::MintAuthorityTransferScheduled {
+        asset_id: ctx.accounts.config.asset_id,
+        successor_authority,
+        executable_at
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::ScheduleMintAuthorityTransfer,
+        ctx.accounts.authority.key(),
+        successor_authority,
+    )
+}
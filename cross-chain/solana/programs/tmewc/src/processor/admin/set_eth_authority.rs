@@ -0,0 +1,46 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetEthAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+        has_one = authority @ TmewcError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+}
+
+/// Setting the Ethereum authority does not hand over control by itself. The eth authority PDA
+/// still has to become `Config.authority` through the usual authority change.
+pub fn set_eth_authority(
+    ctx: Context<SetEthAuthority>,
+    eth_authority: Option<[u8; 20]>,
+) -> Result<()> {
+    ctx.accounts.config.eth_authority = eth_authority;
+
+    emit_cpi!(crate::event::EthAuthorityUpdated {
+        asset_id: ctx.accounts.config.asset_id,
+        eth_authority
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::SetEthAuthority,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
+}
@@ -1,15 +1,26 @@
-use crate::{error::TmewcError, state::Config};
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config},
+};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct TakeAuthority<'info> {
     #[account(
         mut,
-        seeds = [Config::SEED_PREFIX],
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
         bump,
     )]
     config: Account<'info, Config>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     pending_authority: Signer<'info>,
 }
 
@@ -32,7 +43,19 @@ impl<'info> TakeAuthority<'info> {
 
 #[access_control(TakeAuthority::constraints(&ctx))]
 pub fn take_authority(ctx: Context<TakeAuthority>) -> Result<()> {
+    let old_authority = ctx.accounts.config.authority;
     ctx.accounts.config.authority = ctx.accounts.pending_authority.key();
     ctx.accounts.config.pending_authority = None;
-    Ok(())
+
+    emit_cpi!(crate::event::AuthorityChanged {
+        asset_id: ctx.accounts.config.asset_id,
+        old_authority,
+        new_authority: ctx.accounts.pending_authority.key()
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::TakeAuthority,
+        ctx.accounts.pending_authority.key(),
+        Pubkey::default(),
+    )
 }
@@ -0,0 +1,98 @@
+use crate::{
+    constants::SEED_PREFIX_TMEWC_MINT,
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config, Role, RoleAssignment},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ThawTokenAccount<'info> {
+    #[account(
+        seeds = [SEED_PREFIX_TMEWC_MINT, &config.asset_seed()],
+        bump = config.mint_bump,
+        mint::freeze_authority = config,
+        mint::token_program = token_program,
+    )]
+    mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            RoleAssignment::SEED_PREFIX,
+            &config.asset_seed(),
+            &[Role::FreezeAdmin as u8],
+            authority.key().as_ref()
+        ],
+        bump = role_assignment.bump
+    )]
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    token_program: Interface<'info, token_interface::TokenInterface>,
+}
+
+impl<'info> ThawTokenAccount<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.config.authority
+                || ctx.accounts.role_assignment.is_some(),
+            TmewcError::IsNotAuthority
+        );
+
+        Ok(())
+    }
+}
+
+/// Thaw a token account of this asset frozen with `freeze_token_account`.
+#[access_control(ThawTokenAccount::constraints(&ctx))]
+pub fn thaw_token_account(ctx: Context<ThawTokenAccount>) -> Result<()> {
+    let asset_seed = ctx.accounts.config.asset_seed();
+    token_interface::thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::ThawAccount {
+            account: ctx.accounts.token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        },
+        &[&[
+            Config::SEED_PREFIX,
+            &asset_seed,
+            &[ctx.accounts.config.bump],
+        ]],
+    ))?;
+
+    let token_account = ctx.accounts.token_account.key();
+
+    emit_cpi!(crate::event::TokenAccountThawed {
+        asset_id: ctx.accounts.config.asset_id,
+        token_account
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::ThawTokenAccount,
+        ctx.accounts.authority.key(),
+        token_account,
+    )
+}
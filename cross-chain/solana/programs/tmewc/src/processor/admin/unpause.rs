@@ -1,23 +1,57 @@
-use crate::{error::TmewcError, state::Config};
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config, Role, RoleAssignment},
+};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Unpause<'info> {
     #[account(
         mut,
-        has_one = authority @ TmewcError::IsNotAuthority,
-        seeds = [Config::SEED_PREFIX],
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
         bump,
     )]
     config: Account<'info, Config>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            RoleAssignment::SEED_PREFIX,
+            &config.asset_seed(),
+            &[Role::Unpauser as u8],
+            authority.key().as_ref()
+        ],
+        bump = role_assignment.bump
+    )]
+    role_assignment: Option<Account<'info, RoleAssignment>>,
 }
 
 impl<'info> Unpause<'info> {
     fn constraints(ctx: &Context<Self>) -> Result<()> {
+        // Either the authority or a member holding the unpauser role.
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.config.authority
+                || ctx.accounts.role_assignment.is_some(),
+            TmewcError::IsNotAuthority
+        );
+
         require!(ctx.accounts.config.paused, TmewcError::IsNotPaused);
 
+        // Minting stays paused after the mint authority has moved to a successor.
+        require!(
+            !ctx.accounts.config.mint_authority_transferred,
+            TmewcError::MintAuthorityTransferred
+        );
+
         Ok(())
     }
 }
@@ -25,5 +59,15 @@ impl<'info> Unpause<'info> {
 #[access_control(Unpause::constraints(&ctx))]
 pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
     ctx.accounts.config.paused = false;
-    Ok(())
+
+    emit_cpi!(crate::event::Unpaused {
+        asset_id: ctx.accounts.config.asset_id,
+        authority: ctx.accounts.authority.key()
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::Unpause,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
 }
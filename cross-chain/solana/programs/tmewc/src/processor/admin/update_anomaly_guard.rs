@@ -0,0 +1,89 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config, Role, RoleAssignment},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateAnomalyGuard<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            RoleAssignment::SEED_PREFIX,
+            &config.asset_seed(),
+            &[Role::LimitAdmin as u8],
+            authority.key().as_ref()
+        ],
+        bump = role_assignment.bump
+    )]
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateAnomalyGuardArgs {
+    max_mint_amount: u64,
+    mint_window_limit: u64,
+    mint_window_duration: u32,
+}
+
+impl<'info> UpdateAnomalyGuard<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.config.authority
+                || ctx.accounts.role_assignment.is_some(),
+            TmewcError::IsNotAuthority
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(UpdateAnomalyGuard::constraints(&ctx))]
+pub fn update_anomaly_guard(
+    ctx: Context<UpdateAnomalyGuard>,
+    args: UpdateAnomalyGuardArgs,
+) -> Result<()> {
+    let UpdateAnomalyGuardArgs {
+        max_mint_amount,
+        mint_window_limit,
+        mint_window_duration,
+    } = args;
+
+    let config = &mut ctx.accounts.config;
+    config.max_mint_amount = max_mint_amount;
+    config.mint_window_limit = mint_window_limit;
+    config.mint_window_duration = mint_window_duration;
+
+    // Start tracking from a fresh window with the new thresholds.
+    config.mint_window_start = 0;
+    config.mint_window_amount = 0;
+
+    emit_cpi!(crate::event::AnomalyGuardUpdated {
+        asset_id: ctx.accounts.config.asset_id,
+        max_mint_amount,
+        mint_window_limit,
+        mint_window_duration
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateAnomalyGuard,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
+}
@@ -0,0 +1,46 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateGovernanceEmitter<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+        has_one = authority @ TmewcError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+}
+
+/// Registering the governance emitter does not hand over control by itself. The governance
+/// authority PDA still has to become `Config.authority` through the usual authority change.
+pub fn update_governance_emitter(
+    ctx: Context<UpdateGovernanceEmitter>,
+    governance_emitter: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.config.governance_emitter = governance_emitter;
+
+    emit_cpi!(crate::event::GovernanceEmitterUpdated {
+        asset_id: ctx.accounts.config.asset_id,
+        governance_emitter
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateGovernanceEmitter,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
+}
@@ -0,0 +1,114 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config, Role, RoleAssignment},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::metadata;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            RoleAssignment::SEED_PREFIX,
+            &config.asset_seed(),
+            &[Role::MetadataAdmin as u8],
+            authority.key().as_ref()
+        ],
+        bump = role_assignment.bump
+    )]
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// CHECK: This account is needed for the MPL Token Metadata program, which only lets the
+    /// config update the metadata of this asset's mint.
+    #[account(mut)]
+    tmewc_metadata: UncheckedAccount<'info>,
+
+    mpl_token_metadata_program: Program<'info, metadata::Metadata>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateMetadataArgs {
+    name: String,
+    symbol: String,
+    uri: String,
+}
+
+impl<'info> UpdateMetadata<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.config.authority
+                || ctx.accounts.role_assignment.is_some(),
+            TmewcError::IsNotAuthority
+        );
+
+        // The successor is the update authority once the mint authority is transferred.
+        require!(
+            !ctx.accounts.config.mint_authority_transferred,
+            TmewcError::MintAuthorityTransferred
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(UpdateMetadata::constraints(&ctx))]
+pub fn update_metadata(ctx: Context<UpdateMetadata>, args: UpdateMetadataArgs) -> Result<()> {
+    let UpdateMetadataArgs { name, symbol, uri } = args;
+
+    let asset_seed = ctx.accounts.config.asset_seed();
+    metadata::update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.mpl_token_metadata_program.to_account_info(),
+            metadata::UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.tmewc_metadata.to_account_info(),
+                update_authority: ctx.accounts.config.to_account_info(),
+            },
+            &[&[
+                Config::SEED_PREFIX,
+                &asset_seed,
+                &[ctx.accounts.config.bump],
+            ]],
+        ),
+        None,
+        Some(mpl_token_metadata::state::DataV2 {
+            symbol: symbol.clone(),
+            name: name.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        }),
+        None,
+        None,
+    )?;
+
+    emit_cpi!(crate::event::MetadataUpdated {
+        asset_id: ctx.accounts.config.asset_id,
+        name,
+        symbol,
+        uri
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateMetadata,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tmewc_metadata.key(),
+    )
+}
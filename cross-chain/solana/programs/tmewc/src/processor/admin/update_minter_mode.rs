@@ -0,0 +1,70 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config, MinterInfo},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMinterMode<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump,
+        has_one = authority @ TmewcError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = minter,
+        seeds = [MinterInfo::SEED_PREFIX, &config.asset_seed(), minter.key().as_ref()],
+        bump = minter_info.bump,
+    )]
+    minter_info: Account<'info, MinterInfo>,
+
+    /// CHECK: This pubkey lives in `MinterInfo`.
+    minter: AccountInfo<'info>,
+}
+
+impl<'info> UpdateMinterMode<'info> {
+    fn constraints(ctx: &Context<Self>, optimistic: bool) -> Result<()> {
+        // Optimistic mints are only worth the wait if there is one.
+        if optimistic {
+            require_gt!(
+                ctx.accounts.config.optimistic_minting_delay,
+                0,
+                TmewcError::NoOptimisticMintingDelay
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(UpdateMinterMode::constraints(&ctx, optimistic))]
+pub fn update_minter_mode(ctx: Context<UpdateMinterMode>, optimistic: bool) -> Result<()> {
+    let minter = ctx.accounts.minter.key();
+
+    ctx.accounts.minter_info.optimistic = optimistic;
+
+    emit_cpi!(crate::event::MinterModeUpdated {
+        asset_id: ctx.accounts.config.asset_id,
+        minter,
+        optimistic
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateMinterMode,
+        ctx.accounts.authority.key(),
+        minter,
+    )
+}
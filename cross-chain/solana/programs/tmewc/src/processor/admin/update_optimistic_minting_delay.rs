@@ -0,0 +1,67 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config, Role, RoleAssignment},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateOptimisticMintingDelay<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            RoleAssignment::SEED_PREFIX,
+            &config.asset_seed(),
+            &[Role::LimitAdmin as u8],
+            authority.key().as_ref()
+        ],
+        bump = role_assignment.bump
+    )]
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+impl<'info> UpdateOptimisticMintingDelay<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.config.authority
+                || ctx.accounts.role_assignment.is_some(),
+            TmewcError::IsNotAuthority
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(UpdateOptimisticMintingDelay::constraints(&ctx))]
+pub fn update_optimistic_minting_delay(
+    ctx: Context<UpdateOptimisticMintingDelay>,
+    delay: u32,
+) -> Result<()> {
+    ctx.accounts.config.optimistic_minting_delay = delay;
+
+    emit_cpi!(crate::event::OptimisticMintingDelayUpdated {
+        asset_id: ctx.accounts.config.asset_id,
+        delay
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateOptimisticMintingDelay,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
+}
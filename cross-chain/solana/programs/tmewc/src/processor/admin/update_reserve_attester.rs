@@ -0,0 +1,65 @@
+use crate::{
+    error::TmewcError,
+    state::{AuditAction, AuditLog, Config, ReserveAttestation},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateReserveAttester<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+        has_one = authority @ TmewcError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX, &config.asset_seed()],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ReserveAttestation::INIT_SPACE,
+        seeds = [ReserveAttestation::SEED_PREFIX, &config.asset_seed()],
+        bump,
+    )]
+    reserve_attestation: Account<'info, ReserveAttestation>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Set the reserve attester (`None` disables proof of reserve) and how long an attestation stays
+/// valid. To accept attestations from Ethereum, set the attester to the governance authority PDA
+/// and relay `attest_reserve` in a governance VAA.
+pub fn update_reserve_attester(
+    ctx: Context<UpdateReserveAttester>,
+    reserve_attester: Option<Pubkey>,
+    max_reserve_staleness: u32,
+) -> Result<()> {
+    ctx.accounts.reserve_attestation.bump = ctx.bumps["reserve_attestation"];
+
+    let config = &mut ctx.accounts.config;
+    config.reserve_attester = reserve_attester;
+    config.max_reserve_staleness = max_reserve_staleness;
+
+    emit_cpi!(crate::event::ReserveAttesterUpdated {
+        asset_id: ctx.accounts.config.asset_id,
+        reserve_attester,
+        max_reserve_staleness
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateReserveAttester,
+        ctx.accounts.authority.key(),
+        reserve_attester.unwrap_or_default(),
+    )
+}
@@ -0,0 +1,64 @@
+use crate::{
+    error::TmewcError,
+    state::{Config, ReserveAttestation},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AttestReserve<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [ReserveAttestation::SEED_PREFIX, &config.asset_seed()],
+        bump = reserve_attestation.bump,
+    )]
+    reserve_attestation: Account<'info, ReserveAttestation>,
+
+    attester: Signer<'info>,
+}
+
+impl<'info> AttestReserve<'info> {
+    fn constraints(ctx: &Context<Self>, observed_at: i64) -> Result<()> {
+        require!(
+            ctx.accounts.config.reserve_attester == Some(ctx.accounts.attester.key()),
+            TmewcError::IsNotReserveAttester
+        );
+
+        require_gt!(
+            observed_at,
+            ctx.accounts.reserve_attestation.attested_at,
+            TmewcError::InvalidReserveObservation
+        );
+        require_gte!(
+            Clock::get()?.unix_timestamp,
+            observed_at,
+            TmewcError::InvalidReserveObservation
+        );
+
+        Ok(())
+    }
+}
+
+/// Record `reserve` as observed on Ethereum at `observed_at`. Observations have to be newer than
+/// the last one, so that a delayed attestation (e.g. a governance VAA relayed late) cannot roll
+/// back a fresher one.
+#[access_control(AttestReserve::constraints(&ctx, observed_at))]
+pub fn attest_reserve(ctx: Context<AttestReserve>, reserve: u64, observed_at: i64) -> Result<()> {
+    let attestation = &mut ctx.accounts.reserve_attestation;
+    attestation.reserve = reserve;
+    attestation.attested_at = observed_at;
+
+    emit_cpi!(crate::event::ReserveAttested {
+        asset_id: ctx.accounts.config.asset_id,
+        reserve,
+        attested_at: observed_at
+    });
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use crate::{
+    constants::{SEED_PREFIX_GATEWAY_CUSTODIAN, TMEWC_ASSET_ID, WORMHOLE_GATEWAY_PROGRAM_ID},
+    error::TmewcError,
+    state::{Config, SupplyCheckpoint, SupplyCheckpoints},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+
+#[derive(Accounts)]
+#[instruction(gateway_minted_amount: u64)]
+pub struct Checkpoint<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+        has_one = mint,
+    )]
+    config: Account<'info, Config>,
+
+    mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    // The Wormhole Gateway's custodian vouches for the minted amount it passes in. Only provided
+    // for tMEWC, which is the only asset the gateway mints, so tMEWC checkpoints are recorded
+    // through the gateway.
+    #[account(
+        seeds = [SEED_PREFIX_GATEWAY_CUSTODIAN],
+        bump,
+        seeds::program = WORMHOLE_GATEWAY_PROGRAM_ID,
+    )]
+    gateway_custodian: Option<Signer<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SupplyCheckpoints::compute_size(SupplyCheckpoints::MAX_CHECKPOINTS),
+        seeds = [SupplyCheckpoints::SEED_PREFIX, &config.asset_seed()],
+        bump,
+    )]
+    supply_checkpoints: Account<'info, SupplyCheckpoints>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> Checkpoint<'info> {
+    fn constraints(ctx: &Context<Self>, gateway_minted_amount: u64) -> Result<()> {
+        require_eq!(
+            ctx.accounts.gateway_custodian.is_some(),
+            ctx.accounts.config.asset_id == TMEWC_ASSET_ID,
+            TmewcError::InvalidGatewayCustodian
+        );
+
+        // Only the gateway can report a minted amount.
+        if ctx.accounts.gateway_custodian.is_none() {
+            require_eq!(
+                gateway_minted_amount,
+                0,
+                TmewcError::InvalidGatewayCustodian
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(Checkpoint::constraints(&ctx, gateway_minted_amount))]
+pub fn checkpoint(ctx: Context<Checkpoint>, gateway_minted_amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    if let Some(latest) = ctx.accounts.supply_checkpoints.latest() {
+        require!(
+            clock.unix_timestamp >= latest.timestamp + SupplyCheckpoints::MIN_INTERVAL,
+            TmewcError::CheckpointTooEarly
+        );
+    }
+
+    let checkpoints = &mut ctx.accounts.supply_checkpoints;
+    checkpoints.bump = ctx.bumps["supply_checkpoints"];
+    checkpoints.push(SupplyCheckpoint {
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+        supply: ctx.accounts.mint.supply,
+        gateway_minted_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,196 @@
+use crate::{
+    constants::{
+        GOVERNANCE_CHAIN, GOVERNANCE_MODULE, SEED_PREFIX_GOVERNANCE_AUTHORITY, SOLANA_CHAIN,
+    },
+    error::TmewcError,
+    processor::invoke_self_signed,
+    state::{Config, GovernanceReceipt},
+};
+use anchor_lang::prelude::*;
+use std::io;
+use wormhole_anchor_sdk::wormhole::{self as core_bridge, program::Wormhole as CoreBridge};
+
+/// Governance action executing one of this program's instructions.
+const ACTION_EXECUTE_INSTRUCTION: u8 = 1;
+
+/// Payload of a governance VAA, encoded big-endian like Wormhole's own governance messages:
+/// module (32 bytes), action (1 byte), target chain (2 bytes), number of accounts (1 byte), the
+/// account keys and finally the instruction data.
+#[derive(Debug, Clone)]
+pub struct GovernanceMessage {
+    pub module: [u8; 32],
+    pub action: u8,
+    pub chain: u16,
+    pub accounts: Vec<Pubkey>,
+    pub data: Vec<u8>,
+}
+
+impl AnchorDeserialize for GovernanceMessage {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut module = [0; 32];
+        reader.read_exact(&mut module)?;
+
+        let mut header = [0; 4];
+        reader.read_exact(&mut header)?;
+        let [action, chain_hi, chain_lo, num_accounts] = header;
+
+        let mut accounts = Vec::with_capacity(num_accounts.into());
+        for _ in 0..num_accounts {
+            let mut key = [0; 32];
+            reader.read_exact(&mut key)?;
+            accounts.push(Pubkey::from(key));
+        }
+
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        Ok(Self {
+            module,
+            action,
+            chain: u16::from_be_bytes([chain_hi, chain_lo]),
+            accounts,
+            data,
+        })
+    }
+}
+
+impl AnchorSerialize for GovernanceMessage {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let num_accounts = u8::try_from(self.accounts.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "too many accounts"))?;
+
+        writer.write_all(&self.module)?;
+        writer.write_all(&[self.action])?;
+        writer.write_all(&self.chain.to_be_bytes())?;
+        writer.write_all(&[num_accounts])?;
+        for key in &self.accounts {
+            writer.write_all(key.as_ref())?;
+        }
+        writer.write_all(&self.data)
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(message_hash: [u8; 32])]
+pub struct ExecuteGovernanceVaa<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        seeds = [core_bridge::SEED_PREFIX_POSTED_VAA, &message_hash],
+        bump,
+        seeds::program = core_bridge_program
+    )]
+    posted_vaa: Box<Account<'info, core_bridge::PostedVaa<GovernanceMessage>>>,
+
+    /// Replay protection. Creating this account fails if the VAA has been executed before.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + GovernanceReceipt::INIT_SPACE,
+        seeds = [
+            GovernanceReceipt::SEED_PREFIX,
+            &posted_vaa.emitter_chain().to_be_bytes(),
+            posted_vaa.emitter_address(),
+            &posted_vaa.sequence().to_be_bytes(),
+        ],
+        bump,
+    )]
+    governance_receipt: Account<'info, GovernanceReceipt>,
+
+    /// CHECK: System-owned PDA signing the admin instruction. It must hold enough lamports if the
+    /// instruction has the authority pay for anything.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_GOVERNANCE_AUTHORITY, &config.asset_seed()],
+        bump,
+    )]
+    governance_authority: UncheckedAccount<'info>,
+
+    core_bridge_program: Program<'info, CoreBridge>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteGovernanceVaa<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        let governance_emitter = ctx.accounts.config.governance_emitter;
+        require!(
+            governance_emitter != [0; 32],
+            TmewcError::NoGovernanceEmitter
+        );
+
+        let posted_vaa = &ctx.accounts.posted_vaa;
+        require!(
+            posted_vaa.emitter_chain() == GOVERNANCE_CHAIN
+                && *posted_vaa.emitter_address() == governance_emitter,
+            TmewcError::InvalidGovernanceEmitter
+        );
+
+        let msg = posted_vaa.data();
+        require!(
+            msg.module == GOVERNANCE_MODULE
+                && msg.action == ACTION_EXECUTE_INSTRUCTION
+                && msg.chain == SOLANA_CHAIN,
+            TmewcError::InvalidGovernanceMessage
+        );
+
+        // The relayer has to pass exactly the accounts governance signed off on.
+        let remaining_accounts = ctx.remaining_accounts;
+        require!(
+            msg.accounts.len() == remaining_accounts.len()
+                && msg
+                    .accounts
+                    .iter()
+                    .zip(remaining_accounts)
+                    .all(|(key, info)| key == info.key),
+            TmewcError::GovernanceAccountsMismatch
+        );
+
+        Ok(())
+    }
+}
+
+/// Execute the instruction carried by a governance VAA, signed by the governance authority PDA.
+/// Anyone can relay the VAA.
+#[access_control(ExecuteGovernanceVaa::constraints(&ctx))]
+pub fn execute_governance_vaa<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteGovernanceVaa<'info>>,
+    _message_hash: [u8; 32],
+) -> Result<()> {
+    let sequence = ctx.accounts.posted_vaa.sequence();
+
+    ctx.accounts
+        .governance_receipt
+        .set_inner(GovernanceReceipt {
+            bump: ctx.bumps["governance_receipt"],
+            sequence,
+            executed_at: Clock::get()?.unix_timestamp,
+        });
+
+    let asset_seed = ctx.accounts.config.asset_seed();
+    invoke_self_signed(
+        ctx.remaining_accounts,
+        &ctx.accounts.governance_authority,
+        &ctx.accounts.program,
+        ctx.accounts.posted_vaa.data().data.clone(),
+        &[
+            SEED_PREFIX_GOVERNANCE_AUTHORITY,
+            &asset_seed,
+            &[ctx.bumps["governance_authority"]],
+        ],
+    )?;
+
+    emit_cpi!(crate::event::GovernanceVaaExecuted {
+        asset_id: ctx.accounts.config.asset_id,
+        sequence
+    });
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use crate::{
+    constants::SEED_PREFIX_TMEWC_MINT,
+    error::TmewcError,
+    state::{Config, MintRequest, MinterInfo, ReserveAttestation},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeMint<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_TMEWC_MINT, &config.asset_seed()],
+        bump = config.mint_bump,
+        mint::authority = config,
+        mint::token_program = token_program,
+    )]
+    mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = minter,
+        has_one = recipient_token,
+        seeds = [
+            MintRequest::SEED_PREFIX,
+            &config.asset_seed(),
+            &mint_request.id.to_le_bytes()
+        ],
+        bump = mint_request.bump,
+    )]
+    mint_request: Account<'info, MintRequest>,
+
+    // The requesting minter must still be a minter.
+    #[account(
+        has_one = minter,
+        seeds = [MinterInfo::SEED_PREFIX, &config.asset_seed(), minter.key().as_ref()],
+        bump = minter_info.bump,
+    )]
+    minter_info: Account<'info, MinterInfo>,
+
+    // Required while proof of reserve is enabled.
+    #[account(
+        seeds = [ReserveAttestation::SEED_PREFIX, &config.asset_seed()],
+        bump = reserve_attestation.bump,
+    )]
+    reserve_attestation: Option<Account<'info, ReserveAttestation>>,
+
+    /// CHECK: Requesting minter, who receives the mint request's rent back.
+    #[account(mut)]
+    minter: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    recipient_token: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    // The tMEWC mint may be owned by either SPL Token or Token-2022.
+    token_program: Interface<'info, token_interface::TokenInterface>,
+}
+
+impl<'info> FinalizeMint<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, TmewcError::IsPaused);
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        require_gte!(
+            timestamp,
+            ctx.accounts.mint_request.finalizable_at,
+            TmewcError::MintRequestNotFinalizable
+        );
+
+        ReserveAttestation::check_backing(
+            ctx.accounts.reserve_attestation.as_deref(),
+            &ctx.accounts.config,
+            ctx.accounts.mint.supply,
+            ctx.accounts.mint_request.amount,
+            timestamp,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[access_control(FinalizeMint::constraints(&ctx))]
+pub fn finalize_mint(ctx: Context<FinalizeMint>) -> Result<bool> {
+    let amount = ctx.accounts.mint_request.amount;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // Like instant mints, pause instead of failing when the mint looks anomalous. The request is
+    // kept so it can be finalized once the program is unpaused (or cancelled by a guardian).
+    if !ctx.accounts.config.try_track_mint(amount, timestamp) {
+        ctx.accounts.config.paused = true;
+
+        emit_cpi!(crate::event::AutoPaused {
+            asset_id: ctx.accounts.config.asset_id,
+            minter: ctx.accounts.minter.key(),
+            amount
+        });
+
+        return Ok(false);
+    }
+
+    let asset_seed = ctx.accounts.config.asset_seed();
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.recipient_token.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[&[
+                Config::SEED_PREFIX,
+                &asset_seed,
+                &[ctx.accounts.config.bump],
+            ]],
+        ),
+        amount,
+    )?;
+
+    // Reload the mint to report the post-mint supply.
+    ctx.accounts.mint.reload()?;
+
+    emit_cpi!(crate::event::MintRequestFinalized {
+        asset_id: ctx.accounts.config.asset_id,
+        id: ctx.accounts.mint_request.id
+    });
+
+    emit_cpi!(crate::event::Minted {
+        asset_id: ctx.accounts.config.asset_id,
+        minter: ctx.accounts.minter.key(),
+        recipient: ctx.accounts.recipient_token.owner,
+        amount,
+        supply: ctx.accounts.mint.supply
+    });
+
+    // Return the request's rent to the minter.
+    ctx.accounts
+        .mint_request
+        .close(ctx.accounts.minter.to_account_info())?;
+
+    Ok(true)
+}
@@ -1,35 +1,56 @@
 use crate::{
     constants::SEED_PREFIX_TMEWC_MINT,
     error::TmewcError,
-    state::{Config, MinterInfo},
+    state::{Config, MinterInfo, ReserveAttestation, Role, RoleAssignment},
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token;
+use anchor_spl::token_interface;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Mint<'info> {
     // Use the correct token mint for the program.
     #[account(
         mut,
-        seeds = [SEED_PREFIX_TMEWC_MINT],
+        seeds = [SEED_PREFIX_TMEWC_MINT, &config.asset_seed()],
         bump = config.mint_bump,
         mint::authority = config,
+        mint::token_program = token_program,
     )]
-    mint: Account<'info, token::Mint>,
+    mint: InterfaceAccount<'info, token_interface::Mint>,
 
     #[account(
-        seeds = [Config::SEED_PREFIX],
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
         bump = config.bump,
     )]
     config: Account<'info, Config>,
 
-    // Require the signing minter to match a valid minter info.
+    // Require the signing minter to match a valid minter info or hold the minter role.
     #[account(
         has_one = minter,
-        seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
+        seeds = [MinterInfo::SEED_PREFIX, &config.asset_seed(), minter.key().as_ref()],
         bump = minter_info.bump,
     )]
-    minter_info: Account<'info, MinterInfo>,
+    minter_info: Option<Account<'info, MinterInfo>>,
+
+    #[account(
+        seeds = [
+            RoleAssignment::SEED_PREFIX,
+            &config.asset_seed(),
+            &[Role::Minter as u8],
+            minter.key().as_ref()
+        ],
+        bump = role_assignment.bump,
+    )]
+    role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    // Required while proof of reserve is enabled.
+    #[account(
+        seeds = [ReserveAttestation::SEED_PREFIX, &config.asset_seed()],
+        bump = reserve_attestation.bump,
+    )]
+    reserve_attestation: Option<Account<'info, ReserveAttestation>>,
 
     minter: Signer<'info>,
 
@@ -37,33 +58,86 @@ pub struct Mint<'info> {
     #[account(
         mut,
         token::mint = mint,
+        token::token_program = token_program,
     )]
-    recipient_token: Account<'info, token::TokenAccount>,
+    recipient_token: InterfaceAccount<'info, token_interface::TokenAccount>,
 
-    token_program: Program<'info, token::Token>,
+    // The tMEWC mint may be owned by either SPL Token or Token-2022.
+    token_program: Interface<'info, token_interface::TokenInterface>,
 }
 
 impl<'info> Mint<'info> {
-    fn constraints(ctx: &Context<Self>) -> Result<()> {
+    fn constraints(ctx: &Context<Self>, amount: u64) -> Result<()> {
         // Can not mint when paused.
         require!(!ctx.accounts.config.paused, TmewcError::IsPaused);
 
+        match &ctx.accounts.minter_info {
+            // Optimistic minters have to go through the request flow.
+            Some(minter_info) => require!(!minter_info.optimistic, TmewcError::MinterIsOptimistic),
+            None => require!(
+                ctx.accounts.role_assignment.is_some(),
+                TmewcError::SignerNotMinter
+            ),
+        }
+
+        ReserveAttestation::check_backing(
+            ctx.accounts.reserve_attestation.as_deref(),
+            &ctx.accounts.config,
+            ctx.accounts.mint.supply,
+            amount,
+            Clock::get()?.unix_timestamp,
+        )?;
+
         Ok(())
     }
 }
 
-#[access_control(Mint::constraints(&ctx))]
-pub fn mint(ctx: Context<Mint>, amount: u64) -> Result<()> {
-    token::mint_to(
+#[access_control(Mint::constraints(&ctx, amount))]
+pub fn mint(ctx: Context<Mint>, amount: u64) -> Result<bool> {
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // Pause instead of failing when the mint looks anomalous, so the pause is persisted even if no
+    // guardian is around to react.
+    if !ctx.accounts.config.try_track_mint(amount, timestamp) {
+        ctx.accounts.config.paused = true;
+
+        emit_cpi!(crate::event::AutoPaused {
+            asset_id: ctx.accounts.config.asset_id,
+            minter: ctx.accounts.minter.key(),
+            amount
+        });
+
+        return Ok(false);
+    }
+
+    let asset_seed = ctx.accounts.config.asset_seed();
+    token_interface::mint_to(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            token::MintTo {
+            token_interface::MintTo {
                 mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.recipient_token.to_account_info(),
                 authority: ctx.accounts.config.to_account_info(),
             },
-            &[&[Config::SEED_PREFIX, &[ctx.accounts.config.bump]]],
+            &[&[
+                Config::SEED_PREFIX,
+                &asset_seed,
+                &[ctx.accounts.config.bump],
+            ]],
         ),
         amount,
-    )
+    )?;
+
+    // Reload the mint to report the post-mint supply.
+    ctx.accounts.mint.reload()?;
+
+    emit_cpi!(crate::event::Minted {
+        asset_id: ctx.accounts.config.asset_id,
+        minter: ctx.accounts.minter.key(),
+        recipient: ctx.accounts.recipient_token.owner,
+        amount,
+        supply: ctx.accounts.mint.supply
+    });
+
+    Ok(true)
 }
@@ -1,5 +1,26 @@
 mod admin;
 pub use admin::*;
 
+mod attest_reserve;
+pub use attest_reserve::*;
+
+mod checkpoint;
+pub use checkpoint::*;
+
+mod execute_as_eth_authority;
+pub use execute_as_eth_authority::*;
+
+mod execute_governance_vaa;
+pub use execute_governance_vaa::*;
+
+mod finalize_mint;
+pub use finalize_mint::*;
+
 mod mint;
 pub use mint::*;
+
+mod request_mint;
+pub use request_mint::*;
+
+mod self_cpi;
+pub(crate) use self_cpi::*;
@@ -0,0 +1,103 @@
+use crate::{
+    error::TmewcError,
+    state::{Config, MintRequest, MinterInfo},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RequestMint<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX, &config.asset_seed()],
+        bump = config.bump,
+        has_one = mint,
+    )]
+    config: Account<'info, Config>,
+
+    mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    // Require the signing minter to match a valid minter info.
+    #[account(
+        has_one = minter,
+        seeds = [MinterInfo::SEED_PREFIX, &config.asset_seed(), minter.key().as_ref()],
+        bump = minter_info.bump,
+    )]
+    minter_info: Account<'info, MinterInfo>,
+
+    #[account(mut)]
+    minter: Signer<'info>,
+
+    #[account(
+        init,
+        payer = minter,
+        space = 8 + MintRequest::INIT_SPACE,
+        seeds = [
+            MintRequest::SEED_PREFIX,
+            &config.asset_seed(),
+            &config.next_mint_request_id.to_le_bytes()
+        ],
+        bump,
+    )]
+    mint_request: Account<'info, MintRequest>,
+
+    #[account(token::mint = mint)]
+    recipient_token: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> RequestMint<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, TmewcError::IsPaused);
+
+        // Instant minters have to mint directly.
+        require!(
+            ctx.accounts.minter_info.optimistic,
+            TmewcError::MinterIsNotOptimistic
+        );
+
+        // Without a delay, optimistic mints could be finalized right away.
+        require_gt!(
+            ctx.accounts.config.optimistic_minting_delay,
+            0,
+            TmewcError::NoOptimisticMintingDelay
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(RequestMint::constraints(&ctx))]
+pub fn request_mint(ctx: Context<RequestMint>, amount: u64) -> Result<()> {
+    let id = ctx.accounts.config.next_mint_request_id;
+    let minter = ctx.accounts.minter.key();
+    let recipient_token = ctx.accounts.recipient_token.key();
+    let requested_at = Clock::get()?.unix_timestamp;
+    let finalizable_at = requested_at + i64::from(ctx.accounts.config.optimistic_minting_delay);
+
+    ctx.accounts.mint_request.set_inner(MintRequest {
+        bump: ctx.bumps["mint_request"],
+        id,
+        minter,
+        recipient_token,
+        amount,
+        requested_at,
+        finalizable_at,
+    });
+
+    // Update config.
+    ctx.accounts.config.next_mint_request_id += 1;
+
+    emit_cpi!(crate::event::MintRequested {
+        asset_id: ctx.accounts.config.asset_id,
+        id,
+        minter,
+        recipient_token,
+        amount,
+        finalizable_at
+    });
+
+    Ok(())
+}
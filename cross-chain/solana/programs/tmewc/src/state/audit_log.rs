@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum AuditAction {
+    Initialize,
+    ChangeAuthority,
+    CancelAuthorityChange,
+    TakeAuthority,
+    AddMinter,
+    RemoveMinter,
+    AddGuardian,
+    RemoveGuardian,
+    Pause,
+    Unpause,
+    UpdateAnomalyGuard,
+    UpdateMinterMode,
+    UpdateOptimisticMintingDelay,
+    CancelMintRequest,
+    ScheduleMintAuthorityTransfer,
+    ExecuteMintAuthorityTransfer,
+    SetEthAuthority,
+    UpdateGovernanceEmitter,
+    GrantRole,
+    RevokeRole,
+    RenounceMinter,
+    RenounceGuardian,
+    UpdateReserveAttester,
+    MigrateConfig,
+    CancelMintAuthorityTransfer,
+    UpdateMetadata,
+    MigrateMinterInfo,
+    MigrateGuardianInfo,
+    FreezeTokenAccount,
+    ThawTokenAccount,
+    RenounceRole,
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct AuditEntry {
+    pub action: AuditAction,
+    pub actor: Pubkey,
+    /// Account the action was applied to. Default pubkey if the action has no target.
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+/// Ring buffer of the most recent admin actions. Once full, the oldest entry (found at
+/// `next_index`) is overwritten.
+#[account]
+#[derive(Debug)]
+pub struct AuditLog {
+    pub bump: u8,
+    pub next_index: u32,
+    pub entries: Vec<AuditEntry>,
+}
+
+impl AuditLog {
+    pub const SEED_PREFIX: &'static [u8] = b"audit-log";
+
+    pub const MAX_ENTRIES: usize = 64;
+
+    pub(crate) fn compute_size(num_entries: usize) -> usize {
+        8 + 1 + 4 + 4 + num_entries * AuditEntry::INIT_SPACE
+    }
+
+    pub(crate) fn record(
+        &mut self,
+        action: AuditAction,
+        actor: Pubkey,
+        target: Pubkey,
+    ) -> Result<()> {
+        let entry = AuditEntry {
+            action,
+            actor,
+            target,
+            slot: Clock::get()?.slot,
+        };
+
+        let index = self.next_index as usize;
+        if index < self.entries.len() {
+            self.entries[index] = entry;
+        } else {
+            self.entries.push(entry);
+        }
+
+        self.next_index = ((index + 1) % Self::MAX_ENTRIES) as u32;
+
+        Ok(())
+    }
+}
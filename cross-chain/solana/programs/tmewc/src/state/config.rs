@@ -1,5 +1,16 @@
+use crate::constants::TMEWC_ASSET_ID;
 use anchor_lang::prelude::*;
 
+/// Seed that namespaces the accounts of an asset. tMEWC's accounts predate asset IDs, so its seed
+/// is empty and its accounts keep their original addresses.
+pub fn asset_seed(asset_id: u16) -> Vec<u8> {
+    if asset_id == TMEWC_ASSET_ID {
+        Vec::new()
+    } else {
+        asset_id.to_le_bytes().to_vec()
+    }
+}
+
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct Config {
@@ -17,8 +28,80 @@ pub struct Config {
     pub num_minters: u32,
     pub num_guardians: u32,
     pub paused: bool,
+
+    // Anomaly guard. A zero threshold disables the corresponding check.
+    pub max_mint_amount: u64,
+    pub mint_window_limit: u64,
+    pub mint_window_duration: u32,
+    pub mint_window_start: i64,
+    pub mint_window_amount: u64,
+
+    // Optimistic minting.
+    pub optimistic_minting_delay: u32,
+    pub next_mint_request_id: u64,
+
+    // Mint authority hand-off. Once transferred, minting is paused for good.
+    pub successor_authority: Option<Pubkey>,
+    pub mint_authority_transfer_at: i64,
+    pub mint_authority_transferred: bool,
+
+    // Ethereum authority, whose secp256k1 signatures authorize admin instructions signed by the
+    // eth authority PDA.
+    pub eth_authority: Option<[u8; 20]>,
+    pub eth_authority_nonce: u64,
+
+    /// Emitter of governance VAAs on Ethereum. Zero if not registered.
+    pub governance_emitter: [u8; 32],
+
+    // Proof of reserve. Disabled while no attester is set.
+    pub reserve_attester: Option<Pubkey>,
+    pub max_reserve_staleness: u32,
+
+    /// Namespaces this asset's mint and accounts. Appended last, so that configs created before
+    /// asset IDs existed migrate as tMEWC.
+    pub asset_id: u16,
+
+    /// Incremented every time a guardian is added. See `GuardianInfo::epoch`.
+    pub guardian_epoch: u64,
 }
 
 impl Config {
     pub const SEED_PREFIX: &'static [u8] = b"config";
+
+    /// Time that has to pass between scheduling and executing a mint authority transfer.
+    pub const MINT_AUTHORITY_TRANSFER_DELAY: i64 = 7 * 24 * 60 * 60;
+
+    /// Seed that namespaces this asset's accounts. See [asset_seed].
+    pub fn asset_seed(&self) -> Vec<u8> {
+        asset_seed(self.asset_id)
+    }
+
+    /// Account for a mint of `amount` in the current minting window. Returns `false` without
+    /// tracking anything if the mint exceeds the single-mint size or the per-window threshold.
+    pub(crate) fn try_track_mint(&mut self, amount: u64, timestamp: i64) -> bool {
+        if self.max_mint_amount != 0 && amount > self.max_mint_amount {
+            return false;
+        }
+
+        // Start a new window if the current one has elapsed.
+        let window_elapsed =
+            timestamp >= self.mint_window_start + i64::from(self.mint_window_duration);
+        let window_amount = if window_elapsed {
+            0
+        } else {
+            self.mint_window_amount
+        };
+
+        let updated_window_amount = window_amount.saturating_add(amount);
+        if self.mint_window_limit != 0 && updated_window_amount > self.mint_window_limit {
+            return false;
+        }
+
+        if window_elapsed {
+            self.mint_window_start = timestamp;
+        }
+        self.mint_window_amount = updated_window_amount;
+
+        true
+    }
 }
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Marks a governance VAA as executed. Seeded by the VAA's emitter and sequence so it can only be
+/// created once.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct GovernanceReceipt {
+    pub bump: u8,
+    pub sequence: u64,
+    pub executed_at: i64,
+}
+
+impl GovernanceReceipt {
+    pub const SEED_PREFIX: &'static [u8] = b"governance-receipt";
+}
@@ -5,6 +5,14 @@ use anchor_lang::prelude::*;
 pub struct GuardianInfo {
     pub bump: u8,
     pub guardian: Pubkey,
+
+    /// Nonce the guardian's next off-chain signed pause has to carry.
+    pub pause_nonce: u64,
+
+    /// Guardian epoch of the config when this guardian was added. Signed pauses commit to it, so
+    /// that signatures from an earlier tenure of the same key cannot be replayed once its nonce
+    /// starts over.
+    pub epoch: u64,
 }
 
 impl GuardianInfo {
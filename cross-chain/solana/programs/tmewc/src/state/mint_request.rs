@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct MintRequest {
+    pub bump: u8,
+    pub id: u64,
+    pub minter: Pubkey,
+    pub recipient_token: Pubkey,
+    pub amount: u64,
+    pub requested_at: i64,
+
+    /// Fixed when requested, so that later changes to the optimistic minting delay do not affect
+    /// pending requests.
+    pub finalizable_at: i64,
+}
+
+impl MintRequest {
+    pub const SEED_PREFIX: &'static [u8] = b"mint-request";
+}
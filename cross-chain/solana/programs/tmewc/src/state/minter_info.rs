@@ -5,6 +5,10 @@ use anchor_lang::prelude::*;
 pub struct MinterInfo {
     pub minter: Pubkey,
     pub bump: u8,
+
+    /// Whether this minter must go through `request_mint` and `finalize_mint` instead of minting
+    /// instantly.
+    pub optimistic: bool,
 }
 
 impl MinterInfo {
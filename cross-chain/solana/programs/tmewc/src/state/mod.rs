@@ -1,14 +1,32 @@
+mod audit_log;
+pub use audit_log::*;
+
 mod config;
 pub use config::*;
 
+mod governance_receipt;
+pub use governance_receipt::*;
+
 mod guardian_info;
 pub use guardian_info::*;
 
 mod guardians;
 pub use guardians::*;
 
+mod mint_request;
+pub use mint_request::*;
+
 mod minter_info;
 pub use minter_info::*;
 
 mod minters;
 pub use minters::*;
+
+mod reserve_attestation;
+pub use reserve_attestation::*;
+
+mod role_assignment;
+pub use role_assignment::*;
+
+mod supply_checkpoints;
+pub use supply_checkpoints::*;
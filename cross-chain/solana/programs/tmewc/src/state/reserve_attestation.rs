@@ -0,0 +1,70 @@
+use crate::{error::TmewcError, state::Config};
+use anchor_lang::prelude::*;
+
+/// Latest attested amount of the asset locked on Ethereum for Solana. While a reserve attester is
+/// set, mints must keep the supply at or below this reserve.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct ReserveAttestation {
+    pub bump: u8,
+    pub reserve: u64,
+
+    /// Time the reserve was observed on Ethereum, which is what staleness is measured from.
+    pub attested_at: i64,
+}
+
+impl ReserveAttestation {
+    pub const SEED_PREFIX: &'static [u8] = b"reserve-attestation";
+
+    /// Check that minting `amount` on top of `supply` stays backed by a fresh attestation. Passes
+    /// when proof of reserve is disabled (no attester set).
+    pub(crate) fn check_backing(
+        attestation: Option<&Self>,
+        config: &Config,
+        supply: u64,
+        amount: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        if config.reserve_attester.is_none() {
+            return Ok(());
+        }
+
+        let attestation = attestation.ok_or(TmewcError::ReserveAttestationRequired)?;
+        require!(
+            !attestation.is_stale(config, timestamp),
+            TmewcError::ReserveAttestationStale
+        );
+        require_gte!(
+            attestation.reserve,
+            supply.saturating_add(amount),
+            TmewcError::ReserveExceeded
+        );
+
+        Ok(())
+    }
+
+    /// Amount that can be minted on top of `supply` without failing `check_backing`: unbounded
+    /// when proof of reserve is disabled and zero when the attestation is missing or stale. Lets
+    /// minters deliver the asset some other way instead of failing the mint.
+    pub fn mintable(
+        attestation: Option<&Self>,
+        config: &Config,
+        supply: u64,
+        timestamp: i64,
+    ) -> u64 {
+        if config.reserve_attester.is_none() {
+            return u64::MAX;
+        }
+
+        match attestation {
+            Some(attestation) if !attestation.is_stale(config, timestamp) => {
+                attestation.reserve.saturating_sub(supply)
+            }
+            _ => 0,
+        }
+    }
+
+    fn is_stale(&self, config: &Config, timestamp: i64) -> bool {
+        timestamp > self.attested_at + i64::from(config.max_reserve_staleness)
+    }
+}
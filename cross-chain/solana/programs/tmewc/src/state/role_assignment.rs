@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// Duties that can be granted to members besides `Config.authority`, minters and guardians.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum Role {
+    /// Mint instantly, like a non-optimistic minter. Holders are listed in `Minters` and counted
+    /// in `Config.num_minters` alongside minters added with `add_minter`.
+    Minter,
+    /// Pause, like a guardian.
+    Pauser,
+    /// Unpause.
+    Unpauser,
+    /// Update the token metadata.
+    MetadataAdmin,
+    /// Update the anomaly guard thresholds and the optimistic minting delay.
+    LimitAdmin,
+    /// Freeze and thaw token accounts, with the config as the mint's freeze authority.
+    FreezeAdmin,
+}
+
+/// Grants `role` to `member`. The account only exists while the role is held.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RoleAssignment {
+    pub bump: u8,
+    pub role: Role,
+    pub member: Pubkey,
+}
+
+impl RoleAssignment {
+    pub const SEED_PREFIX: &'static [u8] = b"role-assignment";
+}
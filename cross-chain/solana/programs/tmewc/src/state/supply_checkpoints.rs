@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct SupplyCheckpoint {
+    pub slot: u64,
+    pub timestamp: i64,
+    pub supply: u64,
+    pub gateway_minted_amount: u64,
+}
+
+/// Ring buffer of the most recent supply checkpoints. Once full, the oldest checkpoint (found at
+/// `next_index`) is overwritten.
+///
+/// Checkpoints are permissionless snapshots taken at most once per `MIN_INTERVAL`, not a record of
+/// every supply change: nothing is recorded on mint or burn, since tokens can be burned without
+/// this program being involved. A full buffer covers `RETENTION` (longer if nobody checkpoints
+/// every interval), which is the window reconciliation runs over. The supply at a slot between two
+/// checkpoints has to be derived from the nearest checkpoint and the `Minted` events and burns in
+/// between, and history older than `RETENTION` from an archive of this account.
+#[account]
+#[derive(Debug)]
+pub struct SupplyCheckpoints {
+    pub bump: u8,
+    pub next_index: u32,
+    pub checkpoints: Vec<SupplyCheckpoint>,
+}
+
+impl SupplyCheckpoints {
+    pub const SEED_PREFIX: &'static [u8] = b"supply-checkpoints";
+
+    /// Minimum number of seconds between two checkpoints, so that the history cannot be flushed
+    /// by spamming checkpoints.
+    pub const MIN_INTERVAL: i64 = 3 * 60 * 60;
+
+    /// Shortest period a full buffer covers.
+    pub const RETENTION: i64 = 30 * 24 * 60 * 60;
+
+    /// `RETENTION` worth of checkpoints at the minimum checkpoint interval. The account stays
+    /// under the 10 KiB that can be allocated through CPI, which is how the gateway creates it.
+    pub const MAX_CHECKPOINTS: usize = (Self::RETENTION / Self::MIN_INTERVAL) as usize;
+
+    pub(crate) fn compute_size(num_checkpoints: usize) -> usize {
+        8 + 1 + 4 + 4 + num_checkpoints * SupplyCheckpoint::INIT_SPACE
+    }
+
+    pub(crate) fn latest(&self) -> Option<&SupplyCheckpoint> {
+        if self.checkpoints.is_empty() {
+            return None;
+        }
+
+        let index = (self.next_index as usize + Self::MAX_CHECKPOINTS - 1) % Self::MAX_CHECKPOINTS;
+        self.checkpoints.get(index)
+    }
+
+    pub(crate) fn push(&mut self, checkpoint: SupplyCheckpoint) {
+        let index = self.next_index as usize;
+        if index < self.checkpoints.len() {
+            self.checkpoints[index] = checkpoint;
+        } else {
+            self.checkpoints.push(checkpoint);
+        }
+
+        self.next_index = ((index + 1) % Self::MAX_CHECKPOINTS) as u32;
+    }
+}
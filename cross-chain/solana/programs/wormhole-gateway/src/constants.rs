@@ -14,5 +14,28 @@ pub const TMEWC_ETHEREUM_TOKEN_ADDRESS: [u8; 32] = [
     0xe7, 0xcc, 0x54, 0xa5, 0x9e, 0x31, 0x5f, 0xf1, 0xeb, 0x26, 0x66, 0x86, 0xa9, 0x37,
 ];
 
+/// Wormhole's Token Bridge carries amounts with at most this many decimals.
+pub const MAX_WORMHOLE_DECIMALS: u8 = 8;
+
 /// A.K.A. b"msg".
 pub const MSG_SEED_PREFIX: &[u8] = b"msg";
+
+/// Seed of the system-owned PDA that signs admin instructions authorized by the Ethereum
+/// authority. Set it as `Custodian.authority` to hand admin control to an Ethereum address.
+pub const SEED_PREFIX_ETH_AUTHORITY: &[u8] = b"eth-authority";
+
+/// Prefix of the message the Ethereum authority signs.
+pub const ETH_AUTHORITY_DOMAIN: &[u8] = b"wormhole-gateway eth authority";
+
+/// Seed of the system-owned PDA that signs admin instructions carried by governance VAAs. Set it
+/// as `Custodian.authority` to hand admin control to the governance contract.
+pub const SEED_PREFIX_GOVERNANCE_AUTHORITY: &[u8] = b"governance-authority";
+
+/// Wormhole chain ID of Ethereum, where the governance contract lives.
+pub const GOVERNANCE_CHAIN: u16 = 2;
+
+/// Wormhole chain ID of Solana, which governance messages have to target.
+pub const SOLANA_CHAIN: u16 = 1;
+
+/// Module of governance messages for this program, "WormholeGateway" left-padded to 32 bytes.
+pub const GOVERNANCE_MODULE: [u8; 32] = *b"\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0WormholeGateway";
@@ -23,6 +23,18 @@ pub enum WormholeGatewayError {
     #[msg("Amount must not be 0")]
     ZeroAmount = 0x50,
 
+    #[msg("Amount too low to bridge")]
+    AmountTooLowToBridge = 0x52,
+
+    #[msg("Wrapped amount to bridge exceeds u64")]
+    WrappedAmountOverflow = 0x54,
+
+    #[msg("tMEWC amount to mint exceeds u64")]
+    TmewcAmountOverflow = 0x56,
+
+    #[msg("Mint decimals are too far apart to normalize amounts")]
+    InvalidMintDecimals = 0x58,
+
     #[msg("Token Bridge transfer already redeemed")]
     TransferAlreadyRedeemed = 0x70,
 
@@ -40,4 +52,79 @@ pub enum WormholeGatewayError {
 
     #[msg("Minted amount after deposit exceeds u64")]
     MintedAmountOverflow = 0xb2,
+
+    #[msg("No Ethereum authority set")]
+    NoEthAuthority = 0xc0,
+
+    #[msg("Missing or invalid Ethereum authority signature")]
+    InvalidEthAuthorization = 0xc2,
+
+    #[msg("No governance emitter registered")]
+    NoGovernanceEmitter = 0xd0,
+
+    #[msg("VAA was not emitted by the governance emitter")]
+    InvalidGovernanceEmitter = 0xd2,
+
+    #[msg("Governance message is not meant for this program")]
+    InvalidGovernanceMessage = 0xd4,
+
+    #[msg("Accounts do not match the governance message")]
+    GovernanceAccountsMismatch = 0xd6,
+
+    #[msg("Inbound bridging is paused")]
+    InboundPaused = 0xe0,
+
+    #[msg("Outbound bridging is paused")]
+    OutboundPaused = 0xe2,
+
+    #[msg("Deposits are paused")]
+    DepositPaused = 0xe4,
+
+    #[msg("No pause flag set")]
+    NoPauseFlags = 0xe6,
+
+    #[msg("Gateway is disabled")]
+    GatewayDisabled = 0xf0,
+
+    #[msg("Gateway is not disabled")]
+    GatewayNotDisabled = 0xf2,
+
+    #[msg("Amount exceeds the outbound limit per transfer")]
+    OutboundTransferLimitExceeded = 0x100,
+
+    #[msg("Amount exceeds the outbound limit of the current window")]
+    OutboundWindowLimitExceeded = 0x102,
+
+    #[msg("Amount exceeds the inbound rate limit")]
+    InboundRateLimitExceeded = 0x110,
+
+    #[msg("Pending receipt must be provided exactly for large transfers")]
+    InvalidPendingReceipt = 0x120,
+
+    #[msg("Pending receipt cannot be released yet")]
+    PendingReceiptDelayed = 0x122,
+
+    #[msg("Pending receipt can no longer be cancelled")]
+    PendingReceiptReleasable = 0x124,
+
+    #[msg("Pending receipt was cancelled")]
+    PendingReceiptCancelled = 0x126,
+
+    #[msg("Pending receipt must be cancelled to be refunded")]
+    PendingReceiptNotCancelled = 0x128,
+
+    #[msg("Pending mint must be provided exactly in escrow mode")]
+    InvalidPendingMint = 0x130,
+
+    #[msg("Transfer was not sent by a registered gateway")]
+    InvalidSendingGateway = 0x140,
+
+    #[msg("TMEWC config does not belong to the tMEWC mint")]
+    InvalidTmewcConfig = 0x150,
+
+    #[msg("TMEWC reserve attestation must be provided while proof of reserve is enabled")]
+    InvalidTmewcReserveAttestation = 0x152,
+
+    #[msg("Gateway infos must be provided exactly for the given chains")]
+    InvalidGatewayInfo = 0x160,
 }
@@ -1,3 +1,4 @@
+use crate::state::{OverLimitMode, PauseFlags};
 use anchor_lang::prelude::*;
 
 #[event]
@@ -22,13 +23,183 @@ pub struct WormholeTmewcDeposited {
     pub amount: u64,
 }
 
+#[event]
+pub struct PendingReceiptQueued {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub message_hash: [u8; 32],
+    pub release_at: i64,
+}
+
+#[event]
+pub struct PendingReceiptReleased {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub message_hash: [u8; 32],
+}
+
+#[event]
+pub struct PendingReceiptCancelled {
+    pub guardian: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub message_hash: [u8; 32],
+}
+
+#[event]
+pub struct PendingReceiptRefunded {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub message_hash: [u8; 32],
+}
+
+#[event]
+pub struct WormholeTmewcEscrowed {
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct WormholeTmewcPartiallyMinted {
+    pub recipient: Pubkey,
+    pub minted: u64,
+    pub wrapped: u64,
+}
+
+#[event]
+pub struct PendingMintProcessed {
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
 #[event]
 pub struct GatewayAddressUpdated {
     pub chain: u16,
     pub gateway: [u8; 32],
 }
 
+#[event]
+pub struct GatewayDisabled {
+    pub chain: u16,
+}
+
+#[event]
+pub struct GatewayEnabled {
+    pub chain: u16,
+}
+
+#[event]
+pub struct GatewayRemoved {
+    pub chain: u16,
+    pub gateway: [u8; 32],
+}
+
+#[event]
+pub struct GatewayAllowlistUpdated {
+    pub enabled: bool,
+}
+
+#[event]
+pub struct OutboundLimitUpdated {
+    pub chain: u16,
+    pub max_transfer_amount: u64,
+    pub window_limit: u64,
+    pub window_duration: u32,
+}
+
+#[event]
+pub struct DefaultOutboundLimitUpdated {
+    pub max_transfer_amount: u64,
+    pub window_limit: u64,
+    pub window_duration: u32,
+}
+
 #[event]
 pub struct MintingLimitUpdated {
     pub minting_limit: u64,
 }
+
+#[event]
+pub struct InboundRateLimitUpdated {
+    pub capacity: u64,
+    pub window_duration: u32,
+}
+
+#[event]
+pub struct LargeTransferDelayUpdated {
+    pub threshold: u64,
+    pub delay: u32,
+}
+
+#[event]
+pub struct OverLimitModeUpdated {
+    pub over_limit_mode: OverLimitMode,
+    pub partial_mint: bool,
+}
+
+#[event]
+pub struct Initialized {
+    pub authority: Pubkey,
+    pub tmewc_mint: Pubkey,
+    pub wrapped_tmewc_mint: Pubkey,
+    pub minting_limit: u64,
+}
+
+#[event]
+pub struct AuthorityChangeStaged {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityChangeCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityChanged {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct EthAuthorityUpdated {
+    pub eth_authority: Option<[u8; 20]>,
+}
+
+#[event]
+pub struct EthAuthorityExecuted {
+    pub nonce: u64,
+}
+
+#[event]
+pub struct GovernanceEmitterUpdated {
+    pub governance_emitter: [u8; 32],
+}
+
+#[event]
+pub struct GovernanceVaaExecuted {
+    pub sequence: u64,
+}
+
+#[event]
+pub struct GuardianAdded {
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct GuardianRemoved {
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct Paused {
+    pub guardian: Pubkey,
+    pub paused: PauseFlags,
+}
+
+#[event]
+pub struct Unpaused {
+    pub paused: PauseFlags,
+}
@@ -34,6 +34,14 @@ pub mod wormhole_gateway {
         processor::initialize(ctx, minting_limit)
     }
 
+    pub fn migrate_custodian(ctx: Context<MigrateCustodian>, chains: Vec<u16>) -> Result<()> {
+        processor::migrate_custodian(ctx, chains)
+    }
+
+    pub fn migrate_gateway_info(ctx: Context<MigrateGatewayInfo>, chain: u16) -> Result<()> {
+        processor::migrate_gateway_info(ctx, chain)
+    }
+
     pub fn change_authority(ctx: Context<ChangeAuthority>) -> Result<()> {
         processor::change_authority(ctx)
     }
@@ -53,14 +61,137 @@ pub mod wormhole_gateway {
         processor::update_gateway_address(ctx, args)
     }
 
+    pub fn disable_gateway(ctx: Context<DisableGateway>, chain: u16) -> Result<()> {
+        processor::disable_gateway(ctx, chain)
+    }
+
+    pub fn enable_gateway(ctx: Context<EnableGateway>, chain: u16) -> Result<()> {
+        processor::enable_gateway(ctx, chain)
+    }
+
+    pub fn remove_gateway(ctx: Context<RemoveGateway>, chain: u16) -> Result<()> {
+        processor::remove_gateway(ctx, chain)
+    }
+
+    pub fn set_gateway_allowlist(ctx: Context<SetGatewayAllowlist>, enabled: bool) -> Result<()> {
+        processor::set_gateway_allowlist(ctx, enabled)
+    }
+
     pub fn update_minting_limit(ctx: Context<UpdateMintingLimit>, new_limit: u64) -> Result<()> {
         processor::update_minting_limit(ctx, new_limit)
     }
 
+    pub fn update_inbound_rate_limit(
+        ctx: Context<UpdateInboundRateLimit>,
+        args: UpdateInboundRateLimitArgs,
+    ) -> Result<()> {
+        processor::update_inbound_rate_limit(ctx, args)
+    }
+
+    pub fn update_large_transfer_delay(
+        ctx: Context<UpdateLargeTransferDelay>,
+        args: UpdateLargeTransferDelayArgs,
+    ) -> Result<()> {
+        processor::update_large_transfer_delay(ctx, args)
+    }
+
+    pub fn update_outbound_limit(
+        ctx: Context<UpdateOutboundLimit>,
+        args: UpdateOutboundLimitArgs,
+    ) -> Result<()> {
+        processor::update_outbound_limit(ctx, args)
+    }
+
+    pub fn update_default_outbound_limit(
+        ctx: Context<UpdateDefaultOutboundLimit>,
+        args: UpdateDefaultOutboundLimitArgs,
+    ) -> Result<()> {
+        processor::update_default_outbound_limit(ctx, args)
+    }
+
+    pub fn update_over_limit_mode(
+        ctx: Context<UpdateOverLimitMode>,
+        args: UpdateOverLimitModeArgs,
+    ) -> Result<()> {
+        processor::update_over_limit_mode(ctx, args)
+    }
+
+    pub fn set_eth_authority(
+        ctx: Context<SetEthAuthority>,
+        eth_authority: Option<[u8; 20]>,
+    ) -> Result<()> {
+        processor::set_eth_authority(ctx, eth_authority)
+    }
+
+    pub fn execute_as_eth_authority<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteAsEthAuthority<'info>>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        processor::execute_as_eth_authority(ctx, data)
+    }
+
+    pub fn update_governance_emitter(
+        ctx: Context<UpdateGovernanceEmitter>,
+        governance_emitter: [u8; 32],
+    ) -> Result<()> {
+        processor::update_governance_emitter(ctx, governance_emitter)
+    }
+
+    pub fn execute_governance_vaa<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteGovernanceVaa<'info>>,
+        message_hash: [u8; 32],
+    ) -> Result<()> {
+        processor::execute_governance_vaa(ctx, message_hash)
+    }
+
+    pub fn add_guardian(ctx: Context<AddGuardian>) -> Result<()> {
+        processor::add_guardian(ctx)
+    }
+
+    pub fn remove_guardian(ctx: Context<RemoveGuardian>) -> Result<()> {
+        processor::remove_guardian(ctx)
+    }
+
+    pub fn pause(ctx: Context<Pause>, flags: PauseFlags) -> Result<()> {
+        processor::pause(ctx, flags)
+    }
+
+    pub fn unpause(ctx: Context<Unpause>, flags: PauseFlags) -> Result<()> {
+        processor::unpause(ctx, flags)
+    }
+
+    pub fn cancel_pending_receipt(ctx: Context<CancelPendingReceipt>) -> Result<()> {
+        processor::cancel_pending_receipt(ctx)
+    }
+
+    pub fn refund_pending_receipt(ctx: Context<RefundPendingReceipt>) -> Result<()> {
+        processor::refund_pending_receipt(ctx)
+    }
+
+    pub fn checkpoint_supply(ctx: Context<CheckpointSupply>) -> Result<()> {
+        processor::checkpoint_supply(ctx)
+    }
+
+    pub fn get_gateways(
+        ctx: Context<GetGateways>,
+        start: u16,
+        count: u16,
+    ) -> Result<Vec<RegisteredGateway>> {
+        processor::get_gateways(ctx, start, count)
+    }
+
     pub fn receive_tmewc(ctx: Context<ReceiveTmewc>, message_hash: [u8; 32]) -> Result<()> {
         processor::receive_tmewc(ctx, message_hash)
     }
 
+    pub fn release_pending_receipt(ctx: Context<ReleasePendingReceipt>) -> Result<()> {
+        processor::release_pending_receipt(ctx)
+    }
+
+    pub fn process_pending_mint(ctx: Context<ProcessPendingMint>) -> Result<()> {
+        processor::process_pending_mint(ctx)
+    }
+
     pub fn send_tmewc_gateway(
         ctx: Context<SendTmewcGateway>,
         args: SendTmewcGatewayArgs,
@@ -0,0 +1,57 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian, GuardianInfo},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddGuardian<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GuardianInfo::INIT_SPACE,
+        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        bump
+    )]
+    guardian_info: Account<'info, GuardianInfo>,
+
+    /// CHECK: Required authority to pause the gateway. This pubkey lives in `GuardianInfo`.
+    guardian: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn add_guardian(ctx: Context<AddGuardian>) -> Result<()> {
+    let guardian = ctx.accounts.guardian.key();
+
+    ctx.accounts.guardian_info.set_inner(GuardianInfo {
+        bump: ctx.bumps["guardian_info"],
+        guardian,
+    });
+
+    emit_cpi!(crate::event::GuardianAdded { guardian });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::AddGuardian,
+        ctx.accounts.authority.key(),
+        guardian,
+    )
+}
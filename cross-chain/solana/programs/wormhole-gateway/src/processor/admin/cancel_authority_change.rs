@@ -1,6 +1,10 @@
-use crate::{error::WormholeGatewayError, state::Custodian};
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian},
+};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 
 pub struct CancelAuthorityChange<'info> {
@@ -13,10 +17,28 @@ pub struct CancelAuthorityChange<'info> {
     )]
     custodian: Account<'info, Custodian>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     authority: Signer<'info>,
 }
 
 pub fn cancel_authority_change(ctx: Context<CancelAuthorityChange>) -> Result<()> {
-    ctx.accounts.custodian.pending_authority = None;
-    Ok(())
+    // It is safe to unwrap because the account constraint guarantees a pending authority.
+    let pending_authority = ctx.accounts.custodian.pending_authority.take().unwrap();
+
+    emit_cpi!(crate::event::AuthorityChangeCancelled {
+        authority: ctx.accounts.authority.key(),
+        pending_authority
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::CancelAuthorityChange,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
 }
@@ -0,0 +1,71 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, GuardianInfo, PendingReceipt},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelPendingReceipt<'info> {
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        has_one = guardian,
+        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        bump = guardian_info.bump
+    )]
+    guardian_info: Account<'info, GuardianInfo>,
+
+    guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PendingReceipt::SEED_PREFIX, pending_receipt.message_hash.as_ref()],
+        bump = pending_receipt.bump,
+    )]
+    pending_receipt: Account<'info, PendingReceipt>,
+}
+
+impl<'info> CancelPendingReceipt<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            !ctx.accounts.pending_receipt.cancelled,
+            WormholeGatewayError::PendingReceiptCancelled
+        );
+
+        require_gt!(
+            ctx.accounts.pending_receipt.release_at,
+            Clock::get()?.unix_timestamp,
+            WormholeGatewayError::PendingReceiptReleasable
+        );
+
+        Ok(())
+    }
+}
+
+/// Cancel a pending receipt during its delay, so that it can no longer be released. Its Wormhole
+/// tMEWC stays in custody until the authority refunds it.
+#[access_control(CancelPendingReceipt::constraints(&ctx))]
+pub fn cancel_pending_receipt(ctx: Context<CancelPendingReceipt>) -> Result<()> {
+    ctx.accounts.pending_receipt.cancelled = true;
+
+    let pending_receipt = &ctx.accounts.pending_receipt;
+
+    emit_cpi!(crate::event::PendingReceiptCancelled {
+        guardian: ctx.accounts.guardian.key(),
+        recipient: pending_receipt.recipient,
+        amount: pending_receipt.amount,
+        message_hash: pending_receipt.message_hash
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::CancelPendingReceipt,
+        ctx.accounts.guardian.key(),
+        pending_receipt.key(),
+    )
+}
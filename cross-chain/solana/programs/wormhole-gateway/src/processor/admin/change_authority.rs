@@ -1,6 +1,10 @@
-use crate::{error::WormholeGatewayError, state::Custodian};
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian},
+};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ChangeAuthority<'info> {
     #[account(
@@ -11,6 +15,13 @@ pub struct ChangeAuthority<'info> {
     )]
     custodian: Account<'info, Custodian>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     authority: Signer<'info>,
 
     /// CHECK: New authority.
@@ -19,5 +30,15 @@ pub struct ChangeAuthority<'info> {
 
 pub fn change_authority(ctx: Context<ChangeAuthority>) -> Result<()> {
     ctx.accounts.custodian.pending_authority = Some(ctx.accounts.new_authority.key());
-    Ok(())
+
+    emit_cpi!(crate::event::AuthorityChangeStaged {
+        authority: ctx.accounts.authority.key(),
+        pending_authority: ctx.accounts.new_authority.key()
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::ChangeAuthority,
+        ctx.accounts.authority.key(),
+        ctx.accounts.new_authority.key(),
+    )
 }
@@ -0,0 +1,65 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian, GatewayInfo, GatewayRegistry},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct DisableGateway<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()],
+        bump = gateway_info.bump,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
+
+    #[account(
+        mut,
+        seeds = [GatewayRegistry::SEED_PREFIX],
+        bump = gateway_registry.bump,
+    )]
+    gateway_registry: Account<'info, GatewayRegistry>,
+
+    authority: Signer<'info>,
+}
+
+impl<'info> DisableGateway<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            !ctx.accounts.gateway_info.disabled,
+            WormholeGatewayError::GatewayDisabled
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(DisableGateway::constraints(&ctx))]
+pub fn disable_gateway(ctx: Context<DisableGateway>, chain: u16) -> Result<()> {
+    ctx.accounts.gateway_info.disabled = true;
+    ctx.accounts.gateway_registry.set_disabled(chain, true);
+
+    emit_cpi!(crate::event::GatewayDisabled { chain });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::DisableGateway,
+        ctx.accounts.authority.key(),
+        ctx.accounts.gateway_info.key(),
+    )
+}
@@ -0,0 +1,65 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian, GatewayInfo, GatewayRegistry},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct EnableGateway<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()],
+        bump = gateway_info.bump,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
+
+    #[account(
+        mut,
+        seeds = [GatewayRegistry::SEED_PREFIX],
+        bump = gateway_registry.bump,
+    )]
+    gateway_registry: Account<'info, GatewayRegistry>,
+
+    authority: Signer<'info>,
+}
+
+impl<'info> EnableGateway<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            ctx.accounts.gateway_info.disabled,
+            WormholeGatewayError::GatewayNotDisabled
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(EnableGateway::constraints(&ctx))]
+pub fn enable_gateway(ctx: Context<EnableGateway>, chain: u16) -> Result<()> {
+    ctx.accounts.gateway_info.disabled = false;
+    ctx.accounts.gateway_registry.set_disabled(chain, false);
+
+    emit_cpi!(crate::event::GatewayEnabled { chain });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::EnableGateway,
+        ctx.accounts.authority.key(),
+        ctx.accounts.gateway_info.key(),
+    )
+}
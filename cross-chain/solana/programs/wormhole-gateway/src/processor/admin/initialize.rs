@@ -1,11 +1,12 @@
 use crate::{
     constants::{TMEWC_ETHEREUM_TOKEN_ADDRESS, TMEWC_ETHEREUM_TOKEN_CHAIN},
-    state::Custodian,
+    state::{AuditAction, AuditLog, Custodian, GatewayRegistry},
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token;
+use anchor_spl::{token, token_interface};
 use wormhole_anchor_sdk::token_bridge;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Initialize<'info> {
     #[account(mut)]
@@ -20,6 +21,24 @@ pub struct Initialize<'info> {
     )]
     custodian: Account<'info, Custodian>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = AuditLog::compute_size(AuditLog::MAX_ENTRIES),
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = GatewayRegistry::compute_size(0),
+        seeds = [GatewayRegistry::SEED_PREFIX],
+        bump,
+    )]
+    gateway_registry: Account<'info, GatewayRegistry>,
+
     /// TMEWC Program's mint PDA address bump is saved in this program's config. Ordinarily, we would
     /// not have to deserialize this account. But we do in this case to make sure the TMEWC program
     /// has been initialized before this program.
@@ -28,7 +47,7 @@ pub struct Initialize<'info> {
         bump,
         seeds::program = tmewc::ID
     )]
-    tmewc_mint: Account<'info, token::Mint>,
+    tmewc_mint: InterfaceAccount<'info, token_interface::Mint>,
 
     #[account(
         seeds = [
@@ -75,7 +94,40 @@ pub fn initialize(ctx: Context<Initialize>, minting_limit: u64) -> Result<()> {
         token_bridge_sender_bump: ctx.bumps["token_bridge_sender"],
         minting_limit,
         minted_amount: 0,
+        eth_authority: None,
+        eth_authority_nonce: 0,
+        governance_emitter: [0; 32],
+        paused: Default::default(),
+        default_outbound_limit: Default::default(),
+        inbound_rate_limit: Default::default(),
+        large_transfer_threshold: Default::default(),
+        large_transfer_delay: Default::default(),
+        over_limit_mode: Default::default(),
+        partial_mint: Default::default(),
+        gateway_allowlist: Default::default(),
+    });
+
+    emit_cpi!(crate::event::Initialized {
+        authority: ctx.accounts.authority.key(),
+        tmewc_mint: ctx.accounts.tmewc_mint.key(),
+        wrapped_tmewc_mint: ctx.accounts.wrapped_tmewc_mint.key(),
+        minting_limit
     });
 
-    Ok(())
+    ctx.accounts.gateway_registry.set_inner(GatewayRegistry {
+        bump: ctx.bumps["gateway_registry"],
+        gateways: Vec::new(),
+    });
+
+    // Set AuditLog account data with empty vec and record the initialization.
+    ctx.accounts.audit_log.set_inner(AuditLog {
+        bump: ctx.bumps["audit_log"],
+        next_index: 0,
+        entries: Vec::new(),
+    });
+    ctx.accounts.audit_log.record(
+        AuditAction::Initialize,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
 }
@@ -0,0 +1,165 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian, GatewayInfo, GatewayRegistry},
+};
+use anchor_lang::{prelude::*, system_program, Discriminator};
+
+#[derive(Accounts)]
+pub struct MigrateCustodian<'info> {
+    /// CHECK: A custodian created by an earlier version of this program is too small to
+    /// deserialize, so it is only deserialized (and its authority checked) after reallocation.
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump,
+        owner = crate::ID,
+    )]
+    custodian: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AuditLog::compute_size(AuditLog::MAX_ENTRIES),
+        seeds = [AuditLog::SEED_PREFIX],
+        bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = GatewayRegistry::compute_size(0),
+        seeds = [GatewayRegistry::SEED_PREFIX],
+        bump,
+    )]
+    gateway_registry: Account<'info, GatewayRegistry>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Bring a deployment that predates fields appended to `Custodian` up to date. The custodian is
+/// grown to its current size, with the new fields zeroed (i.e. set to their defaults), and the
+/// audit log and gateway registry are created if missing.
+///
+/// Gateways registered before the registry existed are listed by passing their gateway infos as
+/// remaining accounts, in the order of `chains`. Gateway infos that predate `disabled` are listed
+/// as enabled, so they can be backfilled before or after `migrate_gateway_info`. Safe to call more
+/// than once.
+pub fn migrate_custodian(ctx: Context<MigrateCustodian>, chains: Vec<u16>) -> Result<()> {
+    let custodian_info = ctx.accounts.custodian.to_account_info();
+
+    grow_account(
+        &custodian_info,
+        8 + Custodian::INIT_SPACE,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    let custodian = Account::<Custodian>::try_from(&custodian_info)?;
+    require_keys_eq!(
+        custodian.authority,
+        ctx.accounts.authority.key(),
+        WormholeGatewayError::IsNotAuthority
+    );
+
+    ctx.accounts.gateway_registry.bump = ctx.bumps["gateway_registry"];
+
+    require_eq!(
+        chains.len(),
+        ctx.remaining_accounts.len(),
+        WormholeGatewayError::InvalidGatewayInfo
+    );
+    for (&chain, gateway_info) in chains.iter().zip(ctx.remaining_accounts) {
+        let (address, disabled) = read_gateway_info(gateway_info, chain)?;
+        let registry = &mut ctx.accounts.gateway_registry;
+        registry.upsert(chain, address);
+        registry.set_disabled(chain, disabled);
+    }
+
+    let num_gateways = ctx.accounts.gateway_registry.gateways.len();
+    grow_account(
+        &ctx.accounts.gateway_registry.to_account_info(),
+        GatewayRegistry::compute_size(num_gateways),
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    let audit_log = &mut ctx.accounts.audit_log;
+    audit_log.bump = ctx.bumps["audit_log"];
+    audit_log.record(
+        AuditAction::MigrateCustodian,
+        ctx.accounts.authority.key(),
+        custodian_info.key(),
+    )
+}
+
+/// Read the address and status of `chain`'s gateway info, which may predate `disabled` and so be
+/// too small to deserialize.
+fn read_gateway_info(gateway_info: &AccountInfo, chain: u16) -> Result<([u8; 32], bool)> {
+    require_keys_eq!(
+        *gateway_info.owner,
+        crate::ID,
+        WormholeGatewayError::InvalidGatewayInfo
+    );
+
+    let data = gateway_info.try_borrow_data()?;
+    require!(
+        data.len() >= 41 && data[..8] == GatewayInfo::DISCRIMINATOR,
+        WormholeGatewayError::InvalidGatewayInfo
+    );
+
+    // Discriminator, bump and address, followed by `disabled` if already migrated.
+    let bump = data[8];
+    let expected = Pubkey::create_program_address(
+        &[GatewayInfo::SEED_PREFIX, &chain.to_le_bytes(), &[bump]],
+        &crate::ID,
+    )
+    .map_err(|_| error!(WormholeGatewayError::InvalidGatewayInfo))?;
+    require_keys_eq!(
+        gateway_info.key(),
+        expected,
+        WormholeGatewayError::InvalidGatewayInfo
+    );
+
+    let mut address = [0; 32];
+    address.copy_from_slice(&data[9..41]);
+    let disabled = data.get(41).copied().unwrap_or_default() != 0;
+
+    Ok((address, disabled))
+}
+
+/// Grow an account created by an earlier version of this program to `space` bytes, topping up its
+/// rent from `payer`. The appended bytes are zeroed. Accounts already this large are left as is.
+pub(crate) fn grow_account<'info>(
+    account_info: &AccountInfo<'info>,
+    space: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if account_info.data_len() >= space {
+        return Ok(());
+    }
+
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account_info.lamports());
+    if lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    account_info.realloc(space, true)?;
+
+    Ok(())
+}
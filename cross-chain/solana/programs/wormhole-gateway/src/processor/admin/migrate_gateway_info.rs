@@ -0,0 +1,61 @@
+use crate::{
+    error::WormholeGatewayError,
+    processor::grow_account,
+    state::{AuditAction, AuditLog, Custodian, GatewayInfo},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct MigrateGatewayInfo<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    /// CHECK: A gateway info created by an earlier version of this program is too small to
+    /// deserialize, so it is only deserialized after reallocation.
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    gateway_info: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Bring a gateway info that predates fields appended to `GatewayInfo` up to date. It is grown to
+/// its current size, with the new fields zeroed (i.e. the gateway is enabled and its outbound
+/// limit is off), like a gateway info created now. Safe to call more than once.
+pub fn migrate_gateway_info(ctx: Context<MigrateGatewayInfo>, _chain: u16) -> Result<()> {
+    let gateway_info = ctx.accounts.gateway_info.to_account_info();
+    grow_account(
+        &gateway_info,
+        8 + GatewayInfo::INIT_SPACE,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    // Make sure the grown account deserializes.
+    Account::<GatewayInfo>::try_from(&gateway_info)?;
+
+    ctx.accounts.audit_log.record(
+        AuditAction::MigrateGatewayInfo,
+        ctx.accounts.authority.key(),
+        gateway_info.key(),
+    )
+}
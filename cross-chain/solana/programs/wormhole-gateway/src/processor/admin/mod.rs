@@ -1,17 +1,74 @@
+mod add_guardian;
+pub use add_guardian::*;
+
 mod cancel_authority_change;
 pub use cancel_authority_change::*;
 
+mod cancel_pending_receipt;
+pub use cancel_pending_receipt::*;
+
 mod change_authority;
 pub use change_authority::*;
 
+mod disable_gateway;
+pub use disable_gateway::*;
+
+mod enable_gateway;
+pub use enable_gateway::*;
+
 mod initialize;
 pub use initialize::*;
 
+mod migrate_custodian;
+pub use migrate_custodian::*;
+
+mod migrate_gateway_info;
+pub use migrate_gateway_info::*;
+
+mod pause;
+pub use pause::*;
+
+mod refund_pending_receipt;
+pub use refund_pending_receipt::*;
+
+mod remove_gateway;
+pub use remove_gateway::*;
+
+mod remove_guardian;
+pub use remove_guardian::*;
+
+mod set_eth_authority;
+pub use set_eth_authority::*;
+
+mod set_gateway_allowlist;
+pub use set_gateway_allowlist::*;
+
 mod take_authority;
 pub use take_authority::*;
 
+mod unpause;
+pub use unpause::*;
+
+mod update_default_outbound_limit;
+pub use update_default_outbound_limit::*;
+
 mod update_gateway_address;
 pub use update_gateway_address::*;
 
+mod update_governance_emitter;
+pub use update_governance_emitter::*;
+
+mod update_inbound_rate_limit;
+pub use update_inbound_rate_limit::*;
+
+mod update_large_transfer_delay;
+pub use update_large_transfer_delay::*;
+
 mod update_minting_limit;
 pub use update_minting_limit::*;
+
+mod update_outbound_limit;
+pub use update_outbound_limit::*;
+
+mod update_over_limit_mode;
+pub use update_over_limit_mode::*;
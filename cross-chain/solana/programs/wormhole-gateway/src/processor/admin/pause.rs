@@ -0,0 +1,60 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian, GuardianInfo, PauseFlags},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        has_one = guardian,
+        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        bump = guardian_info.bump
+    )]
+    guardian_info: Account<'info, GuardianInfo>,
+
+    guardian: Signer<'info>,
+}
+
+impl<'info> Pause<'info> {
+    fn constraints(flags: &PauseFlags) -> Result<()> {
+        require!(flags.any(), WormholeGatewayError::NoPauseFlags);
+
+        Ok(())
+    }
+}
+
+/// Pause the parts of the gateway set in `flags`. Parts that are already paused stay paused.
+#[access_control(Pause::constraints(&flags))]
+pub fn pause(ctx: Context<Pause>, flags: PauseFlags) -> Result<()> {
+    let paused = &mut ctx.accounts.custodian.paused;
+    paused.inbound |= flags.inbound;
+    paused.outbound |= flags.outbound;
+    paused.deposit |= flags.deposit;
+
+    emit_cpi!(crate::event::Paused {
+        guardian: ctx.accounts.guardian.key(),
+        paused: ctx.accounts.custodian.paused
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::Pause,
+        ctx.accounts.guardian.key(),
+        Pubkey::default(),
+    )
+}
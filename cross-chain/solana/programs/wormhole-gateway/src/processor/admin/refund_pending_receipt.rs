@@ -0,0 +1,112 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian, PendingReceipt},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token, token};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundPendingReceipt<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        has_one = wrapped_tmewc_token,
+        has_one = wrapped_tmewc_mint,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        mut,
+        has_one = recipient,
+        close = receipt_payer,
+        seeds = [PendingReceipt::SEED_PREFIX, pending_receipt.message_hash.as_ref()],
+        bump = pending_receipt.bump,
+    )]
+    pending_receipt: Account<'info, PendingReceipt>,
+
+    /// CHECK: Payer of the pending receipt, who gets its rent back.
+    #[account(
+        mut,
+        address = pending_receipt.payer,
+    )]
+    receipt_payer: AccountInfo<'info>,
+
+    /// Custody account.
+    #[account(mut)]
+    wrapped_tmewc_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// This mint is owned by the Wormhole Token Bridge program. This PDA address is stored in the
+    /// custodian account.
+    wrapped_tmewc_mint: Box<Account<'info, token::Mint>>,
+
+    /// CHECK: This pubkey lives in `PendingReceipt`.
+    recipient: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = wrapped_tmewc_mint,
+        associated_token::authority = recipient,
+    )]
+    recipient_wrapped_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    associated_token_program: Program<'info, associated_token::AssociatedToken>,
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> RefundPendingReceipt<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            ctx.accounts.pending_receipt.cancelled,
+            WormholeGatewayError::PendingReceiptNotCancelled
+        );
+
+        Ok(())
+    }
+}
+
+/// Send the Wormhole tMEWC of a cancelled pending receipt to its recipient, once the authority has
+/// reviewed the transfer.
+#[access_control(RefundPendingReceipt::constraints(&ctx))]
+pub fn refund_pending_receipt(ctx: Context<RefundPendingReceipt>) -> Result<()> {
+    let pending_receipt = &ctx.accounts.pending_receipt;
+    let custodian = &ctx.accounts.custodian;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.wrapped_tmewc_token.to_account_info(),
+                to: ctx.accounts.recipient_wrapped_token.to_account_info(),
+                authority: custodian.to_account_info(),
+            },
+            &[&[Custodian::SEED_PREFIX, &[custodian.bump]]],
+        ),
+        pending_receipt.amount,
+    )?;
+
+    emit_cpi!(crate::event::PendingReceiptRefunded {
+        recipient: pending_receipt.recipient,
+        amount: pending_receipt.amount,
+        message_hash: pending_receipt.message_hash
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::RefundPendingReceipt,
+        ctx.accounts.authority.key(),
+        pending_receipt.key(),
+    )
+}
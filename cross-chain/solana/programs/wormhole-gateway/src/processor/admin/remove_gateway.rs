@@ -0,0 +1,62 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian, GatewayInfo, GatewayRegistry},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct RemoveGateway<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [GatewayInfo::SEED_PREFIX, &chain.to_le_bytes()],
+        bump = gateway_info.bump,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
+
+    #[account(
+        mut,
+        seeds = [GatewayRegistry::SEED_PREFIX],
+        bump = gateway_registry.bump,
+        realloc = gateway_registry.size_without(chain),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    gateway_registry: Account<'info, GatewayRegistry>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn remove_gateway(ctx: Context<RemoveGateway>, chain: u16) -> Result<()> {
+    ctx.accounts.gateway_registry.remove(chain);
+
+    emit_cpi!(crate::event::GatewayRemoved {
+        chain,
+        gateway: ctx.accounts.gateway_info.address
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::RemoveGateway,
+        ctx.accounts.authority.key(),
+        ctx.accounts.gateway_info.key(),
+    )
+}
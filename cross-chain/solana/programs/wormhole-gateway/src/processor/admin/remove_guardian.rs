@@ -0,0 +1,50 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian, GuardianInfo},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveGuardian<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = guardian,
+        close = authority,
+        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        bump = guardian_info.bump,
+    )]
+    guardian_info: Account<'info, GuardianInfo>,
+
+    /// CHECK: Required authority to pause the gateway. This pubkey lives in `GuardianInfo`.
+    guardian: AccountInfo<'info>,
+}
+
+pub fn remove_guardian(ctx: Context<RemoveGuardian>) -> Result<()> {
+    let guardian = ctx.accounts.guardian.key();
+
+    emit_cpi!(crate::event::GuardianRemoved { guardian });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::RemoveGuardian,
+        ctx.accounts.authority.key(),
+        guardian,
+    )
+}
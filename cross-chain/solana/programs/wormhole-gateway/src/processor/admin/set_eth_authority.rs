@@ -0,0 +1,43 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetEthAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+}
+
+/// Setting the Ethereum authority does not hand over control by itself. The eth authority PDA
+/// still has to become `Custodian.authority` through the usual authority change.
+pub fn set_eth_authority(
+    ctx: Context<SetEthAuthority>,
+    eth_authority: Option<[u8; 20]>,
+) -> Result<()> {
+    ctx.accounts.custodian.eth_authority = eth_authority;
+
+    emit_cpi!(crate::event::EthAuthorityUpdated { eth_authority });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::SetEthAuthority,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
+}
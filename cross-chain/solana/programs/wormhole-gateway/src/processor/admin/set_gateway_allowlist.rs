@@ -0,0 +1,39 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetGatewayAllowlist<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+}
+
+/// Toggle whether inbound transfers must be sent by the gateway registered for their chain.
+pub fn set_gateway_allowlist(ctx: Context<SetGatewayAllowlist>, enabled: bool) -> Result<()> {
+    ctx.accounts.custodian.gateway_allowlist = enabled;
+
+    emit_cpi!(crate::event::GatewayAllowlistUpdated { enabled });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::SetGatewayAllowlist,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
+}
@@ -1,6 +1,10 @@
-use crate::{error::WormholeGatewayError, state::Custodian};
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian},
+};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct TakeAuthority<'info> {
     #[account(
@@ -10,6 +14,13 @@ pub struct TakeAuthority<'info> {
     )]
     custodian: Account<'info, Custodian>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     pending_authority: Signer<'info>,
 }
 
@@ -32,7 +43,18 @@ impl<'info> TakeAuthority<'info> {
 
 #[access_control(TakeAuthority::constraints(&ctx))]
 pub fn take_authority(ctx: Context<TakeAuthority>) -> Result<()> {
+    let old_authority = ctx.accounts.custodian.authority;
     ctx.accounts.custodian.authority = ctx.accounts.pending_authority.key();
     ctx.accounts.custodian.pending_authority = None;
-    Ok(())
+
+    emit_cpi!(crate::event::AuthorityChanged {
+        old_authority,
+        new_authority: ctx.accounts.pending_authority.key()
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::TakeAuthority,
+        ctx.accounts.pending_authority.key(),
+        Pubkey::default(),
+    )
 }
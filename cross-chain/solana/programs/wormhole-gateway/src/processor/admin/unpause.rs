@@ -0,0 +1,53 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian, PauseFlags},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+}
+
+impl<'info> Unpause<'info> {
+    fn constraints(flags: &PauseFlags) -> Result<()> {
+        require!(flags.any(), WormholeGatewayError::NoPauseFlags);
+
+        Ok(())
+    }
+}
+
+/// Unpause the parts of the gateway set in `flags`.
+#[access_control(Unpause::constraints(&flags))]
+pub fn unpause(ctx: Context<Unpause>, flags: PauseFlags) -> Result<()> {
+    let paused = &mut ctx.accounts.custodian.paused;
+    paused.inbound &= !flags.inbound;
+    paused.outbound &= !flags.outbound;
+    paused.deposit &= !flags.deposit;
+
+    emit_cpi!(crate::event::Unpaused {
+        paused: ctx.accounts.custodian.paused
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::Unpause,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
+}
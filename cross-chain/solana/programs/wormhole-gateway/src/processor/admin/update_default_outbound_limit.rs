@@ -0,0 +1,62 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateDefaultOutboundLimit<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateDefaultOutboundLimitArgs {
+    max_transfer_amount: u64,
+    window_limit: u64,
+    window_duration: u32,
+}
+
+pub fn update_default_outbound_limit(
+    ctx: Context<UpdateDefaultOutboundLimit>,
+    args: UpdateDefaultOutboundLimitArgs,
+) -> Result<()> {
+    let UpdateDefaultOutboundLimitArgs {
+        max_transfer_amount,
+        window_limit,
+        window_duration,
+    } = args;
+
+    ctx.accounts.custodian.default_outbound_limit.update(
+        max_transfer_amount,
+        window_limit,
+        window_duration,
+    );
+
+    emit_cpi!(crate::event::DefaultOutboundLimitUpdated {
+        max_transfer_amount,
+        window_limit,
+        window_duration
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateDefaultOutboundLimit,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
+}
@@ -1,9 +1,10 @@
 use crate::{
     error::WormholeGatewayError,
-    state::{Custodian, GatewayInfo},
+    state::{AuditAction, AuditLog, Custodian, GatewayInfo, GatewayRegistry},
 };
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: UpdateGatewayAddressArgs)]
 pub struct UpdateGatewayAddress<'info> {
@@ -14,6 +15,13 @@ pub struct UpdateGatewayAddress<'info> {
     )]
     custodian: Account<'info, Custodian>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     #[account(
         init_if_needed,
         payer = authority,
@@ -23,6 +31,16 @@ pub struct UpdateGatewayAddress<'info> {
     )]
     gateway_info: Account<'info, GatewayInfo>,
 
+    #[account(
+        mut,
+        seeds = [GatewayRegistry::SEED_PREFIX],
+        bump = gateway_registry.bump,
+        realloc = gateway_registry.size_with(args.chain),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    gateway_registry: Account<'info, GatewayRegistry>,
+
     #[account(mut)]
     authority: Signer<'info>,
 
@@ -41,15 +59,24 @@ pub fn update_gateway_address(
 ) -> Result<()> {
     let UpdateGatewayAddressArgs { chain, address } = args;
 
-    ctx.accounts.gateway_info.set_inner(GatewayInfo {
-        bump: ctx.bumps["gateway_info"],
-        address,
-    });
+    // A disabled gateway stays disabled until it is explicitly enabled.
+    let gateway_info = &mut ctx.accounts.gateway_info;
+    gateway_info.bump = ctx.bumps["gateway_info"];
+    gateway_info.address = address;
+
+    // Gateways registered before the registry existed are added back with their status.
+    let registry = &mut ctx.accounts.gateway_registry;
+    registry.upsert(chain, address);
+    registry.set_disabled(chain, gateway_info.disabled);
 
-    emit!(crate::event::GatewayAddressUpdated {
+    emit_cpi!(crate::event::GatewayAddressUpdated {
         chain,
         gateway: address
     });
 
-    Ok(())
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateGatewayAddress,
+        ctx.accounts.authority.key(),
+        ctx.accounts.gateway_info.key(),
+    )
 }
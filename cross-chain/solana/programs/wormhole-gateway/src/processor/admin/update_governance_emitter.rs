@@ -0,0 +1,43 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateGovernanceEmitter<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+}
+
+/// Registering the governance emitter does not hand over control by itself. The governance
+/// authority PDA still has to become `Custodian.authority` through the usual authority change.
+pub fn update_governance_emitter(
+    ctx: Context<UpdateGovernanceEmitter>,
+    governance_emitter: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.custodian.governance_emitter = governance_emitter;
+
+    emit_cpi!(crate::event::GovernanceEmitterUpdated { governance_emitter });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateGovernanceEmitter,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
+}
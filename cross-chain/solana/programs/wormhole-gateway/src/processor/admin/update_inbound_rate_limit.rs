@@ -0,0 +1,59 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateInboundRateLimit<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateInboundRateLimitArgs {
+    capacity: u64,
+    window_duration: u32,
+}
+
+pub fn update_inbound_rate_limit(
+    ctx: Context<UpdateInboundRateLimit>,
+    args: UpdateInboundRateLimitArgs,
+) -> Result<()> {
+    let UpdateInboundRateLimitArgs {
+        capacity,
+        window_duration,
+    } = args;
+
+    ctx.accounts.custodian.inbound_rate_limit.update(
+        capacity,
+        window_duration,
+        Clock::get()?.unix_timestamp,
+    );
+
+    emit_cpi!(crate::event::InboundRateLimitUpdated {
+        capacity,
+        window_duration
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateInboundRateLimit,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
+}
@@ -0,0 +1,53 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateLargeTransferDelay<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateLargeTransferDelayArgs {
+    threshold: u64,
+    delay: u32,
+}
+
+/// Set the amount above which inbound transfers are held, and for how long. Receipts that are
+/// already pending keep their release time.
+pub fn update_large_transfer_delay(
+    ctx: Context<UpdateLargeTransferDelay>,
+    args: UpdateLargeTransferDelayArgs,
+) -> Result<()> {
+    let UpdateLargeTransferDelayArgs { threshold, delay } = args;
+
+    let custodian = &mut ctx.accounts.custodian;
+    custodian.large_transfer_threshold = threshold;
+    custodian.large_transfer_delay = delay;
+
+    emit_cpi!(crate::event::LargeTransferDelayUpdated { threshold, delay });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateLargeTransferDelay,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
+}
@@ -1,6 +1,10 @@
-use crate::{error::WormholeGatewayError, state::Custodian};
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian},
+};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateMintingLimit<'info> {
     #[account(
@@ -11,15 +15,26 @@ pub struct UpdateMintingLimit<'info> {
     )]
     custodian: Account<'info, Custodian>,
 
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
     authority: Signer<'info>,
 }
 
 pub fn update_minting_limit(ctx: Context<UpdateMintingLimit>, new_limit: u64) -> Result<()> {
     ctx.accounts.custodian.minting_limit = new_limit;
 
-    emit!(crate::event::MintingLimitUpdated {
+    emit_cpi!(crate::event::MintingLimitUpdated {
         minting_limit: new_limit
     });
 
-    Ok(())
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateMintingLimit,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
 }
@@ -0,0 +1,72 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian, GatewayInfo},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: UpdateOutboundLimitArgs)]
+pub struct UpdateOutboundLimit<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &args.chain.to_le_bytes()],
+        bump = gateway_info.bump,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
+
+    authority: Signer<'info>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateOutboundLimitArgs {
+    chain: u16,
+    max_transfer_amount: u64,
+    window_limit: u64,
+    window_duration: u32,
+}
+
+pub fn update_outbound_limit(
+    ctx: Context<UpdateOutboundLimit>,
+    args: UpdateOutboundLimitArgs,
+) -> Result<()> {
+    let UpdateOutboundLimitArgs {
+        chain,
+        max_transfer_amount,
+        window_limit,
+        window_duration,
+    } = args;
+
+    ctx.accounts.gateway_info.outbound_limit.update(
+        max_transfer_amount,
+        window_limit,
+        window_duration,
+    );
+
+    emit_cpi!(crate::event::OutboundLimitUpdated {
+        chain,
+        max_transfer_amount,
+        window_limit,
+        window_duration
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateOutboundLimit,
+        ctx.accounts.authority.key(),
+        ctx.accounts.gateway_info.key(),
+    )
+}
@@ -0,0 +1,57 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{AuditAction, AuditLog, Custodian, OverLimitMode},
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateOverLimitMode<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump,
+    )]
+    audit_log: Account<'info, AuditLog>,
+
+    authority: Signer<'info>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateOverLimitModeArgs {
+    over_limit_mode: OverLimitMode,
+    partial_mint: bool,
+}
+
+pub fn update_over_limit_mode(
+    ctx: Context<UpdateOverLimitMode>,
+    args: UpdateOverLimitModeArgs,
+) -> Result<()> {
+    let UpdateOverLimitModeArgs {
+        over_limit_mode,
+        partial_mint,
+    } = args;
+
+    let custodian = &mut ctx.accounts.custodian;
+    custodian.over_limit_mode = over_limit_mode;
+    custodian.partial_mint = partial_mint;
+
+    emit_cpi!(crate::event::OverLimitModeUpdated {
+        over_limit_mode,
+        partial_mint
+    });
+
+    ctx.accounts.audit_log.record(
+        AuditAction::UpdateOverLimitMode,
+        ctx.accounts.authority.key(),
+        Pubkey::default(),
+    )
+}
@@ -0,0 +1,51 @@
+use crate::state::Custodian;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CheckpointSupply<'info> {
+    /// NOTE: This account vouches for the minted amount recorded by the TMEWC program.
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = tmewc_mint,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    /// CHECK: This PDA address is stored in the custodian account.
+    tmewc_mint: UncheckedAccount<'info>,
+
+    /// CHECK: TMEWC program requires this account.
+    tmewc_config: UncheckedAccount<'info>,
+
+    /// CHECK: TMEWC program requires this account.
+    #[account(mut)]
+    tmewc_supply_checkpoints: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    tmewc_program: Program<'info, tmewc::Tmewc>,
+    system_program: Program<'info, System>,
+}
+
+/// Record a tMEWC supply checkpoint together with the gateway's minted amount, which the TMEWC
+/// program cannot read by itself. Anyone can record a checkpoint.
+pub fn checkpoint_supply(ctx: Context<CheckpointSupply>) -> Result<()> {
+    let custodian = &ctx.accounts.custodian;
+
+    tmewc::cpi::checkpoint(
+        CpiContext::new_with_signer(
+            ctx.accounts.tmewc_program.to_account_info(),
+            tmewc::cpi::accounts::Checkpoint {
+                config: ctx.accounts.tmewc_config.to_account_info(),
+                mint: ctx.accounts.tmewc_mint.to_account_info(),
+                gateway_custodian: Some(custodian.to_account_info()),
+                supply_checkpoints: ctx.accounts.tmewc_supply_checkpoints.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[&[Custodian::SEED_PREFIX, &[custodian.bump]]],
+        ),
+        custodian.minted_amount,
+    )
+}
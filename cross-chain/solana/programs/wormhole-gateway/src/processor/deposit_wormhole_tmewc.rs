@@ -1,7 +1,9 @@
+use super::NormalizedAmount;
 use crate::{error::WormholeGatewayError, state::Custodian};
 use anchor_lang::prelude::*;
-use anchor_spl::token;
+use anchor_spl::{token, token_interface};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(amount: u64)]
 pub struct DepositWormholeTmewc<'info> {
@@ -26,8 +28,11 @@ pub struct DepositWormholeTmewc<'info> {
     wrapped_tmewc_mint: Box<Account<'info, token::Mint>>,
 
     /// This mint is owned by the TMEWC program. This PDA address is stored in the custodian account.
-    #[account(mut)]
-    tmewc_mint: Account<'info, token::Mint>,
+    #[account(
+        mut,
+        mint::token_program = tmewc_token_program,
+    )]
+    tmewc_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
 
     #[account(
         mut,
@@ -41,25 +46,45 @@ pub struct DepositWormholeTmewc<'info> {
         mut,
         token::mint = tmewc_mint,
         token::authority = recipient,
+        token::token_program = tmewc_token_program,
     )]
-    recipient_token: Box<Account<'info, token::TokenAccount>>,
+    recipient_token: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
 
     /// This program requires that the owner of the TMEWC token account sign for TMEWC being minted
     /// into his account.
     recipient: Signer<'info>,
 
     /// CHECK: TMEWC program requires this account.
+    #[account(mut)]
     tmewc_config: UncheckedAccount<'info>,
 
     /// CHECK: TMEWC program requires this account.
     tmewc_minter_info: UncheckedAccount<'info>,
 
+    /// CHECK: TMEWC program requires this account while proof of reserve is enabled.
+    tmewc_reserve_attestation: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: TMEWC program requires this account.
+    tmewc_event_authority: UncheckedAccount<'info>,
+
     token_program: Program<'info, token::Token>,
+
+    /// Token program that owns the canonical tMEWC mint (either SPL Token or Token-2022).
+    tmewc_token_program: Interface<'info, token_interface::TokenInterface>,
+
     tmewc_program: Program<'info, tmewc::Tmewc>,
 }
 
 impl<'info> DepositWormholeTmewc<'info> {
     fn constraints(ctx: &Context<Self>, amount: u64) -> Result<()> {
+        require!(
+            !ctx.accounts.custodian.paused.deposit,
+            WormholeGatewayError::DepositPaused
+        );
+
+        let amount = ctx.accounts.normalized_amount(amount)?.tmewc;
+        require_gt!(amount, 0, WormholeGatewayError::ZeroAmount);
+
         let updated_minted_amount = ctx
             .accounts
             .custodian
@@ -72,44 +97,89 @@ impl<'info> DepositWormholeTmewc<'info> {
             WormholeGatewayError::MintingLimitExceeded
         );
 
+        require_gte!(
+            ctx.accounts
+                .custodian
+                .inbound_rate_limit
+                .available_at(Clock::get()?.unix_timestamp),
+            amount,
+            WormholeGatewayError::InboundRateLimitExceeded
+        );
+
         Ok(())
     }
+
+    /// The deposited amount is in Wormhole tMEWC, while the limits account for canonical tMEWC.
+    fn normalized_amount(&self, amount: u64) -> Result<NormalizedAmount> {
+        NormalizedAmount::from_wrapped(
+            amount,
+            self.tmewc_mint.decimals,
+            self.wrapped_tmewc_mint.decimals,
+        )
+    }
 }
 
 #[access_control(DepositWormholeTmewc::constraints(&ctx, amount))]
 pub fn deposit_wormhole_tmewc(ctx: Context<DepositWormholeTmewc>, amount: u64) -> Result<()> {
-    // First transfer wrapped tokens to custody account.
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.recipient_wrapped_token.to_account_info(),
-                to: ctx.accounts.wrapped_tmewc_token.to_account_info(),
-                authority: ctx.accounts.recipient.to_account_info(),
-            },
-        ),
-        amount,
-    )?;
-
-    // Account for minted amount.
-    ctx.accounts.custodian.minted_amount += amount;
-
+    // Only take the Wormhole tMEWC that canonical tMEWC can represent.
+    let amount = ctx.accounts.normalized_amount(amount)?;
     let custodian = &ctx.accounts.custodian;
 
-    // Now mint.
-    tmewc::cpi::mint(
+    // Mint first, so the recipient keeps his wrapped tokens if the TMEWC program auto-pauses.
+    let minted = tmewc::cpi::mint(
         CpiContext::new_with_signer(
             ctx.accounts.tmewc_program.to_account_info(),
             tmewc::cpi::accounts::Mint {
                 mint: ctx.accounts.tmewc_mint.to_account_info(),
                 config: ctx.accounts.tmewc_config.to_account_info(),
-                minter_info: ctx.accounts.tmewc_minter_info.to_account_info(),
+                minter_info: Some(ctx.accounts.tmewc_minter_info.to_account_info()),
+                role_assignment: None,
+                reserve_attestation: ctx
+                    .accounts
+                    .tmewc_reserve_attestation
+                    .as_ref()
+                    .map(|info| info.to_account_info()),
                 minter: custodian.to_account_info(),
                 recipient_token: ctx.accounts.recipient_token.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
+                token_program: ctx.accounts.tmewc_token_program.to_account_info(),
+                event_authority: ctx.accounts.tmewc_event_authority.to_account_info(),
+                program: ctx.accounts.tmewc_program.to_account_info(),
             },
             &[&[Custodian::SEED_PREFIX, &[custodian.bump]]],
         ),
-        amount,
-    )
+        amount.tmewc,
+    )?
+    .get();
+
+    if !minted {
+        msg!("tMEWC minting was auto-paused. Keeping Wormhole tMEWC with the recipient");
+        return Ok(());
+    }
+
+    // Now transfer wrapped tokens to custody account.
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.recipient_wrapped_token.to_account_info(),
+                to: ctx.accounts.wrapped_tmewc_token.to_account_info(),
+                authority: ctx.accounts.recipient.to_account_info(),
+            },
+        ),
+        amount.wrapped,
+    )?;
+
+    // Account for minted amount.
+    let custodian = &mut ctx.accounts.custodian;
+    custodian.minted_amount += amount.tmewc;
+    custodian
+        .inbound_rate_limit
+        .track_mint(amount.tmewc, Clock::get()?.unix_timestamp);
+
+    emit_cpi!(crate::event::WormholeTmewcDeposited {
+        depositor: ctx.accounts.recipient.key(),
+        amount: amount.tmewc
+    });
+
+    Ok(())
 }
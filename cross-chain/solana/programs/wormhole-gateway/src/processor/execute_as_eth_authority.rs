@@ -0,0 +1,174 @@
+use crate::{
+    constants::{ETH_AUTHORITY_DOMAIN, SEED_PREFIX_ETH_AUTHORITY},
+    error::WormholeGatewayError,
+    processor::invoke_self_signed,
+    state::Custodian,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        keccak, secp256k1_program,
+        sysvar::{
+            self,
+            instructions::{load_current_index_checked, load_instruction_at_checked},
+        },
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteAsEthAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    /// CHECK: System-owned PDA signing the admin instruction. It must hold enough lamports if the
+    /// instruction has the authority pay for anything.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_ETH_AUTHORITY],
+        bump,
+    )]
+    eth_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar, used to find the secp256k1 signature verification.
+    #[account(address = sysvar::instructions::ID)]
+    instructions: UncheckedAccount<'info>,
+}
+
+impl<'info> ExecuteAsEthAuthority<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            ctx.accounts.custodian.eth_authority.is_some(),
+            WormholeGatewayError::NoEthAuthority
+        );
+
+        Ok(())
+    }
+}
+
+/// Execute one of this program's instructions (`data`, with the remaining accounts) signed by the
+/// eth authority PDA. The instruction right before this one must verify the Ethereum authority's
+/// secp256k1 signature over the message built by `eth_authority_message`, so anyone can relay it.
+#[access_control(ExecuteAsEthAuthority::constraints(&ctx))]
+pub fn execute_as_eth_authority<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteAsEthAuthority<'info>>,
+    data: Vec<u8>,
+) -> Result<()> {
+    let nonce = ctx.accounts.custodian.eth_authority_nonce;
+    let message = eth_authority_message(
+        &ctx.accounts.custodian.key(),
+        nonce,
+        &data,
+        ctx.remaining_accounts,
+        ctx.accounts.eth_authority.key,
+    );
+    verify_eth_authorization(
+        &ctx.accounts.instructions,
+        &ctx.accounts.custodian.eth_authority.unwrap(),
+        &message,
+    )?;
+
+    // Persist the nonce before executing, since the instruction may write to the custodian as well.
+    ctx.accounts.custodian.eth_authority_nonce = nonce + 1;
+    ctx.accounts.custodian.exit(&crate::ID)?;
+
+    invoke_self_signed(
+        ctx.remaining_accounts,
+        &ctx.accounts.eth_authority,
+        &ctx.accounts.program,
+        data,
+        &[SEED_PREFIX_ETH_AUTHORITY, &[ctx.bumps["eth_authority"]]],
+    )?;
+
+    // Pick up whatever the executed instruction wrote to the custodian.
+    ctx.accounts.custodian.reload()?;
+
+    emit_cpi!(crate::event::EthAuthorityExecuted { nonce });
+
+    Ok(())
+}
+
+/// `personal_sign` message over the Keccak-256 digest of the domain separator, cluster, program ID,
+/// custodian, nonce, instruction data and the instruction's accounts, in that order. Each account is
+/// its key followed by its signer and writable flags as the instruction is executed, i.e. with the
+/// eth authority PDA signing.
+fn eth_authority_message(
+    custodian: &Pubkey,
+    nonce: u64,
+    data: &[u8],
+    accounts: &[AccountInfo],
+    eth_authority: &Pubkey,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(
+        ETH_AUTHORITY_DOMAIN.len() + tmewc::CLUSTER.len() + 72 + data.len() + 34 * accounts.len(),
+    );
+    payload.extend_from_slice(ETH_AUTHORITY_DOMAIN);
+    payload.extend_from_slice(tmewc::CLUSTER);
+    payload.extend_from_slice(crate::ID.as_ref());
+    payload.extend_from_slice(custodian.as_ref());
+    payload.extend_from_slice(&nonce.to_le_bytes());
+    payload.extend_from_slice(data);
+    accounts.iter().for_each(|info| {
+        payload.extend_from_slice(info.key.as_ref());
+        payload.push(u8::from(info.is_signer || info.key == eth_authority));
+        payload.push(u8::from(info.is_writable));
+    });
+
+    [
+        tmewc::ETH_SIGNED_MESSAGE_PREFIX,
+        keccak::hash(&payload).as_ref(),
+    ]
+    .concat()
+}
+
+/// Check that the previous instruction is a secp256k1 precompile verifying a single signature by
+/// `eth_authority` over `message`, with all of its offsets pointing into its own data.
+fn verify_eth_authorization(
+    instructions: &AccountInfo,
+    eth_authority: &[u8; 20],
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions)?;
+    require_gt!(
+        current_index,
+        0,
+        WormholeGatewayError::InvalidEthAuthorization
+    );
+
+    let index = current_index - 1;
+    let ix = load_instruction_at_checked(index.into(), instructions)?;
+    require_keys_eq!(
+        ix.program_id,
+        secp256k1_program::ID,
+        WormholeGatewayError::InvalidEthAuthorization
+    );
+
+    // Number of signatures followed by the offsets of the first one.
+    let data = &ix.data;
+    require!(
+        data.len() >= 12 && data[0] == 1,
+        WormholeGatewayError::InvalidEthAuthorization
+    );
+    require!(
+        [data[3], data[6], data[11]]
+            .iter()
+            .all(|ix_index| u16::from(*ix_index) == index),
+        WormholeGatewayError::InvalidEthAuthorization
+    );
+
+    let read_u16 = |at: usize| usize::from(u16::from_le_bytes([data[at], data[at + 1]]));
+    let eth_address_offset = read_u16(4);
+    let message_offset = read_u16(7);
+    let message_size = read_u16(9);
+    require!(
+        data.get(eth_address_offset..eth_address_offset + 20) == Some(eth_authority.as_slice())
+            && data.get(message_offset..message_offset + message_size) == Some(message),
+        WormholeGatewayError::InvalidEthAuthorization
+    );
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::state::{GatewayRegistry, RegisteredGateway};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct GetGateways<'info> {
+    #[account(
+        seeds = [GatewayRegistry::SEED_PREFIX],
+        bump = gateway_registry.bump,
+    )]
+    gateway_registry: Account<'info, GatewayRegistry>,
+}
+
+/// View returning up to `count` registered gateways, sorted by chain, starting at index `start`.
+/// Pages hold at most `GatewayRegistry::MAX_GATEWAYS_PER_PAGE` gateways to fit in the return data.
+/// Meant to be simulated.
+pub fn get_gateways(
+    ctx: Context<GetGateways>,
+    start: u16,
+    count: u16,
+) -> Result<Vec<RegisteredGateway>> {
+    Ok(ctx
+        .accounts
+        .gateway_registry
+        .gateways
+        .iter()
+        .skip(start.into())
+        .take(usize::from(count).min(GatewayRegistry::MAX_GATEWAYS_PER_PAGE))
+        .copied()
+        .collect())
+}
@@ -1,11 +1,35 @@
 mod admin;
 pub use admin::*;
 
+mod checkpoint_supply;
+pub use checkpoint_supply::*;
+
 mod deposit_wormhole_tmewc;
 pub use deposit_wormhole_tmewc::*;
 
+mod execute_as_eth_authority;
+pub use execute_as_eth_authority::*;
+
+mod execute_governance_vaa;
+pub use execute_governance_vaa::*;
+
+mod get_gateways;
+pub use get_gateways::*;
+
+mod normalized_amount;
+pub(crate) use normalized_amount::*;
+
+mod process_pending_mint;
+pub use process_pending_mint::*;
+
 mod receive_tmewc;
 pub use receive_tmewc::*;
 
+mod release_pending_receipt;
+pub use release_pending_receipt::*;
+
+mod self_cpi;
+pub(crate) use self_cpi::*;
+
 mod send_tmewc;
 pub use send_tmewc::*;
@@ -0,0 +1,173 @@
+use crate::{constants::MAX_WORMHOLE_DECIMALS, error::WormholeGatewayError};
+use anchor_lang::prelude::*;
+
+/// Amount in both canonical tMEWC and Wormhole tMEWC units, truncated to the precision Wormhole's
+/// Token Bridge carries so that both sides always represent the same value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NormalizedAmount {
+    /// Canonical tMEWC to burn or mint.
+    pub tmewc: u64,
+
+    /// Wormhole tMEWC to transfer via the Token Bridge or to take into custody.
+    pub wrapped: u64,
+}
+
+impl NormalizedAmount {
+    /// Normalize an amount of canonical tMEWC to send. Fails if nothing is left to bridge.
+    pub fn new(amount: u64, tmewc_decimals: u8, wrapped_decimals: u8) -> Result<Self> {
+        let normalized = Self::from_tmewc(amount, tmewc_decimals, wrapped_decimals)?;
+        require_gt!(
+            normalized.tmewc,
+            0,
+            WormholeGatewayError::AmountTooLowToBridge
+        );
+
+        Ok(normalized)
+    }
+
+    /// Normalize at most `amount` canonical tMEWC. Unlike `new`, the result may be zero.
+    pub fn from_tmewc(amount: u64, tmewc_decimals: u8, wrapped_decimals: u8) -> Result<Self> {
+        let (tmewc_scale, wrapped_scale) = scales(tmewc_decimals, wrapped_decimals)?;
+
+        let normalized = amount / tmewc_scale;
+
+        Ok(Self {
+            tmewc: normalized * tmewc_scale,
+            wrapped: normalized
+                .checked_mul(wrapped_scale)
+                .ok_or(WormholeGatewayError::WrappedAmountOverflow)?,
+        })
+    }
+
+    /// Normalize an amount of received or deposited Wormhole tMEWC to mint canonical tMEWC for.
+    /// Wormhole tMEWC finer than canonical tMEWC's precision is left out of the normalized amount.
+    pub fn from_wrapped(amount: u64, tmewc_decimals: u8, wrapped_decimals: u8) -> Result<Self> {
+        let (tmewc_scale, wrapped_scale) = scales(tmewc_decimals, wrapped_decimals)?;
+
+        let normalized = amount / wrapped_scale;
+
+        Ok(Self {
+            tmewc: normalized
+                .checked_mul(tmewc_scale)
+                .ok_or(WormholeGatewayError::TmewcAmountOverflow)?,
+            wrapped: normalized * wrapped_scale,
+        })
+    }
+}
+
+/// Units of canonical and Wormhole tMEWC per unit of the shared precision.
+fn scales(tmewc_decimals: u8, wrapped_decimals: u8) -> Result<(u64, u64)> {
+    let decimals = tmewc_decimals
+        .min(wrapped_decimals)
+        .min(MAX_WORMHOLE_DECIMALS);
+    let scale = |mint_decimals: u8| {
+        10u64
+            .checked_pow((mint_decimals - decimals).into())
+            .ok_or(WormholeGatewayError::InvalidMintDecimals)
+    };
+
+    Ok((scale(tmewc_decimals)?, scale(wrapped_decimals)?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn error_code(err: Error) -> u32 {
+        match err {
+            Error::AnchorError(err) => err.error_code_number,
+            Error::ProgramError(err) => panic!("unexpected program error: {err}"),
+        }
+    }
+
+    #[test]
+    fn same_decimals() {
+        for decimals in [6, 8] {
+            let amount = NormalizedAmount::new(123_456_789, decimals, decimals).unwrap();
+            assert_eq!(
+                amount,
+                NormalizedAmount {
+                    tmewc: 123_456_789,
+                    wrapped: 123_456_789,
+                }
+            );
+            assert_eq!(
+                NormalizedAmount::from_wrapped(123_456_789, decimals, decimals).unwrap(),
+                amount
+            );
+        }
+    }
+
+    #[test]
+    fn eighteen_decimals() {
+        // Both sides are truncated to Wormhole's 8 decimals.
+        let amount = NormalizedAmount::new(1_234_567_891_234_567_891, 18, 8).unwrap();
+        assert_eq!(
+            amount,
+            NormalizedAmount {
+                tmewc: 1_234_567_890_000_000_000,
+                wrapped: 123_456_789,
+            }
+        );
+        assert_eq!(
+            NormalizedAmount::from_wrapped(123_456_789, 18, 8).unwrap(),
+            amount
+        );
+    }
+
+    #[test]
+    fn fewer_tmewc_decimals() {
+        let amount = NormalizedAmount::new(1_234_567, 6, 8).unwrap();
+        assert_eq!(
+            amount,
+            NormalizedAmount {
+                tmewc: 1_234_567,
+                wrapped: 123_456_700,
+            }
+        );
+
+        // Wormhole tMEWC finer than a unit of canonical tMEWC is left out.
+        assert_eq!(
+            NormalizedAmount::from_wrapped(123_456_789, 6, 8).unwrap(),
+            amount
+        );
+    }
+
+    #[test]
+    fn dust() {
+        assert_eq!(
+            error_code(NormalizedAmount::new(9_999_999_999, 18, 8).unwrap_err()),
+            u32::from(WormholeGatewayError::AmountTooLowToBridge)
+        );
+        assert_eq!(
+            NormalizedAmount::from_tmewc(9_999_999_999, 18, 8).unwrap(),
+            NormalizedAmount {
+                tmewc: 0,
+                wrapped: 0,
+            }
+        );
+        assert_eq!(
+            NormalizedAmount::from_wrapped(99, 6, 8).unwrap(),
+            NormalizedAmount {
+                tmewc: 0,
+                wrapped: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            error_code(NormalizedAmount::new(u64::MAX, 6, 8).unwrap_err()),
+            u32::from(WormholeGatewayError::WrappedAmountOverflow)
+        );
+        assert_eq!(
+            error_code(NormalizedAmount::from_wrapped(u64::MAX, 18, 8).unwrap_err()),
+            u32::from(WormholeGatewayError::TmewcAmountOverflow)
+        );
+        assert_eq!(
+            error_code(NormalizedAmount::new(1, 28, 8).unwrap_err()),
+            u32::from(WormholeGatewayError::InvalidMintDecimals)
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use super::NormalizedAmount;
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, PendingMint},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{token, token_interface};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProcessPendingMint<'info> {
+    /// NOTE: This account also acts as a minter for the TMEWC program.
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = wrapped_tmewc_mint,
+        has_one = tmewc_mint,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        has_one = recipient,
+        seeds = [PendingMint::SEED_PREFIX, recipient.key().as_ref()],
+        bump = pending_mint.bump,
+    )]
+    pending_mint: Account<'info, PendingMint>,
+
+    /// CHECK: Payer of the pending mint, who gets its rent back.
+    #[account(
+        mut,
+        address = pending_mint.payer,
+    )]
+    pending_mint_payer: AccountInfo<'info>,
+
+    /// This mint is owned by the Wormhole Token Bridge program. This PDA address is stored in the
+    /// custodian account.
+    wrapped_tmewc_mint: Box<Account<'info, token::Mint>>,
+
+    /// This mint is owned by the TMEWC program. This PDA address is stored in the custodian account.
+    #[account(
+        mut,
+        mint::token_program = tmewc_token_program,
+    )]
+    tmewc_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        mut,
+        token::mint = tmewc_mint,
+        token::authority = recipient,
+        token::token_program = tmewc_token_program,
+    )]
+    recipient_token: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    /// CHECK: This pubkey lives in `PendingMint`.
+    recipient: AccountInfo<'info>,
+
+    /// CHECK: TMEWC program requires this account.
+    #[account(mut)]
+    tmewc_config: UncheckedAccount<'info>,
+
+    /// CHECK: TMEWC program requires this account.
+    tmewc_minter_info: UncheckedAccount<'info>,
+
+    /// CHECK: TMEWC program requires this account while proof of reserve is enabled.
+    tmewc_reserve_attestation: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: TMEWC program requires this account.
+    tmewc_event_authority: UncheckedAccount<'info>,
+
+    /// Token program that owns the canonical tMEWC mint (either SPL Token or Token-2022).
+    tmewc_token_program: Interface<'info, token_interface::TokenInterface>,
+
+    tmewc_program: Program<'info, tmewc::Tmewc>,
+}
+
+impl<'info> ProcessPendingMint<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            !ctx.accounts.custodian.paused.inbound,
+            WormholeGatewayError::InboundPaused
+        );
+
+        let amount = ctx.accounts.normalized_amount()?.tmewc;
+        let updated_minted_amount = ctx
+            .accounts
+            .custodian
+            .minted_amount
+            .checked_add(amount)
+            .ok_or(WormholeGatewayError::MintedAmountOverflow)?;
+        require_gte!(
+            ctx.accounts.custodian.minting_limit,
+            updated_minted_amount,
+            WormholeGatewayError::MintingLimitExceeded
+        );
+
+        require_gte!(
+            ctx.accounts
+                .custodian
+                .inbound_rate_limit
+                .available_at(Clock::get()?.unix_timestamp),
+            amount,
+            WormholeGatewayError::InboundRateLimitExceeded
+        );
+
+        Ok(())
+    }
+
+    /// The escrowed amount is in Wormhole tMEWC, while the limits account for canonical tMEWC.
+    fn normalized_amount(&self) -> Result<NormalizedAmount> {
+        NormalizedAmount::from_wrapped(
+            self.pending_mint.amount,
+            self.tmewc_mint.decimals,
+            self.wrapped_tmewc_mint.decimals,
+        )
+    }
+}
+
+/// Mint canonical tMEWC for Wormhole tMEWC escrowed in a pending mint. Anyone can process it once
+/// the minting limits allow it. The escrowed Wormhole tMEWC is already in custody.
+#[access_control(ProcessPendingMint::constraints(&ctx))]
+pub fn process_pending_mint(ctx: Context<ProcessPendingMint>) -> Result<()> {
+    let custodian = &ctx.accounts.custodian;
+    let amount = ctx.accounts.normalized_amount()?.tmewc;
+
+    let minted = tmewc::cpi::mint(
+        CpiContext::new_with_signer(
+            ctx.accounts.tmewc_program.to_account_info(),
+            tmewc::cpi::accounts::Mint {
+                mint: ctx.accounts.tmewc_mint.to_account_info(),
+                config: ctx.accounts.tmewc_config.to_account_info(),
+                minter_info: Some(ctx.accounts.tmewc_minter_info.to_account_info()),
+                role_assignment: None,
+                reserve_attestation: ctx
+                    .accounts
+                    .tmewc_reserve_attestation
+                    .as_ref()
+                    .map(|info| info.to_account_info()),
+                minter: custodian.to_account_info(),
+                recipient_token: ctx.accounts.recipient_token.to_account_info(),
+                token_program: ctx.accounts.tmewc_token_program.to_account_info(),
+                event_authority: ctx.accounts.tmewc_event_authority.to_account_info(),
+                program: ctx.accounts.tmewc_program.to_account_info(),
+            },
+            &[&[Custodian::SEED_PREFIX, &[custodian.bump]]],
+        ),
+        amount,
+    )?
+    .get();
+
+    if !minted {
+        msg!("tMEWC minting was auto-paused. Keeping the pending mint");
+        return Ok(());
+    }
+
+    // Account for minted amount.
+    let custodian = &mut ctx.accounts.custodian;
+    custodian.minted_amount += amount;
+    custodian
+        .inbound_rate_limit
+        .track_mint(amount, Clock::get()?.unix_timestamp);
+
+    emit_cpi!(crate::event::PendingMintProcessed {
+        recipient: ctx.accounts.recipient.key(),
+        amount
+    });
+
+    ctx.accounts
+        .pending_mint
+        .close(ctx.accounts.pending_mint_payer.to_account_info())
+}
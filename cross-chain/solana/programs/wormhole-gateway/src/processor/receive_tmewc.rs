@@ -1,15 +1,18 @@
+use super::NormalizedAmount;
 use crate::{
     constants::{TMEWC_ETHEREUM_TOKEN_ADDRESS, TMEWC_ETHEREUM_TOKEN_CHAIN},
     error::WormholeGatewayError,
-    state::Custodian,
+    state::{Custodian, GatewayInfo, OverLimitMode, PendingMint, PendingReceipt},
 };
 use anchor_lang::prelude::*;
-use anchor_spl::{associated_token, token};
+use anchor_lang::system_program;
+use anchor_spl::{associated_token, token, token_interface};
 use wormhole_anchor_sdk::{
     token_bridge::{self, program::TokenBridge},
     wormhole::{self as core_bridge, program::Wormhole as CoreBridge},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(message_hash: [u8; 32])]
 pub struct ReceiveTmewc<'info> {
@@ -33,6 +36,14 @@ pub struct ReceiveTmewc<'info> {
     )]
     posted_vaa: Box<Account<'info, token_bridge::PostedTransferWith<[u8; 32]>>>,
 
+    /// Gateway registered for the VAA's emitter chain. Required while the gateway allowlist is
+    /// enabled.
+    #[account(
+        seeds = [GatewayInfo::SEED_PREFIX, &posted_vaa.emitter_chain().to_le_bytes()],
+        bump = gateway_info.bump,
+    )]
+    gateway_info: Option<Box<Account<'info, GatewayInfo>>>,
+
     /// CHECK: This claim account is created by the Token Bridge program when it redeems its inbound
     /// transfer. By checking whether this account exists is a short-circuit way of bailing out
     /// early if this transfer has already been redeemed (as opposed to letting the Token Bridge
@@ -49,8 +60,11 @@ pub struct ReceiveTmewc<'info> {
     #[account(mut)]
     wrapped_tmewc_mint: Box<Account<'info, token::Mint>>,
 
-    #[account(mut)]
-    tmewc_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        mut,
+        mint::token_program = tmewc_token_program,
+    )]
+    tmewc_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
 
     /// Token account for minted tMEWC.
     ///
@@ -61,8 +75,9 @@ pub struct ReceiveTmewc<'info> {
         mut,
         token::mint = tmewc_mint,
         token::authority = recipient,
+        token::token_program = tmewc_token_program,
     )]
-    recipient_token: Box<Account<'info, token::TokenAccount>>,
+    recipient_token: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
 
     /// CHECK: This account needs to be in the context in case an associated token account needs to
     /// be created for him.
@@ -83,12 +98,40 @@ pub struct ReceiveTmewc<'info> {
     )]
     recipient_wrapped_token: AccountInfo<'info>,
 
+    /// Holds the transfer for the large transfer delay. Required if and only if the amount is
+    /// above the custodian's large transfer threshold.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PendingReceipt::INIT_SPACE,
+        seeds = [PendingReceipt::SEED_PREFIX, &message_hash],
+        bump,
+    )]
+    pending_receipt: Option<Account<'info, PendingReceipt>>,
+
+    /// CHECK: This account exists just in case the transfer cannot be minted while the custodian
+    /// escrows such transfers. The gateway will create it if it doesn't exist. Required if and only
+    /// if the over-limit mode is escrow.
+    #[account(
+        mut,
+        seeds = [PendingMint::SEED_PREFIX, recipient.key().as_ref()],
+        bump,
+    )]
+    pending_mint: Option<UncheckedAccount<'info>>,
+
     /// CHECK: This account is needed for the TMEWC program.
+    #[account(mut)]
     tmewc_config: UncheckedAccount<'info>,
 
     /// CHECK: This account is needed for the TMEWC program.
     tmewc_minter_info: UncheckedAccount<'info>,
 
+    /// CHECK: This account is needed for the TMEWC program while proof of reserve is enabled.
+    tmewc_reserve_attestation: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This account is needed for the TMEWC program.
+    tmewc_event_authority: UncheckedAccount<'info>,
+
     /// CHECK: This account is needed for the Token Bridge program.
     token_bridge_config: UncheckedAccount<'info>,
 
@@ -109,11 +152,20 @@ pub struct ReceiveTmewc<'info> {
     core_bridge_program: Program<'info, CoreBridge>,
     associated_token_program: Program<'info, associated_token::AssociatedToken>,
     token_program: Program<'info, token::Token>,
+
+    /// Token program that owns the canonical tMEWC mint (either SPL Token or Token-2022).
+    tmewc_token_program: Interface<'info, token_interface::TokenInterface>,
+
     system_program: Program<'info, System>,
 }
 
 impl<'info> ReceiveTmewc<'info> {
     fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            !ctx.accounts.custodian.paused.inbound,
+            WormholeGatewayError::InboundPaused
+        );
+
         // Check if transfer has already been claimed.
         require!(
             ctx.accounts.token_bridge_claim.data_is_empty(),
@@ -128,6 +180,24 @@ impl<'info> ReceiveTmewc<'info> {
             WormholeGatewayError::InvalidEthereumTmewc
         );
 
+        // Only registered gateways that are not disabled can send tMEWC while the allowlist is
+        // enabled.
+        if ctx.accounts.custodian.gateway_allowlist {
+            let gateway_info = ctx
+                .accounts
+                .gateway_info
+                .as_ref()
+                .ok_or(WormholeGatewayError::InvalidSendingGateway)?;
+            require!(
+                *transfer.from_address() == gateway_info.address,
+                WormholeGatewayError::InvalidSendingGateway
+            );
+            require!(
+                !gateway_info.disabled,
+                WormholeGatewayError::GatewayDisabled
+            );
+        }
+
         // There must be an encoded amount.
         require_gt!(
             transfer.amount(),
@@ -142,12 +212,352 @@ impl<'info> ReceiveTmewc<'info> {
             WormholeGatewayError::RecipientZeroAddress
         );
 
+        // Large transfers are held for a delay before minting.
+        let threshold = ctx.accounts.custodian.large_transfer_threshold;
+        let is_large = threshold != 0 && transfer.amount() > threshold;
+        require!(
+            is_large == ctx.accounts.pending_receipt.is_some(),
+            WormholeGatewayError::InvalidPendingReceipt
+        );
+
+        // Escrowed transfers need a pending mint for the recipient.
+        require!(
+            (ctx.accounts.custodian.over_limit_mode == OverLimitMode::Escrow)
+                == ctx.accounts.pending_mint.is_some(),
+            WormholeGatewayError::InvalidPendingMint
+        );
+
         Ok(())
     }
+
+    fn settle_receipt(&mut self) -> SettleReceipt<'_, 'info> {
+        SettleReceipt {
+            payer: self.payer.to_account_info(),
+            custodian: &mut self.custodian,
+            wrapped_tmewc_token: self.wrapped_tmewc_token.to_account_info(),
+            wrapped_tmewc_mint: &self.wrapped_tmewc_mint,
+            tmewc_mint: &self.tmewc_mint,
+            recipient_token: self.recipient_token.to_account_info(),
+            recipient: self.recipient.to_account_info(),
+            recipient_wrapped_token: self.recipient_wrapped_token.to_account_info(),
+            pending_mint: self
+                .pending_mint
+                .as_ref()
+                .map(|info| info.to_account_info()),
+            tmewc_config: self.tmewc_config.to_account_info(),
+            tmewc_minter_info: self.tmewc_minter_info.to_account_info(),
+            tmewc_reserve_attestation: self
+                .tmewc_reserve_attestation
+                .as_ref()
+                .map(|info| info.to_account_info()),
+            tmewc_event_authority: self.tmewc_event_authority.to_account_info(),
+            tmewc_program: self.tmewc_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            tmewc_token_program: self.tmewc_token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        }
+    }
+}
+
+/// Portions of a received transfer minted as canonical tMEWC and delivered as Wormhole tMEWC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Settlement {
+    pub minted: u64,
+    pub wrapped: u64,
+
+    /// Portion of the Wormhole tMEWC escrowed instead of sent to the recipient.
+    pub escrowed: u64,
+}
+
+/// Accounts to settle a received transfer, either by minting canonical tMEWC to the recipient or by
+/// delivering Wormhole tMEWC according to the custodian's over-limit mode instead.
+pub(crate) struct SettleReceipt<'ctx, 'info> {
+    pub payer: AccountInfo<'info>,
+    pub custodian: &'ctx mut Account<'info, Custodian>,
+    pub wrapped_tmewc_token: AccountInfo<'info>,
+    pub wrapped_tmewc_mint: &'ctx Account<'info, token::Mint>,
+    pub tmewc_mint: &'ctx InterfaceAccount<'info, token_interface::Mint>,
+    pub recipient_token: AccountInfo<'info>,
+    pub recipient: AccountInfo<'info>,
+    pub recipient_wrapped_token: AccountInfo<'info>,
+    pub pending_mint: Option<AccountInfo<'info>>,
+    pub tmewc_config: AccountInfo<'info>,
+    pub tmewc_minter_info: AccountInfo<'info>,
+    pub tmewc_reserve_attestation: Option<AccountInfo<'info>>,
+    pub tmewc_event_authority: AccountInfo<'info>,
+    pub tmewc_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub tmewc_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'ctx, 'info> SettleReceipt<'ctx, 'info> {
+    /// Mint canonical tMEWC to the recipient, unless the minting limit, the inbound rate limit, the
+    /// attested reserve or the TMEWC program's auto-pause prevents it, in which case Wormhole tMEWC
+    /// is sent or escrowed instead. With partial minting, only the amount over the limits is
+    /// delivered this way.
+    pub(crate) fn settle(self, amount: u64) -> Result<Settlement> {
+        let tmewc_decimals = self.tmewc_mint.decimals;
+        let wrapped_decimals = self.wrapped_tmewc_mint.decimals;
+
+        // The received amount is in Wormhole tMEWC, while the limits account for canonical tMEWC.
+        let received = NormalizedAmount::from_wrapped(amount, tmewc_decimals, wrapped_decimals)?;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        let remaining_limit = self
+            .custodian
+            .minting_limit
+            .saturating_sub(self.custodian.minted_amount);
+        let available_rate = self.custodian.inbound_rate_limit.available_at(timestamp);
+        let remaining_reserve = self.remaining_reserve(timestamp)?;
+
+        if received.tmewc > remaining_limit {
+            msg!("Insufficient minted amount");
+        } else if received.tmewc > available_rate {
+            msg!("Inbound rate limit exceeded");
+        } else if received.tmewc > remaining_reserve {
+            msg!("Reserve attestation is stale or exceeded");
+        }
+
+        // Unless partial minting is enabled, we send Wormhole tMEWC OR mint canonical tMEWC. We do
+        // not want to send dust. Sending Wormhole tMEWC is an exceptional situation and we want to
+        // keep it simple.
+        let mintable = remaining_limit.min(available_rate).min(remaining_reserve);
+        let mut minted = if received.tmewc <= mintable {
+            received
+        } else if self.custodian.partial_mint {
+            NormalizedAmount::from_tmewc(mintable, tmewc_decimals, wrapped_decimals)?
+        } else {
+            NormalizedAmount::default()
+        };
+
+        if minted.tmewc > 0 {
+            if self.mint_tmewc(minted.tmewc)? {
+                self.custodian.minted_amount += minted.tmewc;
+                self.custodian
+                    .inbound_rate_limit
+                    .track_mint(minted.tmewc, timestamp);
+            } else {
+                msg!("tMEWC minting was auto-paused");
+                minted = NormalizedAmount::default();
+            }
+        }
+
+        let not_minted = received.wrapped - minted.wrapped;
+        if not_minted > 0 {
+            self.fall_back(not_minted)?;
+        }
+
+        // Wormhole tMEWC finer than canonical tMEWC's precision can never be minted, so it is sent
+        // to the recipient rather than escrowed.
+        let dust = amount - received.wrapped;
+        if dust > 0 {
+            self.transfer_wrapped_tmewc(dust)?;
+        }
+
+        Ok(Settlement {
+            minted: minted.tmewc,
+            wrapped: amount - minted.wrapped,
+            escrowed: match self.custodian.over_limit_mode {
+                OverLimitMode::SendWrapped => 0,
+                OverLimitMode::Escrow => not_minted,
+            },
+        })
+    }
+
+    /// Amount the TMEWC program's proof of reserve lets the gateway mint. A stale attestation
+    /// yields zero, so the transfer falls back to Wormhole tMEWC instead of failing to mint.
+    fn remaining_reserve(&self, timestamp: i64) -> Result<u64> {
+        let config = Account::<tmewc::Config>::try_from(&self.tmewc_config)?;
+        require_keys_eq!(
+            config.mint,
+            self.tmewc_mint.key(),
+            WormholeGatewayError::InvalidTmewcConfig
+        );
+        if config.reserve_attester.is_none() {
+            return Ok(u64::MAX);
+        }
+
+        // Make sure this is tMEWC's attestation, since no mint CPI checks it on fall back.
+        let attestation = self
+            .tmewc_reserve_attestation
+            .as_ref()
+            .ok_or(WormholeGatewayError::InvalidTmewcReserveAttestation)?;
+        let attestation = Account::<tmewc::ReserveAttestation>::try_from(attestation)?;
+        let expected = Pubkey::create_program_address(
+            &[
+                tmewc::ReserveAttestation::SEED_PREFIX,
+                &config.asset_seed(),
+                &[attestation.bump],
+            ],
+            &tmewc::ID,
+        )
+        .map_err(|_| WormholeGatewayError::InvalidTmewcReserveAttestation)?;
+        require_keys_eq!(
+            attestation.key(),
+            expected,
+            WormholeGatewayError::InvalidTmewcReserveAttestation
+        );
+
+        Ok(tmewc::ReserveAttestation::mintable(
+            Some(&attestation),
+            &config,
+            self.tmewc_mint.supply,
+            timestamp,
+        ))
+    }
+
+    /// Deliver Wormhole tMEWC that cannot be minted as canonical tMEWC.
+    fn fall_back(&self, amount: u64) -> Result<()> {
+        match self.custodian.over_limit_mode {
+            OverLimitMode::SendWrapped => {
+                msg!("Sending Wormhole tMEWC instead");
+                self.transfer_wrapped_tmewc(amount)
+            }
+            OverLimitMode::Escrow => {
+                msg!("Escrowing Wormhole tMEWC instead");
+                self.escrow_wrapped_tmewc(amount)
+            }
+        }
+    }
+
+    /// Mint canonical tMEWC to the recipient. Returns `false` if the TMEWC program auto-paused
+    /// minting instead of minting.
+    fn mint_tmewc(&self, amount: u64) -> Result<bool> {
+        let minted = tmewc::cpi::mint(
+            CpiContext::new_with_signer(
+                self.tmewc_program.to_account_info(),
+                tmewc::cpi::accounts::Mint {
+                    mint: self.tmewc_mint.to_account_info(),
+                    config: self.tmewc_config.to_account_info(),
+                    minter_info: Some(self.tmewc_minter_info.to_account_info()),
+                    role_assignment: None,
+                    reserve_attestation: self
+                        .tmewc_reserve_attestation
+                        .as_ref()
+                        .map(|info| info.to_account_info()),
+                    minter: self.custodian.to_account_info(),
+                    recipient_token: self.recipient_token.to_account_info(),
+                    token_program: self.tmewc_token_program.to_account_info(),
+                    event_authority: self.tmewc_event_authority.to_account_info(),
+                    program: self.tmewc_program.to_account_info(),
+                },
+                &[&[Custodian::SEED_PREFIX, &[self.custodian.bump]]],
+            ),
+            amount,
+        )?
+        .get();
+
+        Ok(minted)
+    }
+
+    /// Send Wormhole tMEWC from custody to the recipient's associated token account.
+    fn transfer_wrapped_tmewc(&self, amount: u64) -> Result<()> {
+        let ata = &self.recipient_wrapped_token;
+
+        // Create associated token account for recipient if it doesn't exist already.
+        if ata.data_is_empty() {
+            associated_token::create(CpiContext::new(
+                self.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: self.payer.to_account_info(),
+                    associated_token: ata.to_account_info(),
+                    authority: self.recipient.to_account_info(),
+                    mint: self.wrapped_tmewc_mint.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                },
+            ))?;
+        }
+
+        // Finally transfer.
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.wrapped_tmewc_token.to_account_info(),
+                    to: ata.to_account_info(),
+                    authority: self.custodian.to_account_info(),
+                },
+                &[&[Custodian::SEED_PREFIX, &[self.custodian.bump]]],
+            ),
+            amount,
+        )
+    }
+
+    /// Keep Wormhole tMEWC in custody, adding it to the recipient's pending mint. The pending mint
+    /// is created if it doesn't exist.
+    fn escrow_wrapped_tmewc(&self, amount: u64) -> Result<()> {
+        let pending_mint = self
+            .pending_mint
+            .as_ref()
+            .ok_or(WormholeGatewayError::InvalidPendingMint)?;
+        let recipient = self.recipient.key();
+
+        let mut claim = if pending_mint.data_is_empty() {
+            let (_, bump) = Pubkey::find_program_address(
+                &[PendingMint::SEED_PREFIX, recipient.as_ref()],
+                &crate::ID,
+            );
+            let space = 8 + PendingMint::INIT_SPACE;
+            let signer_seeds: &[&[u8]] = &[PendingMint::SEED_PREFIX, recipient.as_ref(), &[bump]];
+
+            // Anyone can send lamports to this address beforehand, which would make creating the
+            // account fail. So top it up to rent exemption and allocate and assign it instead.
+            let lamports = Rent::get()?
+                .minimum_balance(space)
+                .saturating_sub(pending_mint.lamports());
+            if lamports > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: self.payer.to_account_info(),
+                            to: pending_mint.to_account_info(),
+                        },
+                    ),
+                    lamports,
+                )?;
+            }
+            system_program::allocate(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    system_program::Allocate {
+                        account_to_allocate: pending_mint.to_account_info(),
+                    },
+                    &[signer_seeds],
+                ),
+                space as u64,
+            )?;
+            system_program::assign(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    system_program::Assign {
+                        account_to_assign: pending_mint.to_account_info(),
+                    },
+                    &[signer_seeds],
+                ),
+                &crate::ID,
+            )?;
+
+            PendingMint {
+                bump,
+                payer: self.payer.key(),
+                recipient,
+                amount: 0,
+            }
+        } else {
+            Account::<PendingMint>::try_from(pending_mint)?.into_inner()
+        };
+        claim.amount += amount;
+        claim.try_serialize(&mut &mut pending_mint.try_borrow_mut_data()?[..])
+    }
 }
 
 #[access_control(ReceiveTmewc::constraints(&ctx))]
-pub fn receive_tmewc(ctx: Context<ReceiveTmewc>, _message_hash: [u8; 32]) -> Result<()> {
+pub fn receive_tmewc(ctx: Context<ReceiveTmewc>, message_hash: [u8; 32]) -> Result<()> {
     let wrapped_tmewc_token = &ctx.accounts.wrapped_tmewc_token;
     let wrapped_tmewc_mint = &ctx.accounts.wrapped_tmewc_mint;
 
@@ -179,73 +589,61 @@ pub fn receive_tmewc(ctx: Context<ReceiveTmewc>, _message_hash: [u8; 32]) -> Res
         ]],
     ))?;
 
-    // Because we are working with wrapped token amounts, we can take the amount as-is and determine
-    // whether to mint or transfer based on the minting limit.
+    // This is an amount of Wormhole tMEWC, which settling normalizes to canonical tMEWC.
     let amount = ctx.accounts.posted_vaa.data().amount();
-    let recipient = &ctx.accounts.recipient;
 
-    emit!(crate::event::WormholeTmewcReceived {
-        receiver: recipient.key(),
+    emit_cpi!(crate::event::WormholeTmewcReceived {
+        receiver: ctx.accounts.recipient.key(),
         amount
     });
 
-    let updated_minted_amount = ctx.accounts.custodian.minted_amount.saturating_add(amount);
-    let custodian_seeds = &[Custodian::SEED_PREFIX, &[ctx.accounts.custodian.bump]];
+    if let Some(pending_receipt) = &mut ctx.accounts.pending_receipt {
+        let release_at = Clock::get()?
+            .unix_timestamp
+            .saturating_add(ctx.accounts.custodian.large_transfer_delay.into());
 
-    // We send Wormhole tMEWC OR mint canonical tMEWC. We do not want to send dust. Sending Wormhole
-    // tMEWC is an exceptional situation and we want to keep it simple.
-    if updated_minted_amount > ctx.accounts.custodian.minting_limit {
-        msg!("Insufficient minted amount. Sending Wormhole tMEWC instead");
+        pending_receipt.set_inner(PendingReceipt {
+            bump: ctx.bumps["pending_receipt"],
+            payer: ctx.accounts.payer.key(),
+            recipient: ctx.accounts.recipient.key(),
+            amount,
+            message_hash,
+            release_at,
+            cancelled: false,
+        });
 
-        let ata = &ctx.accounts.recipient_wrapped_token;
+        emit_cpi!(crate::event::PendingReceiptQueued {
+            recipient: ctx.accounts.recipient.key(),
+            amount,
+            message_hash,
+            release_at
+        });
 
-        // Create associated token account for recipient if it doesn't exist already.
-        if ata.data_is_empty() {
-            associated_token::create(CpiContext::new(
-                ctx.accounts.associated_token_program.to_account_info(),
-                associated_token::Create {
-                    payer: ctx.accounts.payer.to_account_info(),
-                    associated_token: ata.to_account_info(),
-                    authority: recipient.to_account_info(),
-                    mint: wrapped_tmewc_mint.to_account_info(),
-                    token_program: ctx.accounts.token_program.to_account_info(),
-                    system_program: ctx.accounts.system_program.to_account_info(),
-                },
-            ))?;
-        }
+        return Ok(());
+    }
 
-        // Finally transfer.
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                token::Transfer {
-                    from: wrapped_tmewc_token.to_account_info(),
-                    to: ata.to_account_info(),
-                    authority: ctx.accounts.custodian.to_account_info(),
-                },
-                &[custodian_seeds],
-            ),
-            amount,
-        )
-    } else {
-        // The function is non-reentrant given bridge.completeTransferWithPayload
-        // call that does not allow to use the same VAA again.
-        ctx.accounts.custodian.minted_amount = updated_minted_amount;
+    // The function is non-reentrant given bridge.completeTransferWithPayload
+    // call that does not allow to use the same VAA again.
+    let Settlement {
+        minted,
+        wrapped,
+        escrowed,
+    } = ctx.accounts.settle_receipt().settle(amount)?;
+
+    if escrowed > 0 {
+        emit_cpi!(crate::event::WormholeTmewcEscrowed {
+            recipient: ctx.accounts.recipient.key(),
+            amount: escrowed
+        });
+    }
 
-        tmewc::cpi::mint(
-            CpiContext::new_with_signer(
-                ctx.accounts.tmewc_program.to_account_info(),
-                tmewc::cpi::accounts::Mint {
-                    mint: ctx.accounts.tmewc_mint.to_account_info(),
-                    config: ctx.accounts.tmewc_config.to_account_info(),
-                    minter_info: ctx.accounts.tmewc_minter_info.to_account_info(),
-                    minter: ctx.accounts.custodian.to_account_info(),
-                    recipient_token: ctx.accounts.recipient_token.to_account_info(),
-                    token_program: ctx.accounts.token_program.to_account_info(),
-                },
-                &[custodian_seeds],
-            ),
-            amount,
-        )
+    if minted > 0 && wrapped > 0 {
+        emit_cpi!(crate::event::WormholeTmewcPartiallyMinted {
+            recipient: ctx.accounts.recipient.key(),
+            minted,
+            wrapped
+        });
     }
+
+    Ok(())
 }
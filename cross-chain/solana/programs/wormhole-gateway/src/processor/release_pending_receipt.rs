@@ -0,0 +1,206 @@
+use super::{SettleReceipt, Settlement};
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, OverLimitMode, PendingMint, PendingReceipt},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token, token, token_interface};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReleasePendingReceipt<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = wrapped_tmewc_token,
+        has_one = wrapped_tmewc_mint,
+        has_one = tmewc_mint
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        has_one = recipient,
+        close = receipt_payer,
+        seeds = [PendingReceipt::SEED_PREFIX, pending_receipt.message_hash.as_ref()],
+        bump = pending_receipt.bump,
+    )]
+    pending_receipt: Account<'info, PendingReceipt>,
+
+    /// CHECK: Payer of the pending receipt, who gets its rent back.
+    #[account(
+        mut,
+        address = pending_receipt.payer,
+    )]
+    receipt_payer: AccountInfo<'info>,
+
+    /// Custody account.
+    #[account(mut)]
+    wrapped_tmewc_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// This mint is owned by the Wormhole Token Bridge program. This PDA address is stored in the
+    /// custodian account.
+    wrapped_tmewc_mint: Box<Account<'info, token::Mint>>,
+
+    #[account(
+        mut,
+        mint::token_program = tmewc_token_program,
+    )]
+    tmewc_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    /// Token account for minted tMEWC.
+    #[account(
+        mut,
+        token::mint = tmewc_mint,
+        token::authority = recipient,
+        token::token_program = tmewc_token_program,
+    )]
+    recipient_token: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    /// CHECK: This account needs to be in the context in case an associated token account needs to
+    /// be created for him. This pubkey lives in `PendingReceipt`.
+    recipient: AccountInfo<'info>,
+
+    /// CHECK: This account exists just in case the minting limit is breached by this transfer.
+    /// The gateway will create an associated token account for the recipient if it doesn't exist.
+    #[account(
+        mut,
+        address = associated_token::get_associated_token_address(
+            &recipient.key(),
+            &wrapped_tmewc_mint.key()
+        ),
+    )]
+    recipient_wrapped_token: AccountInfo<'info>,
+
+    /// CHECK: This account exists just in case the transfer cannot be minted while the custodian
+    /// escrows such transfers. The gateway will create it if it doesn't exist. Required if and only
+    /// if the over-limit mode is escrow.
+    #[account(
+        mut,
+        seeds = [PendingMint::SEED_PREFIX, recipient.key().as_ref()],
+        bump,
+    )]
+    pending_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This account is needed for the TMEWC program.
+    #[account(mut)]
+    tmewc_config: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the TMEWC program.
+    tmewc_minter_info: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the TMEWC program while proof of reserve is enabled.
+    tmewc_reserve_attestation: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This account is needed for the TMEWC program.
+    tmewc_event_authority: UncheckedAccount<'info>,
+
+    tmewc_program: Program<'info, tmewc::Tmewc>,
+    associated_token_program: Program<'info, associated_token::AssociatedToken>,
+    token_program: Program<'info, token::Token>,
+
+    /// Token program that owns the canonical tMEWC mint (either SPL Token or Token-2022).
+    tmewc_token_program: Interface<'info, token_interface::TokenInterface>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> ReleasePendingReceipt<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            !ctx.accounts.custodian.paused.inbound,
+            WormholeGatewayError::InboundPaused
+        );
+
+        require!(
+            !ctx.accounts.pending_receipt.cancelled,
+            WormholeGatewayError::PendingReceiptCancelled
+        );
+
+        require_gte!(
+            Clock::get()?.unix_timestamp,
+            ctx.accounts.pending_receipt.release_at,
+            WormholeGatewayError::PendingReceiptDelayed
+        );
+
+        // Escrowed transfers need a pending mint for the recipient.
+        require!(
+            (ctx.accounts.custodian.over_limit_mode == OverLimitMode::Escrow)
+                == ctx.accounts.pending_mint.is_some(),
+            WormholeGatewayError::InvalidPendingMint
+        );
+
+        Ok(())
+    }
+
+    fn settle_receipt(&mut self) -> SettleReceipt<'_, 'info> {
+        SettleReceipt {
+            payer: self.payer.to_account_info(),
+            custodian: &mut self.custodian,
+            wrapped_tmewc_token: self.wrapped_tmewc_token.to_account_info(),
+            wrapped_tmewc_mint: &self.wrapped_tmewc_mint,
+            tmewc_mint: &self.tmewc_mint,
+            recipient_token: self.recipient_token.to_account_info(),
+            recipient: self.recipient.to_account_info(),
+            recipient_wrapped_token: self.recipient_wrapped_token.to_account_info(),
+            pending_mint: self
+                .pending_mint
+                .as_ref()
+                .map(|info| info.to_account_info()),
+            tmewc_config: self.tmewc_config.to_account_info(),
+            tmewc_minter_info: self.tmewc_minter_info.to_account_info(),
+            tmewc_reserve_attestation: self
+                .tmewc_reserve_attestation
+                .as_ref()
+                .map(|info| info.to_account_info()),
+            tmewc_event_authority: self.tmewc_event_authority.to_account_info(),
+            tmewc_program: self.tmewc_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            tmewc_token_program: self.tmewc_token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        }
+    }
+}
+
+/// Mint the tMEWC of a pending receipt once its delay has passed. Anyone can release it. Like
+/// `receive_tmewc`, Wormhole tMEWC is sent or escrowed instead if the minting limits prevent
+/// minting.
+#[access_control(ReleasePendingReceipt::constraints(&ctx))]
+pub fn release_pending_receipt(ctx: Context<ReleasePendingReceipt>) -> Result<()> {
+    let pending_receipt = &ctx.accounts.pending_receipt;
+    let amount = pending_receipt.amount;
+
+    emit_cpi!(crate::event::PendingReceiptReleased {
+        recipient: pending_receipt.recipient,
+        amount,
+        message_hash: pending_receipt.message_hash
+    });
+
+    let Settlement {
+        minted,
+        wrapped,
+        escrowed,
+    } = ctx.accounts.settle_receipt().settle(amount)?;
+
+    if escrowed > 0 {
+        emit_cpi!(crate::event::WormholeTmewcEscrowed {
+            recipient: ctx.accounts.recipient.key(),
+            amount: escrowed
+        });
+    }
+
+    if minted > 0 && wrapped > 0 {
+        emit_cpi!(crate::event::WormholeTmewcPartiallyMinted {
+            recipient: ctx.accounts.recipient.key(),
+            minted,
+            wrapped
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+    },
+};
+
+/// Invoke one of this program's instructions (`data`, with `accounts`) with `authority`, a PDA of
+/// this program derived from `signer_seeds`, as signer.
+pub(crate) fn invoke_self_signed<'info>(
+    accounts: &[AccountInfo<'info>],
+    authority: &AccountInfo<'info>,
+    program: &AccountInfo<'info>,
+    data: Vec<u8>,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let metas = accounts
+        .iter()
+        .map(|info| AccountMeta {
+            pubkey: info.key(),
+            is_signer: info.is_signer || info.key == authority.key,
+            is_writable: info.is_writable,
+        })
+        .collect();
+
+    let mut account_infos = accounts.to_vec();
+    account_infos.push(authority.clone());
+    account_infos.push(program.clone());
+
+    invoke_signed(
+        &Instruction {
+            program_id: crate::ID,
+            accounts: metas,
+            data,
+        },
+        &account_infos,
+        &[signer_seeds],
+    )
+    .map_err(Into::into)
+}
@@ -1,14 +1,16 @@
 use crate::{
     constants::MSG_SEED_PREFIX,
+    error::WormholeGatewayError,
     state::{Custodian, GatewayInfo},
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token;
+use anchor_spl::{token, token_interface};
 use wormhole_anchor_sdk::{
     token_bridge::{self, program::TokenBridge},
     wormhole::{self as core_bridge, program::Wormhole as CoreBridge},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: SendTmewcGatewayArgs)]
 pub struct SendTmewcGateway<'info> {
@@ -24,6 +26,7 @@ pub struct SendTmewcGateway<'info> {
     custodian: Account<'info, Custodian>,
 
     #[account(
+        mut,
         seeds = [GatewayInfo::SEED_PREFIX, &args.recipient_chain.to_le_bytes()],
         bump = gateway_info.bump,
     )]
@@ -33,19 +36,24 @@ pub struct SendTmewcGateway<'info> {
     #[account(mut)]
     wrapped_tmewc_token: Box<Account<'info, token::TokenAccount>>,
 
-    /// CHECK: This account is needed for the Token Bridge program.
+    /// This mint is owned by the Wormhole Token Bridge program. Its decimals determine the amount
+    /// to bridge.
     #[account(mut)]
-    wrapped_tmewc_mint: UncheckedAccount<'info>,
+    wrapped_tmewc_mint: Box<Account<'info, token::Mint>>,
 
-    #[account(mut)]
-    tmewc_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        mut,
+        mint::token_program = tmewc_token_program,
+    )]
+    tmewc_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
 
     #[account(
         mut,
         token::mint = tmewc_mint,
-        token::authority = sender
+        token::authority = sender,
+        token::token_program = tmewc_token_program,
     )]
-    sender_token: Box<Account<'info, token::TokenAccount>>,
+    sender_token: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
 
     #[account(mut)]
     sender: Signer<'info>,
@@ -98,13 +106,29 @@ pub struct SendTmewcGateway<'info> {
     token_bridge_program: Program<'info, TokenBridge>,
     core_bridge_program: Program<'info, CoreBridge>,
     token_program: Program<'info, token::Token>,
+
+    /// Token program that owns the canonical tMEWC mint (either SPL Token or Token-2022).
+    tmewc_token_program: Interface<'info, token_interface::TokenInterface>,
+
     system_program: Program<'info, System>,
 }
 
 impl<'info> SendTmewcGateway<'info> {
     fn constraints(ctx: &Context<Self>, args: &SendTmewcGatewayArgs) -> Result<()> {
+        require!(
+            !ctx.accounts.custodian.paused.outbound,
+            WormholeGatewayError::OutboundPaused
+        );
+
+        require!(
+            !ctx.accounts.gateway_info.disabled,
+            WormholeGatewayError::GatewayDisabled
+        );
+
         super::validate_send(
             &ctx.accounts.wrapped_tmewc_token,
+            &ctx.accounts.tmewc_mint,
+            &ctx.accounts.wrapped_tmewc_mint,
             &args.recipient,
             args.amount,
         )
@@ -135,8 +159,19 @@ pub fn send_tmewc_gateway(ctx: Context<SendTmewcGateway>, args: SendTmewcGateway
 
     let gateway = ctx.accounts.gateway_info.address;
 
-    // Prepare for wrapped tMEWC transfer (this method also truncates the amount to prevent having to
-    // handle dust since tMEWC has >8 decimals).
+    // Truncate the amount to Wormhole's precision so that no dust is burned without being bridged.
+    let amount = super::NormalizedAmount::new(
+        amount,
+        ctx.accounts.tmewc_mint.decimals,
+        ctx.accounts.wrapped_tmewc_mint.decimals,
+    )?;
+
+    ctx.accounts
+        .gateway_info
+        .outbound_limit
+        .track_send(amount.tmewc, Clock::get()?.unix_timestamp)?;
+
+    // Prepare for wrapped tMEWC transfer.
     super::burn_and_prepare_transfer(
         super::PrepareTransfer {
             custodian: &mut ctx.accounts.custodian,
@@ -146,14 +181,19 @@ pub fn send_tmewc_gateway(ctx: Context<SendTmewcGateway>, args: SendTmewcGateway
             wrapped_tmewc_token,
             token_bridge_transfer_authority,
             token_program,
+            tmewc_token_program: &ctx.accounts.tmewc_token_program,
         },
-        amount,
+        &amount,
+    )?;
+
+    emit_cpi!(crate::event::WormholeTmewcSent {
+        amount: amount.tmewc,
         recipient_chain,
-        Some(gateway),
+        gateway,
         recipient,
-        None, // arbiter_fee
-        nonce,
-    )?;
+        arbiter_fee: 0,
+        nonce
+    });
 
     let custodian = &ctx.accounts.custodian;
 
@@ -195,7 +235,7 @@ pub fn send_tmewc_gateway(ctx: Context<SendTmewcGateway>, args: SendTmewcGateway
             ],
         ),
         nonce,
-        amount,
+        amount.wrapped,
         gateway,
         recipient_chain,
         recipient.to_vec(),
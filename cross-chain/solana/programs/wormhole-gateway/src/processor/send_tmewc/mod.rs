@@ -4,23 +4,28 @@ pub use gateway::*;
 mod wrapped;
 pub use wrapped::*;
 
-use crate::error::WormholeGatewayError;
-use crate::state::Custodian;
+use super::NormalizedAmount;
+use crate::{error::WormholeGatewayError, state::Custodian};
 use anchor_lang::prelude::*;
-use anchor_spl::token;
+use anchor_spl::{token, token_interface};
 
 pub fn validate_send(
     wrapped_tmewc_token: &Account<'_, token::TokenAccount>,
+    tmewc_mint: &InterfaceAccount<'_, token_interface::Mint>,
+    wrapped_tmewc_mint: &Account<'_, token::Mint>,
     recipient: &[u8; 32],
     amount: u64,
 ) -> Result<()> {
     require!(*recipient != [0; 32], WormholeGatewayError::ZeroRecipient);
     require_gt!(amount, 0, WormholeGatewayError::ZeroAmount);
 
+    let normalized =
+        NormalizedAmount::new(amount, tmewc_mint.decimals, wrapped_tmewc_mint.decimals)?;
+
     // Check that the wrapped tMEWC in custody is at least enough to bridge out.
     require_gte!(
         wrapped_tmewc_token.amount,
-        amount,
+        normalized.wrapped,
         WormholeGatewayError::NotEnoughWrappedTmewc
     );
 
@@ -29,22 +34,18 @@ pub fn validate_send(
 
 pub struct PrepareTransfer<'ctx, 'info> {
     custodian: &'ctx mut Account<'info, Custodian>,
-    tmewc_mint: &'ctx Account<'info, token::Mint>,
-    sender_token: &'ctx Account<'info, token::TokenAccount>,
+    tmewc_mint: &'ctx InterfaceAccount<'info, token_interface::Mint>,
+    sender_token: &'ctx InterfaceAccount<'info, token_interface::TokenAccount>,
     sender: &'ctx Signer<'info>,
     wrapped_tmewc_token: &'ctx Account<'info, token::TokenAccount>,
     token_bridge_transfer_authority: &'ctx AccountInfo<'info>,
     token_program: &'ctx Program<'info, token::Token>,
+    tmewc_token_program: &'ctx Interface<'info, token_interface::TokenInterface>,
 }
 
 pub fn burn_and_prepare_transfer(
     prepare_transfer: PrepareTransfer,
-    amount: u64,
-    recipient_chain: u16,
-    gateway: Option<[u8; 32]>,
-    recipient: [u8; 32],
-    arbiter_fee: Option<u64>,
-    nonce: u32,
+    amount: &NormalizedAmount,
 ) -> Result<()> {
     let PrepareTransfer {
         custodian,
@@ -54,36 +55,28 @@ pub fn burn_and_prepare_transfer(
         wrapped_tmewc_token,
         token_bridge_transfer_authority,
         token_program,
+        tmewc_token_program,
     } = prepare_transfer;
 
     // Account for burning tMEWC.
     custodian.minted_amount = custodian
         .minted_amount
-        .checked_sub(amount)
+        .checked_sub(amount.tmewc)
         .ok_or(WormholeGatewayError::MintedAmountUnderflow)?;
 
     // Burn TMEWC mint.
-    token::burn(
+    token_interface::burn(
         CpiContext::new(
-            token_program.to_account_info(),
-            token::Burn {
+            tmewc_token_program.to_account_info(),
+            token_interface::Burn {
                 mint: tmewc_mint.to_account_info(),
                 from: sender_token.to_account_info(),
                 authority: sender.to_account_info(),
             },
         ),
-        amount,
+        amount.tmewc,
     )?;
 
-    emit!(crate::event::WormholeTmewcSent {
-        amount,
-        recipient_chain,
-        gateway: gateway.unwrap_or_default(),
-        recipient,
-        arbiter_fee: arbiter_fee.unwrap_or_default(),
-        nonce
-    });
-
     // Delegate authority to Token Bridge's transfer authority.
     token::approve(
         CpiContext::new_with_signer(
@@ -95,6 +88,6 @@ pub fn burn_and_prepare_transfer(
             },
             &[&[Custodian::SEED_PREFIX, &[custodian.bump]]],
         ),
-        amount,
+        amount.wrapped,
     )
 }
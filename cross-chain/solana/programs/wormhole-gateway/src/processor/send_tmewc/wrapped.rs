@@ -1,11 +1,16 @@
-use crate::{constants::MSG_SEED_PREFIX, state::Custodian};
+use crate::{
+    constants::MSG_SEED_PREFIX,
+    error::WormholeGatewayError,
+    state::{Custodian, GatewayInfo},
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token;
+use anchor_spl::{token, token_interface};
 use wormhole_anchor_sdk::{
     token_bridge::{self, program::TokenBridge},
     wormhole::{self as core_bridge, program::Wormhole as CoreBridge},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: SendTmewcWrappedArgs)]
 pub struct SendTmewcWrapped<'info> {
@@ -19,23 +24,37 @@ pub struct SendTmewcWrapped<'info> {
     )]
     custodian: Account<'info, Custodian>,
 
+    /// CHECK: Gateway info of the recipient chain, which may not exist. If it does, its outbound
+    /// limit applies instead of the custodian's default one.
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &args.recipient_chain.to_le_bytes()],
+        bump,
+    )]
+    gateway_info: UncheckedAccount<'info>,
+
     /// Custody account.
     #[account(mut)]
     wrapped_tmewc_token: Box<Account<'info, token::TokenAccount>>,
 
-    /// CHECK: This account is needed for the Token Bridge program.
+    /// This mint is owned by the Wormhole Token Bridge program. Its decimals determine the amount
+    /// to bridge.
     #[account(mut)]
-    wrapped_tmewc_mint: UncheckedAccount<'info>,
+    wrapped_tmewc_mint: Box<Account<'info, token::Mint>>,
 
-    #[account(mut)]
-    tmewc_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        mut,
+        mint::token_program = tmewc_token_program,
+    )]
+    tmewc_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
 
     #[account(
         mut,
         token::mint = tmewc_mint,
-        token::authority = sender
+        token::authority = sender,
+        token::token_program = tmewc_token_program,
     )]
-    sender_token: Box<Account<'info, token::TokenAccount>>,
+    sender_token: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
 
     #[account(mut)]
     sender: Signer<'info>,
@@ -84,13 +103,24 @@ pub struct SendTmewcWrapped<'info> {
     token_bridge_program: Program<'info, TokenBridge>,
     core_bridge_program: Program<'info, CoreBridge>,
     token_program: Program<'info, token::Token>,
+
+    /// Token program that owns the canonical tMEWC mint (either SPL Token or Token-2022).
+    tmewc_token_program: Interface<'info, token_interface::TokenInterface>,
+
     system_program: Program<'info, System>,
 }
 
 impl<'info> SendTmewcWrapped<'info> {
     fn constraints(ctx: &Context<Self>, args: &SendTmewcWrappedArgs) -> Result<()> {
+        require!(
+            !ctx.accounts.custodian.paused.outbound,
+            WormholeGatewayError::OutboundPaused
+        );
+
         super::validate_send(
             &ctx.accounts.wrapped_tmewc_token,
+            &ctx.accounts.tmewc_mint,
+            &ctx.accounts.wrapped_tmewc_mint,
             &args.recipient,
             args.amount,
         )
@@ -121,6 +151,29 @@ pub fn send_tmewc_wrapped(ctx: Context<SendTmewcWrapped>, args: SendTmewcWrapped
     let token_bridge_transfer_authority = &ctx.accounts.token_bridge_transfer_authority;
     let token_program = &ctx.accounts.token_program;
 
+    // Truncate the amount to Wormhole's precision so that no dust is burned without being bridged.
+    let amount = super::NormalizedAmount::new(
+        amount,
+        ctx.accounts.tmewc_mint.decimals,
+        ctx.accounts.wrapped_tmewc_mint.decimals,
+    )?;
+
+    // Chains without a gateway share the default outbound limit.
+    let timestamp = Clock::get()?.unix_timestamp;
+    if ctx.accounts.gateway_info.data_is_empty() {
+        ctx.accounts
+            .custodian
+            .default_outbound_limit
+            .track_send(amount.tmewc, timestamp)?;
+    } else {
+        let mut gateway_info =
+            Account::<GatewayInfo>::try_from(&ctx.accounts.gateway_info.to_account_info())?;
+        gateway_info
+            .outbound_limit
+            .track_send(amount.tmewc, timestamp)?;
+        gateway_info.exit(&crate::ID)?;
+    }
+
     // Prepare for wrapped tMEWC transfer.
     super::burn_and_prepare_transfer(
         super::PrepareTransfer {
@@ -131,14 +184,19 @@ pub fn send_tmewc_wrapped(ctx: Context<SendTmewcWrapped>, args: SendTmewcWrapped
             wrapped_tmewc_token,
             token_bridge_transfer_authority,
             token_program,
+            tmewc_token_program: &ctx.accounts.tmewc_token_program,
         },
-        amount,
+        &amount,
+    )?;
+
+    emit_cpi!(crate::event::WormholeTmewcSent {
+        amount: amount.tmewc,
         recipient_chain,
-        None, // gateway
+        gateway: Default::default(),
         recipient,
-        Some(arbiter_fee),
-        nonce,
-    )?;
+        arbiter_fee,
+        nonce
+    });
 
     let custodian = &ctx.accounts.custodian;
 
@@ -175,7 +233,7 @@ pub fn send_tmewc_wrapped(ctx: Context<SendTmewcWrapped>, args: SendTmewcWrapped
             ],
         ),
         nonce,
-        amount,
+        amount.wrapped,
         arbiter_fee,
         recipient,
         recipient_chain,
@@ -1,3 +1,4 @@
+use crate::state::{InboundRateLimit, OutboundLimit};
 use anchor_lang::prelude::*;
 use wormhole_anchor_sdk::token_bridge;
 
@@ -15,6 +16,76 @@ pub struct Custodian {
     pub token_bridge_sender_bump: u8,
     pub minting_limit: u64,
     pub minted_amount: u64,
+
+    /// Ethereum authority, whose secp256k1 signatures authorize admin instructions signed by the
+    /// eth authority PDA.
+    pub eth_authority: Option<[u8; 20]>,
+    pub eth_authority_nonce: u64,
+
+    /// Emitter of governance VAAs on Ethereum. Zero if not registered.
+    pub governance_emitter: [u8; 32],
+
+    /// Directions of bridging paused by a guardian.
+    pub paused: PauseFlags,
+
+    /// Caps on tMEWC sent by `send_tmewc_wrapped` to chains without a gateway, tracked across all
+    /// of them.
+    pub default_outbound_limit: OutboundLimit,
+
+    /// Rate of minting for inbound transfers and deposits, on top of `minting_limit`.
+    pub inbound_rate_limit: InboundRateLimit,
+
+    /// Inbound transfers above this amount are held for `large_transfer_delay` seconds before
+    /// minting. Zero disables the delay.
+    pub large_transfer_threshold: u64,
+    pub large_transfer_delay: u32,
+
+    /// How inbound transfers are delivered when canonical tMEWC cannot be minted.
+    pub over_limit_mode: OverLimitMode,
+
+    /// Whether inbound transfers over the minting limits are minted up to the limits, delivering
+    /// only the excess according to `over_limit_mode`.
+    pub partial_mint: bool,
+
+    /// Whether inbound transfers must be sent by the enabled gateway registered for their emitter
+    /// chain.
+    pub gateway_allowlist: bool,
+}
+
+/// Parts of the gateway that can be paused independently, so that e.g. inbound bridging can be
+/// stopped while users can still bridge out.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace,
+)]
+pub struct PauseFlags {
+    /// Receiving tMEWC from other chains.
+    pub inbound: bool,
+
+    /// Sending tMEWC to other chains.
+    pub outbound: bool,
+
+    /// Depositing wrapped tMEWC for canonical tMEWC.
+    pub deposit: bool,
+}
+
+/// Delivery of inbound transfers that cannot be minted as canonical tMEWC, e.g. because of the
+/// minting limit.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace,
+)]
+pub enum OverLimitMode {
+    /// Send Wormhole tMEWC to the recipient, who can deposit it later.
+    #[default]
+    SendWrapped,
+
+    /// Keep Wormhole tMEWC in custody as a `PendingMint` for the recipient.
+    Escrow,
+}
+
+impl PauseFlags {
+    pub fn any(&self) -> bool {
+        self.inbound || self.outbound || self.deposit
+    }
 }
 
 impl Custodian {
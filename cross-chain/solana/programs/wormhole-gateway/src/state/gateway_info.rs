@@ -1,3 +1,4 @@
+use crate::state::OutboundLimit;
 use anchor_lang::prelude::*;
 
 #[account]
@@ -5,6 +6,12 @@ use anchor_lang::prelude::*;
 pub struct GatewayInfo {
     pub bump: u8,
     pub address: [u8; 32],
+
+    /// Whether sending to this chain's gateway is cut off.
+    pub disabled: bool,
+
+    /// Caps on tMEWC sent to this chain.
+    pub outbound_limit: OutboundLimit,
 }
 
 impl GatewayInfo {
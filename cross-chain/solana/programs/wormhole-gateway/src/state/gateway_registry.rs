@@ -0,0 +1,76 @@
+use anchor_lang::{prelude::*, solana_program::program::MAX_RETURN_DATA};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct RegisteredGateway {
+    pub chain: u16,
+    pub address: [u8; 32],
+    pub disabled: bool,
+}
+
+/// Gateways with a `GatewayInfo`, sorted by chain, so that clients can list the supported
+/// destination chains without scanning all program accounts.
+#[account]
+#[derive(Debug)]
+pub struct GatewayRegistry {
+    pub bump: u8,
+    pub gateways: Vec<RegisteredGateway>,
+}
+
+impl GatewayRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"gateway-registry";
+
+    /// Most gateways that fit in an instruction's return data, which `get_gateways` pages by.
+    pub const MAX_GATEWAYS_PER_PAGE: usize = (MAX_RETURN_DATA - 4) / RegisteredGateway::INIT_SPACE;
+
+    pub(crate) fn compute_size(num_gateways: usize) -> usize {
+        8 + 1 + 4 + num_gateways * RegisteredGateway::INIT_SPACE
+    }
+
+    /// Account size once `chain` is registered.
+    pub(crate) fn size_with(&self, chain: u16) -> usize {
+        match self.find(chain) {
+            Ok(_) => Self::compute_size(self.gateways.len()),
+            Err(_) => Self::compute_size(self.gateways.len() + 1),
+        }
+    }
+
+    /// Account size once `chain` is removed.
+    pub(crate) fn size_without(&self, chain: u16) -> usize {
+        match self.find(chain) {
+            Ok(_) => Self::compute_size(self.gateways.len() - 1),
+            Err(_) => Self::compute_size(self.gateways.len()),
+        }
+    }
+
+    /// Register `chain`'s gateway address, keeping its status if it is already registered.
+    pub(crate) fn upsert(&mut self, chain: u16, address: [u8; 32]) {
+        match self.find(chain) {
+            Ok(index) => self.gateways[index].address = address,
+            Err(index) => self.gateways.insert(
+                index,
+                RegisteredGateway {
+                    chain,
+                    address,
+                    disabled: false,
+                },
+            ),
+        }
+    }
+
+    pub(crate) fn set_disabled(&mut self, chain: u16, disabled: bool) {
+        if let Ok(index) = self.find(chain) {
+            self.gateways[index].disabled = disabled;
+        }
+    }
+
+    pub(crate) fn remove(&mut self, chain: u16) {
+        if let Ok(index) = self.find(chain) {
+            self.gateways.remove(index);
+        }
+    }
+
+    fn find(&self, chain: u16) -> std::result::Result<usize, usize> {
+        self.gateways
+            .binary_search_by_key(&chain, |gateway| gateway.chain)
+    }
+}
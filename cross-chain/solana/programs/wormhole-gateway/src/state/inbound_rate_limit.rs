@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+/// Rate limit on tMEWC minted for inbound transfers and deposits. Up to `capacity` can be minted at
+/// once, and the capacity refills linearly over `window_duration`. A zero capacity disables it.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace,
+)]
+pub struct InboundRateLimit {
+    pub capacity: u64,
+    pub window_duration: u32,
+    pub available: u64,
+    pub last_updated: i64,
+}
+
+impl InboundRateLimit {
+    /// Set new thresholds, starting with the full capacity available.
+    pub(crate) fn update(&mut self, capacity: u64, window_duration: u32, timestamp: i64) {
+        *self = Self {
+            capacity,
+            window_duration,
+            available: capacity,
+            last_updated: timestamp,
+        };
+    }
+
+    /// Amount that can be minted at `timestamp`.
+    pub(crate) fn available_at(&self, timestamp: i64) -> u64 {
+        if self.capacity == 0 {
+            return u64::MAX;
+        }
+
+        let elapsed = u128::try_from(timestamp.saturating_sub(self.last_updated)).unwrap_or(0);
+        let refill = match self.window_duration {
+            0 => u128::from(self.capacity),
+            duration => u128::from(self.capacity) * elapsed / u128::from(duration),
+        };
+
+        (u128::from(self.available) + refill).min(u128::from(self.capacity)) as u64
+    }
+
+    /// Account for minting `amount` at `timestamp`, which must not exceed `available_at`.
+    pub(crate) fn track_mint(&mut self, amount: u64, timestamp: i64) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.available = self.available_at(timestamp).saturating_sub(amount);
+        self.last_updated = timestamp;
+    }
+}
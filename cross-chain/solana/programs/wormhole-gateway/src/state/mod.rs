@@ -1,5 +1,29 @@
+mod audit_log;
+pub use audit_log::*;
+
 mod custodian;
 pub use custodian::*;
 
 mod gateway_info;
 pub use gateway_info::*;
+
+mod gateway_registry;
+pub use gateway_registry::*;
+
+mod governance_receipt;
+pub use governance_receipt::*;
+
+mod guardian_info;
+pub use guardian_info::*;
+
+mod inbound_rate_limit;
+pub use inbound_rate_limit::*;
+
+mod outbound_limit;
+pub use outbound_limit::*;
+
+mod pending_mint;
+pub use pending_mint::*;
+
+mod pending_receipt;
+pub use pending_receipt::*;
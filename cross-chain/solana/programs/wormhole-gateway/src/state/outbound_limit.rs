@@ -0,0 +1,62 @@
+use crate::error::WormholeGatewayError;
+use anchor_lang::prelude::*;
+
+/// Caps on tMEWC sent to a destination chain. A zero threshold disables the corresponding check.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace,
+)]
+pub struct OutboundLimit {
+    pub max_transfer_amount: u64,
+    pub window_limit: u64,
+    pub window_duration: u32,
+    pub window_start: i64,
+    pub window_amount: u64,
+}
+
+impl OutboundLimit {
+    /// Set new thresholds and start tracking from a fresh window.
+    pub(crate) fn update(
+        &mut self,
+        max_transfer_amount: u64,
+        window_limit: u64,
+        window_duration: u32,
+    ) {
+        *self = Self {
+            max_transfer_amount,
+            window_limit,
+            window_duration,
+            window_start: 0,
+            window_amount: 0,
+        };
+    }
+
+    /// Account for sending `amount` in the current window, failing if the transfer exceeds the
+    /// per-transfer or the per-window threshold.
+    pub(crate) fn track_send(&mut self, amount: u64, timestamp: i64) -> Result<()> {
+        require!(
+            self.max_transfer_amount == 0 || amount <= self.max_transfer_amount,
+            WormholeGatewayError::OutboundTransferLimitExceeded
+        );
+
+        // Start a new window if the current one has elapsed.
+        let window_elapsed = timestamp >= self.window_start + i64::from(self.window_duration);
+        let window_amount = if window_elapsed {
+            0
+        } else {
+            self.window_amount
+        };
+
+        let updated_window_amount = window_amount.saturating_add(amount);
+        require!(
+            self.window_limit == 0 || updated_window_amount <= self.window_limit,
+            WormholeGatewayError::OutboundWindowLimitExceeded
+        );
+
+        if window_elapsed {
+            self.window_start = timestamp;
+        }
+        self.window_amount = updated_window_amount;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Wormhole tMEWC held in custody for a recipient, received while canonical tMEWC could not be
+/// minted. Anyone can process it into canonical tMEWC once minting is possible again.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct PendingMint {
+    pub bump: u8,
+
+    /// Payer of the rent, refunded when the claim is processed.
+    pub payer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+impl PendingMint {
+    pub const SEED_PREFIX: &'static [u8] = b"pending-mint";
+}
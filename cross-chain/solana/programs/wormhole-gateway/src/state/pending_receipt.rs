@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// Inbound transfer above the large transfer threshold, whose tMEWC is held in custody until
+/// `release_at`. Guardians can cancel it before then, after which only the authority can refund it
+/// to the recipient.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct PendingReceipt {
+    pub bump: u8,
+
+    /// Payer of the rent, refunded when the receipt is released or refunded.
+    pub payer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+
+    /// Hash of the redeemed VAA.
+    pub message_hash: [u8; 32],
+    pub release_at: i64,
+    pub cancelled: bool,
+}
+
+impl PendingReceipt {
+    pub const SEED_PREFIX: &'static [u8] = b"pending-receipt";
+}
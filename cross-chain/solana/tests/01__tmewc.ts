@@ -1,14 +1,32 @@
+import { MockEmitter } from "@certusone/wormhole-sdk/lib/cjs/mock";
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
+import { Metadata } from "@metaplex-foundation/mpl-token-metadata";
 import * as spl from "@solana/spl-token";
 import { assert, expect } from "chai";
+import { randomBytes } from "crypto";
+import { ethers } from "ethers";
 import { Tmewc } from "../target/types/tmewc";
+import {
+  LEGACY_GUARDIAN,
+  LEGACY_MINTER,
+  MISMATCHED_TOKEN_PROGRAM,
+  TMEWC_ETH_AUTHORITY_DOMAIN,
+  TMEWC_TOKEN_PROGRAM,
+} from "./helpers/consts";
 import * as tmewc from "./helpers/tmewc";
 import {
+  ethAddressFromPrivateKey,
+  ethAuthorizationDigest,
+  ethAuthorizationIx,
+  ethSignatureIx,
+  expectEvent,
   expectIxFail,
   expectIxSuccess,
   getOrCreateAta,
   getTokenBalance,
+  mockGovernanceVaa,
+  simulateIxReturnData,
   sleep,
   transferLamports,
 } from "./helpers/utils";
@@ -41,7 +59,7 @@ describe("tmewc", () => {
 
   it("initialize", async () => {
     const ix = await tmewc.initializeIx({ authority: authority.publicKey });
-    await expectIxSuccess([ix], [authority]);
+    const txSig = await expectIxSuccess([ix], [authority]);
     await tmewc.checkConfig({
       authority: authority.publicKey,
       numMinters: 0,
@@ -50,6 +68,25 @@ describe("tmewc", () => {
       paused: false,
       pendingAuthority: null,
     });
+
+    const initialized = await expectEvent(txSig, "Initialized");
+    expect(initialized.assetId).to.equal(0);
+    expect(initialized.authority).to.eql(authority.publicKey);
+    expect(initialized.mint).to.eql(tmewc.getMintPDA());
+
+    const mintInfo = await program.provider.connection.getAccountInfo(
+      tmewc.getMintPDA()
+    );
+    expect(mintInfo.owner).to.eql(TMEWC_TOKEN_PROGRAM);
+
+    // The config is the freeze authority, for `freeze_token_account`.
+    const mintState = await spl.getMint(
+      program.provider.connection,
+      tmewc.getMintPDA(),
+      undefined,
+      TMEWC_TOKEN_PROGRAM
+    );
+    expect(mintState.freezeAuthority).to.eql(tmewc.getConfigPDA());
   });
 
   describe("authority changes", () => {
@@ -80,7 +117,7 @@ describe("tmewc", () => {
         authority: authority.publicKey,
         newAuthority: newAuthority.publicKey,
       });
-      await expectIxSuccess([changeIx], [authority]);
+      const txSig = await expectIxSuccess([changeIx], [authority]);
       await tmewc.checkConfig({
         authority: authority.publicKey,
         numMinters: 0,
@@ -89,6 +126,10 @@ describe("tmewc", () => {
         paused: false,
         pendingAuthority: newAuthority.publicKey,
       });
+
+      const staged = await expectEvent(txSig, "AuthorityChangeStaged");
+      expect(staged.authority).to.eql(authority.publicKey);
+      expect(staged.pendingAuthority).to.eql(newAuthority.publicKey);
     });
 
     it("take as new authority", async () => {
@@ -98,7 +139,7 @@ describe("tmewc", () => {
       const takeIx = await tmewc.takeAuthorityIx({
         pendingAuthority: newAuthority.publicKey,
       });
-      await expectIxSuccess([takeIx], [newAuthority]);
+      const txSig = await expectIxSuccess([takeIx], [newAuthority]);
       await tmewc.checkConfig({
         authority: newAuthority.publicKey,
         numMinters: 0,
@@ -107,6 +148,10 @@ describe("tmewc", () => {
         paused: false,
         pendingAuthority: null,
       });
+
+      const changed = await expectEvent(txSig, "AuthorityChanged");
+      expect(changed.oldAuthority).to.eql(authority.publicKey);
+      expect(changed.newAuthority).to.eql(newAuthority.publicKey);
     });
 
     it("change pending authority back to original authority", async () => {
@@ -170,6 +215,217 @@ describe("tmewc", () => {
         pendingAuthority: null,
       });
     });
+
+    it("audit log records authority changes", async () => {
+      const { nextIndex, entries } = await tmewc.getAuditLogData();
+      expect(entries).has.length(nextIndex);
+
+      const [initialized] = entries;
+      expect(initialized.action).to.eql({ initialize: {} });
+      expect(initialized.actor).to.eql(authority.publicKey);
+
+      const lastEntry = entries[entries.length - 1];
+      expect(lastEntry.action).to.eql({ takeAuthority: {} });
+      expect(lastEntry.actor).to.eql(authority.publicKey);
+      expect(lastEntry.target).to.eql(anchor.web3.PublicKey.default);
+    });
+
+    it("change and cancel authority change", async () => {
+      const changeIx = await tmewc.changeAuthorityIx({
+        authority: authority.publicKey,
+        newAuthority: newAuthority.publicKey,
+      });
+      await expectIxSuccess([changeIx], [authority]);
+
+      const cancelIx = await tmewc.cancelAuthorityChangeIx({
+        authority: authority.publicKey,
+      });
+      const txSig = await expectIxSuccess([cancelIx], [authority]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(0),
+        paused: false,
+        pendingAuthority: null,
+      });
+
+      const cancelled = await expectEvent(txSig, "AuthorityChangeCancelled");
+      expect(cancelled.authority).to.eql(authority.publicKey);
+      expect(cancelled.pendingAuthority).to.eql(newAuthority.publicKey);
+    });
+
+    it("audit log wraps around", async () => {
+      const { nextIndex } = await tmewc.getAuditLogData();
+
+      // Fill the whole ring with distinct entries.
+      const maxEntries = 64;
+      for (let batch = 0; batch < maxEntries / 8; ++batch) {
+        const ixs = [];
+        for (let i = 1; i <= 8; ++i) {
+          ixs.push(
+            await tmewc.updateOptimisticMintingDelayIx(
+              { authority: authority.publicKey },
+              batch * 8 + i
+            )
+          );
+        }
+        await expectIxSuccess(ixs, [authority]);
+      }
+
+      // After a full lap, the next index is back where it started and every
+      // older entry was overwritten.
+      const auditLog = await tmewc.getAuditLogData();
+      expect(auditLog.entries).has.length(maxEntries);
+      expect(auditLog.nextIndex).to.equal(nextIndex);
+      for (const entry of auditLog.entries) {
+        expect(entry.action).to.eql({ updateOptimisticMintingDelay: {} });
+      }
+
+      const resetIx = await tmewc.updateOptimisticMintingDelayIx(
+        { authority: authority.publicKey },
+        0
+      );
+      await expectIxSuccess([resetIx], [authority]);
+      expect((await tmewc.getConfigData()).optimisticMintingDelay).to.equal(0);
+    });
+
+    it("cannot migrate config without authority", async () => {
+      const ix = await tmewc.migrateConfigIx({
+        authority: imposter.publicKey,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("migrate config", async () => {
+      const configBefore = await tmewc.getConfigData();
+
+      // An up-to-date config is left as is.
+      const ix = await tmewc.migrateConfigIx({
+        authority: authority.publicKey,
+      });
+      await expectIxSuccess([ix], [authority]);
+      expect(await tmewc.getConfigData()).to.eql(configBefore);
+
+      const { nextIndex, entries } = await tmewc.getAuditLogData();
+      const lastEntry = entries[(nextIndex + entries.length - 1) % 64];
+      expect(lastEntry.action).to.eql({ migrateConfig: {} });
+      expect(lastEntry.actor).to.eql(authority.publicKey);
+      expect(lastEntry.target).to.eql(tmewc.getConfigPDA());
+    });
+
+    it("cannot migrate minter info without authority", async () => {
+      const ix = await tmewc.migrateMinterInfoIx({
+        authority: imposter.publicKey,
+        minter: LEGACY_MINTER,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("migrate minter info", async () => {
+      const connection = program.provider.connection;
+      const minterInfo = tmewc.getMinterInfoPDA(LEGACY_MINTER);
+
+      // The minter info predates `optimistic`, so it cannot be used as is.
+      const { data: legacyData } = await connection.getAccountInfo(minterInfo);
+      expect(legacyData).has.length(41);
+      const cannotUpdateIx = await tmewc.updateMinterModeIx(
+        {
+          authority: authority.publicKey,
+          minter: LEGACY_MINTER,
+        },
+        false
+      );
+      await expectIxFail(
+        [cannotUpdateIx],
+        [authority],
+        "AccountDidNotDeserialize"
+      );
+
+      const ix = await tmewc.migrateMinterInfoIx({
+        authority: authority.publicKey,
+        minter: LEGACY_MINTER,
+      });
+      await expectIxSuccess([ix], [authority]);
+
+      const { data } = await connection.getAccountInfo(minterInfo);
+      expect(data).has.length(42);
+      const minterInfoState = await tmewc.getMinterInfo(LEGACY_MINTER);
+      expect(minterInfoState.minter).to.eql(LEGACY_MINTER);
+      expect(minterInfoState.optimistic).to.be.false;
+
+      const { nextIndex, entries } = await tmewc.getAuditLogData();
+      const lastEntry = entries[(nextIndex + entries.length - 1) % 64];
+      expect(lastEntry.action).to.eql({ migrateMinterInfo: {} });
+      expect(lastEntry.target).to.eql(LEGACY_MINTER);
+
+      // A migrated minter info is left as is.
+      const againIx = await tmewc.migrateMinterInfoIx({
+        authority: authority.publicKey,
+        minter: LEGACY_MINTER,
+      });
+      await expectIxSuccess([againIx], [txPayer, authority]);
+      expect(await tmewc.getMinterInfo(LEGACY_MINTER)).to.eql(minterInfoState);
+    });
+
+    it("cannot migrate guardian info without authority", async () => {
+      const ix = await tmewc.migrateGuardianInfoIx({
+        authority: imposter.publicKey,
+        guardian: LEGACY_GUARDIAN,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("migrate guardian info", async () => {
+      const connection = program.provider.connection;
+      const guardianInfo = tmewc.getGuardianInfoPDA(LEGACY_GUARDIAN);
+
+      // The guardian info predates signed pauses, so it cannot be used as is.
+      const { data: legacyData } = await connection.getAccountInfo(
+        guardianInfo
+      );
+      expect(legacyData).has.length(41);
+      const { guardianEpoch } = await tmewc.getConfigData();
+
+      const ix = await tmewc.migrateGuardianInfoIx({
+        authority: authority.publicKey,
+        guardian: LEGACY_GUARDIAN,
+      });
+      await expectIxSuccess([ix], [authority]);
+
+      // The guardian gets an epoch of its own, like a newly added guardian.
+      const { data } = await connection.getAccountInfo(guardianInfo);
+      expect(data).has.length(57);
+      const guardianInfoState = await tmewc.getGuardianInfo(LEGACY_GUARDIAN);
+      expect(guardianInfoState.guardian).to.eql(LEGACY_GUARDIAN);
+      expect(guardianInfoState.pauseNonce.toNumber()).to.equal(0);
+      expect(guardianInfoState.epoch.toNumber()).to.equal(
+        guardianEpoch.toNumber()
+      );
+      const configState = await tmewc.getConfigData();
+      expect(configState.guardianEpoch.toNumber()).to.equal(
+        guardianEpoch.toNumber() + 1
+      );
+
+      const { nextIndex, entries } = await tmewc.getAuditLogData();
+      const lastEntry = entries[(nextIndex + entries.length - 1) % 64];
+      expect(lastEntry.action).to.eql({ migrateGuardianInfo: {} });
+      expect(lastEntry.target).to.eql(LEGACY_GUARDIAN);
+
+      // A migrated guardian info is left as is, and so is the config's epoch.
+      const againIx = await tmewc.migrateGuardianInfoIx({
+        authority: authority.publicKey,
+        guardian: LEGACY_GUARDIAN,
+      });
+      await expectIxSuccess([againIx], [txPayer, authority]);
+      expect(await tmewc.getGuardianInfo(LEGACY_GUARDIAN)).to.eql(
+        guardianInfoState
+      );
+      const configStateAgain = await tmewc.getConfigData();
+      expect(configStateAgain.guardianEpoch.toNumber()).to.equal(
+        guardianEpoch.toNumber() + 1
+      );
+    });
   });
 
   describe("minting", () => {
@@ -209,9 +465,13 @@ describe("tmewc", () => {
       const recipientToken = await getOrCreateAta(
         authority,
         tmewc.getMintPDA(),
-        recipient.publicKey
+        recipient.publicKey,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const recipientBefore = await getTokenBalance(
+        recipientToken,
+        TMEWC_TOKEN_PROGRAM
       );
-      const recipientBefore = await getTokenBalance(recipientToken);
       expect(recipientBefore).to.equal(BigInt(0));
 
       const mintIx = await tmewc.mintIx(
@@ -221,7 +481,7 @@ describe("tmewc", () => {
         },
         new anchor.BN(amount.toString())
       );
-      await expectIxSuccess([mintIx], [txPayer, minter]);
+      const txSig = await expectIxSuccess([mintIx], [txPayer, minter]);
       await tmewc.checkConfig({
         authority: authority.publicKey,
         numMinters: 1,
@@ -231,14 +491,28 @@ describe("tmewc", () => {
         pendingAuthority: null,
       });
 
-      const recipientAfter = await getTokenBalance(recipientToken);
+      const recipientAfter = await getTokenBalance(
+
+        recipientToken,
+
+        TMEWC_TOKEN_PROGRAM
+
+      );
       expect(recipientAfter).to.equal(amount);
+
+      const minted = await expectEvent(txSig, "Minted");
+      expect(minted.minter).to.eql(minter.publicKey);
+      expect(minted.recipient).to.eql(recipient.publicKey);
+      expect(minted.amount.toString()).to.equal(amount.toString());
+      expect(minted.supply.toString()).to.equal("1000");
     });
 
     it("cannot mint without minter", async () => {
       const recipientToken = spl.getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient.publicKey
+        recipient.publicKey,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       const cannotMintIx = await tmewc.mintIx(
@@ -272,6 +546,30 @@ describe("tmewc", () => {
       );
     });
 
+    it("cannot mint with mismatched token program", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        recipient.publicKey,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      // The token program not owning the tMEWC mint must be rejected.
+      const cannotMintIx = await tmewc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+          tokenProgram: MISMATCHED_TOKEN_PROGRAM,
+        },
+        new anchor.BN(420)
+      );
+      await expectIxFail(
+        [cannotMintIx],
+        [txPayer, minter],
+        "ConstraintMintTokenProgram"
+      );
+    });
+
     it("add another minter", async () => {
       const mustBeNull = await tmewc
         .checkMinterInfo(anotherMinter.publicKey)
@@ -309,9 +607,14 @@ describe("tmewc", () => {
 
       const recipientToken = await spl.getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient.publicKey
+        recipient.publicKey,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const recipientBefore = await getTokenBalance(
+        recipientToken,
+        TMEWC_TOKEN_PROGRAM
       );
-      const recipientBefore = await getTokenBalance(recipientToken);
       expect(recipientBefore).to.equal(BigInt(1000));
 
       const mintIx = await tmewc.mintIx(
@@ -331,7 +634,13 @@ describe("tmewc", () => {
         pendingAuthority: null,
       });
 
-      const recipientAfter = await getTokenBalance(recipientToken);
+      const recipientAfter = await getTokenBalance(
+
+        recipientToken,
+
+        TMEWC_TOKEN_PROGRAM
+
+      );
       expect(recipientAfter).to.equal(recipientBefore + amount);
     });
 
@@ -397,6 +706,8 @@ describe("tmewc", () => {
   });
 
   describe("guardians", () => {
+    let signedPauseIxs: anchor.web3.TransactionInstruction[];
+
     it("cannot add guardian without authority", async () => {
       const cannotAddIx = await tmewc.addGuardianIx({
         authority: imposter.publicKey,
@@ -470,9 +781,14 @@ describe("tmewc", () => {
 
       const recipientToken = spl.getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient.publicKey
+        recipient.publicKey,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const recipientBefore = await getTokenBalance(
+        recipientToken,
+        TMEWC_TOKEN_PROGRAM
       );
-      const recipientBefore = await getTokenBalance(recipientToken);
       expect(recipientBefore).to.equal(BigInt(1500));
 
       const mintIx = await tmewc.mintIx(
@@ -492,7 +808,13 @@ describe("tmewc", () => {
         pendingAuthority: null,
       });
 
-      const recipientAfter = await getTokenBalance(recipientToken);
+      const recipientAfter = await getTokenBalance(
+
+        recipientToken,
+
+        TMEWC_TOKEN_PROGRAM
+
+      );
       expect(recipientAfter).to.equal(recipientBefore + amount);
     });
 
@@ -500,7 +822,7 @@ describe("tmewc", () => {
       const pauseIx = await tmewc.pauseIx({
         guardian: guardian.publicKey,
       });
-      await expectIxSuccess([pauseIx], [txPayer, guardian]);
+      const txSig = await expectIxSuccess([pauseIx], [txPayer, guardian]);
       await tmewc.checkConfig({
         authority: authority.publicKey,
         numMinters: 1,
@@ -509,12 +831,17 @@ describe("tmewc", () => {
         paused: true,
         pendingAuthority: null,
       });
+
+      const paused = await expectEvent(txSig, "Paused");
+      expect(paused.guardian).to.eql(guardian.publicKey);
     });
 
     it("cannot mint while paused", async () => {
       const recipientToken = spl.getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient.publicKey
+        recipient.publicKey,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       const mintIx = await tmewc.mintIx(
@@ -564,7 +891,7 @@ describe("tmewc", () => {
       const unpauseIx = await tmewc.unpauseIx({
         authority: authority.publicKey,
       });
-      await expectIxSuccess([unpauseIx], [authority]);
+      const txSig = await expectIxSuccess([unpauseIx], [authority]);
       await tmewc.checkConfig({
         authority: authority.publicKey,
         numMinters: 1,
@@ -573,6 +900,9 @@ describe("tmewc", () => {
         paused: false,
         pendingAuthority: null,
       });
+
+      const unpaused = await expectEvent(txSig, "Unpaused");
+      expect(unpaused.authority).to.eql(authority.publicKey);
     });
 
     it("cannot unpause again", async () => {
@@ -591,9 +921,14 @@ describe("tmewc", () => {
 
       const recipientToken = spl.getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient.publicKey
+        recipient.publicKey,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const recipientBefore = await getTokenBalance(
+        recipientToken,
+        TMEWC_TOKEN_PROGRAM
       );
-      const recipientBefore = await getTokenBalance(recipientToken);
       expect(recipientBefore).to.equal(BigInt(1600));
 
       const mintIx = await tmewc.mintIx(
@@ -613,7 +948,13 @@ describe("tmewc", () => {
         pendingAuthority: null,
       });
 
-      const recipientAfter = await getTokenBalance(recipientToken);
+      const recipientAfter = await getTokenBalance(
+
+        recipientToken,
+
+        TMEWC_TOKEN_PROGRAM
+
+      );
       expect(recipientAfter).to.equal(recipientBefore + amount);
     });
 
@@ -635,7 +976,9 @@ describe("tmewc", () => {
     it("cannot mint again while paused", async () => {
       const recipientToken = spl.getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient.publicKey
+        recipient.publicKey,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       const mintIx = await tmewc.mintIx(
@@ -712,6 +1055,90 @@ describe("tmewc", () => {
       );
     });
 
+    it("cannot pause with expired signature", async () => {
+      const ixes = await tmewc.pauseWithSignatureIxs(
+        {},
+        guardian,
+        Math.floor(Date.now() / 1000) - 3600
+      );
+      await expectIxFail(ixes, [txPayer], "PauseSignatureExpired");
+    });
+
+    it("cannot pause with wrong nonce", async () => {
+      const ixes = await tmewc.pauseWithSignatureIxs(
+        {},
+        guardian,
+        Math.floor(Date.now() / 1000) + 3600,
+        BigInt(1)
+      );
+      await expectIxFail(ixes, [txPayer], "InvalidPauseNonce");
+    });
+
+    it("cannot pause with another key's signature", async () => {
+      const ixes = await tmewc.pauseWithSignatureIxs(
+        {
+          guardianInfo: tmewc.getGuardianInfoPDA(guardian.publicKey),
+        },
+        imposter,
+        Math.floor(Date.now() / 1000) + 3600
+      );
+      await expectIxFail(ixes, [txPayer], "InvalidPauseSignature");
+    });
+
+    it("cannot pause without signature verification", async () => {
+      const [, pauseIx] = await tmewc.pauseWithSignatureIxs(
+        {},
+        guardian,
+        Math.floor(Date.now() / 1000) + 3600
+      );
+      await expectIxFail([pauseIx], [txPayer], "InvalidPauseSignature");
+    });
+
+    it("pause with signature", async () => {
+      // Anyone can relay the guardian's signature.
+      const ixes = await tmewc.pauseWithSignatureIxs(
+        {},
+        guardian,
+        Math.floor(Date.now() / 1000) + 3600
+      );
+      await expectIxSuccess(ixes, [txPayer]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 1,
+        supply: BigInt(1800),
+        paused: true,
+        pendingAuthority: null,
+      });
+
+      const unpauseIx = await tmewc.unpauseIx({
+        authority: authority.publicKey,
+      });
+      await expectIxSuccess([unpauseIx], [authority]);
+
+      // The same signature cannot be used twice. Relay it from another fee
+      // payer so the transaction is not rejected as already processed.
+      await expectIxFail(ixes, [imposter], "InvalidPauseNonce");
+      signedPauseIxs = ixes;
+    });
+
+    it("cannot replay signature after guardian is re-added", async () => {
+      const removeIx = await tmewc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      const addIx = await tmewc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([removeIx, addIx], [authority]);
+
+      // The pause nonce starts over, but the guardian epoch does not.
+      const guardianInfo = await tmewc.getGuardianInfo(guardian.publicKey);
+      expect(guardianInfo.pauseNonce.toNumber()).to.equal(0);
+      await expectIxFail(signedPauseIxs, [authority], "InvalidPauseSignature");
+    });
+
     it("pause and remove last guardian", async () => {
       const pauseIx = await tmewc.pauseIx({
         guardian: guardian.publicKey,
@@ -748,7 +1175,9 @@ describe("tmewc", () => {
     it("cannot mint yet again", async () => {
       const recipientToken = spl.getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient.publicKey
+        recipient.publicKey,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       const mintIx = await tmewc.mintIx(
@@ -777,12 +1206,20 @@ describe("tmewc", () => {
 
       const recipientToken = spl.getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient.publicKey
+        recipient.publicKey,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       const amount = BigInt(200);
 
-      const recipientBefore = await getTokenBalance(recipientToken);
+      const recipientBefore = await getTokenBalance(
+
+        recipientToken,
+
+        TMEWC_TOKEN_PROGRAM
+
+      );
       const mintIx = await tmewc.mintIx(
         {
           minter: minter.publicKey,
@@ -800,7 +1237,13 @@ describe("tmewc", () => {
         pendingAuthority: null,
       });
 
-      const recipientAfter = await getTokenBalance(recipientToken);
+      const recipientAfter = await getTokenBalance(
+
+        recipientToken,
+
+        TMEWC_TOKEN_PROGRAM
+
+      );
       expect(recipientAfter).to.equal(recipientBefore + amount);
     });
 
@@ -820,4 +1263,1740 @@ describe("tmewc", () => {
       });
     });
   });
+
+  describe("anomaly guard", () => {
+    const recipientToken = spl.getAssociatedTokenAddressSync(
+      tmewc.getMintPDA(),
+      recipient.publicKey,
+      false,
+      TMEWC_TOKEN_PROGRAM
+    );
+
+    it("cannot update anomaly guard without authority", async () => {
+      const cannotUpdateIx = await tmewc.updateAnomalyGuardIx(
+        {
+          authority: imposter.publicKey,
+        },
+        {
+          maxMintAmount: new anchor.BN(500),
+          mintWindowLimit: new anchor.BN(700),
+          mintWindowDuration: 3600,
+        }
+      );
+      await expectIxFail([cannotUpdateIx], [imposter], "IsNotAuthority");
+    });
+
+    it("update anomaly guard", async () => {
+      const updateIx = await tmewc.updateAnomalyGuardIx(
+        {
+          authority: authority.publicKey,
+        },
+        {
+          maxMintAmount: new anchor.BN(500),
+          mintWindowLimit: new anchor.BN(700),
+          mintWindowDuration: 3600,
+        }
+      );
+      await expectIxSuccess([updateIx], [authority]);
+
+      const configState = await tmewc.getConfigData();
+      expect(configState.maxMintAmount.toNumber()).to.equal(500);
+      expect(configState.mintWindowLimit.toNumber()).to.equal(700);
+      expect(configState.mintWindowDuration).to.equal(3600);
+    });
+
+    it("mint within thresholds", async () => {
+      const addMinterIx = await tmewc.addMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([addMinterIx], [authority]);
+
+      const mintIx = await tmewc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(400)
+      );
+
+      // The instruction reports that it minted.
+      const returnData = await simulateIxReturnData(
+        [mintIx],
+        [txPayer, minter]
+      );
+      expect(returnData).to.eql(Buffer.from([1]));
+
+      await expectIxSuccess([mintIx], [txPayer, minter]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2400),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+
+    it("auto pause on oversized mint", async () => {
+      const mintIx = await tmewc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(600)
+      );
+
+      // The transaction succeeds so the pause sticks, but nothing is minted.
+      // The instruction reports this to CPI callers through its return data.
+      const returnData = await simulateIxReturnData(
+        [mintIx],
+        [txPayer, minter]
+      );
+      expect(returnData).to.eql(Buffer.from([0]));
+
+      await expectIxSuccess([mintIx], [txPayer, minter]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2400),
+        paused: true,
+        pendingAuthority: null,
+      });
+    });
+
+    it("auto pause on window threshold", async () => {
+      const unpauseIx = await tmewc.unpauseIx({
+        authority: authority.publicKey,
+      });
+      await expectIxSuccess([unpauseIx], [authority]);
+
+      // 400 already minted in this window, so another 400 exceeds 700.
+      const mintIx = await tmewc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(400)
+      );
+      await expectIxSuccess([mintIx], [txPayer, minter]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2400),
+        paused: true,
+        pendingAuthority: null,
+      });
+    });
+
+    it("disable anomaly guard and clean up", async () => {
+      const updateIx = await tmewc.updateAnomalyGuardIx(
+        {
+          authority: authority.publicKey,
+        },
+        {
+          maxMintAmount: new anchor.BN(0),
+          mintWindowLimit: new anchor.BN(0),
+          mintWindowDuration: 0,
+        }
+      );
+      const unpauseIx = await tmewc.unpauseIx({
+        authority: authority.publicKey,
+      });
+      const removeIx = await tmewc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([updateIx, unpauseIx, removeIx], [authority]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2400),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("optimistic minting", () => {
+    const recipientToken = spl.getAssociatedTokenAddressSync(
+      tmewc.getMintPDA(),
+      recipient.publicKey,
+      false,
+      TMEWC_TOKEN_PROGRAM
+    );
+
+    it("cannot update optimistic minting delay without authority", async () => {
+      const cannotUpdateIx = await tmewc.updateOptimisticMintingDelayIx(
+        {
+          authority: imposter.publicKey,
+        },
+        2
+      );
+      await expectIxFail([cannotUpdateIx], [imposter], "IsNotAuthority");
+    });
+
+    it("cannot make minter optimistic without delay", async () => {
+      const addMinterIx = await tmewc.addMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      const updateModeIx = await tmewc.updateMinterModeIx(
+        {
+          authority: authority.publicKey,
+          minter: minter.publicKey,
+        },
+        true
+      );
+      await expectIxFail(
+        [addMinterIx, updateModeIx],
+        [authority],
+        "NoOptimisticMintingDelay"
+      );
+    });
+
+    it("set up optimistic minter", async () => {
+      const updateDelayIx = await tmewc.updateOptimisticMintingDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        2
+      );
+      const addMinterIx = await tmewc.addMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      const updateModeIx = await tmewc.updateMinterModeIx(
+        {
+          authority: authority.publicKey,
+          minter: minter.publicKey,
+        },
+        true
+      );
+      const addGuardianIx = await tmewc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess(
+        [updateDelayIx, addMinterIx, updateModeIx, addGuardianIx],
+        [authority]
+      );
+
+      const configState = await tmewc.getConfigData();
+      expect(configState.optimisticMintingDelay).to.equal(2);
+
+      const minterInfo = await tmewc.getMinterInfo(minter.publicKey);
+      expect(minterInfo.optimistic).to.be.true;
+    });
+
+    it("cannot update minter mode without authority", async () => {
+      const cannotUpdateIx = await tmewc.updateMinterModeIx(
+        {
+          authority: imposter.publicKey,
+          minter: minter.publicKey,
+        },
+        false
+      );
+      await expectIxFail([cannotUpdateIx], [imposter], "IsNotAuthority");
+    });
+
+    it("cannot mint instantly as optimistic minter", async () => {
+      const mintIx = await tmewc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxFail([mintIx], [txPayer, minter], "MinterIsOptimistic");
+    });
+
+    it("request and cancel mint", async () => {
+      const { nextMintRequestId } = await tmewc.getConfigData();
+      const mintRequest = tmewc.getMintRequestPDA(nextMintRequestId);
+
+      const requestIx = await tmewc.requestMintIx(
+        {
+          minter: minter.publicKey,
+          mintRequest,
+          recipientToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxSuccess([requestIx], [minter]);
+
+      const requestState = await tmewc.getMintRequestData(nextMintRequestId);
+      expect(requestState.minter).to.eql(minter.publicKey);
+      expect(requestState.recipientToken).to.eql(recipientToken);
+      expect(requestState.amount.toNumber()).to.equal(100);
+      expect(requestState.finalizableAt.toNumber()).to.equal(
+        requestState.requestedAt.toNumber() + 2
+      );
+
+      // Cannot finalize before the delay elapses.
+      const finalizeIx = await tmewc.finalizeMintIx({
+        mintRequest,
+        minter: minter.publicKey,
+        recipientToken,
+      });
+      await expectIxFail([finalizeIx], [txPayer], "MintRequestNotFinalizable");
+
+      // Only guardians can cancel.
+      const cannotCancelIx = await tmewc.cancelMintRequestIx({
+        mintRequest,
+        minter: minter.publicKey,
+        guardian: imposter.publicKey,
+      });
+      await expectIxFail([cannotCancelIx], [imposter], "AccountNotInitialized");
+
+      const cancelIx = await tmewc.cancelMintRequestIx({
+        mintRequest,
+        minter: minter.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess([cancelIx], [guardian]);
+      expect(await tmewc.getMintRequestData(nextMintRequestId)).is.null;
+    });
+
+    it("request and finalize mint", async () => {
+      const { nextMintRequestId } = await tmewc.getConfigData();
+      const mintRequest = tmewc.getMintRequestPDA(nextMintRequestId);
+
+      const requestIx = await tmewc.requestMintIx(
+        {
+          minter: minter.publicKey,
+          mintRequest,
+          recipientToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxSuccess([requestIx], [minter]);
+
+      // Raising the delay does not hold up pending requests.
+      const updateDelayIx = await tmewc.updateOptimisticMintingDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        600
+      );
+      await expectIxSuccess([updateDelayIx], [authority]);
+
+      await sleep(3000);
+
+      // Anyone can finalize after the delay.
+      const finalizeIx = await tmewc.finalizeMintIx({
+        mintRequest,
+        minter: minter.publicKey,
+        recipientToken,
+      });
+      await expectIxSuccess([finalizeIx], [txPayer]);
+      expect(await tmewc.getMintRequestData(nextMintRequestId)).is.null;
+
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 1,
+        supply: BigInt(2500),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+
+    it("clean up optimistic minter", async () => {
+      const updateDelayIx = await tmewc.updateOptimisticMintingDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        0
+      );
+      const removeMinterIx = await tmewc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      const removeGuardianIx = await tmewc.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian.publicKey,
+      });
+      await expectIxSuccess(
+        [updateDelayIx, removeMinterIx, removeGuardianIx],
+        [authority]
+      );
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2500),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("multiple assets", () => {
+    const assetId = 1;
+    const assetMint = tmewc.getMintPDA(assetId);
+    const assetAccounts = {
+      config: tmewc.getConfigPDA(assetId),
+      auditLog: tmewc.getAuditLogPDA(assetId),
+      minters: tmewc.getMintersPDA(assetId),
+      minterInfo: tmewc.getMinterInfoPDA(minter.publicKey, assetId),
+    };
+
+    it("cannot initialize another asset without tMEWC authority", async () => {
+      const ix = await tmewc.initializeIx(
+        { authority: imposter.publicKey },
+        { assetId, name: "Test Asset", symbol: "TEST" }
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+
+      const missingConfigIx = await tmewc.initializeIx(
+        { tmewcConfig: null, authority: authority.publicKey },
+        { assetId, name: "Test Asset", symbol: "TEST" }
+      );
+      await expectIxFail([missingConfigIx], [authority], "IsNotAuthority");
+    });
+
+    it("initialize another asset", async () => {
+      const ix = await tmewc.initializeIx(
+        {
+          mint: assetMint,
+          config: assetAccounts.config,
+          auditLog: assetAccounts.auditLog,
+          guardians: tmewc.getGuardiansPDA(assetId),
+          minters: assetAccounts.minters,
+          authority: authority.publicKey,
+          tmewcMetadata: tmewc.getTmewcMetadataPDA(assetId),
+        },
+        { assetId, name: "Test Asset", symbol: "TEST" }
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const configState = await tmewc.getConfigData(assetId);
+      expect(configState.assetId).to.equal(assetId);
+      expect(configState.mint).to.eql(assetMint);
+      expect(configState.mint).to.not.eql(tmewc.getMintPDA());
+    });
+
+    it("mint another asset without touching tMEWC", async () => {
+      const addMinterIx = await tmewc.addMinterIx({
+        ...assetAccounts,
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([addMinterIx], [authority]);
+
+      const recipientToken = await getOrCreateAta(
+        authority,
+        assetMint,
+        recipient.publicKey,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const mintIx = await tmewc.mintIx(
+        {
+          mint: assetMint,
+          config: assetAccounts.config,
+          minterInfo: assetAccounts.minterInfo,
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxSuccess([mintIx], [txPayer, minter]);
+      expect(
+        await getTokenBalance(recipientToken, TMEWC_TOKEN_PROGRAM)
+      ).to.equal(BigInt(100));
+
+      // The tMEWC minter registry is untouched.
+      const mintTmewcIx = await tmewc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken: spl.getAssociatedTokenAddressSync(
+            tmewc.getMintPDA(),
+            recipient.publicKey,
+            false,
+            TMEWC_TOKEN_PROGRAM
+          ),
+        },
+        new anchor.BN(100)
+      );
+      await expectIxFail(
+        [mintTmewcIx],
+        [txPayer, minter],
+        "AccountNotInitialized"
+      );
+
+      const removeMinterIx = await tmewc.removeMinterIx({
+        ...assetAccounts,
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([removeMinterIx], [authority]);
+
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2500),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+
+    it("initialize, mint and transfer a Token-2022 asset", async () => {
+      const token2022AssetId = 2;
+      const token2022Mint = tmewc.getMintPDA(token2022AssetId);
+      const token2022Accounts = {
+        config: tmewc.getConfigPDA(token2022AssetId),
+        auditLog: tmewc.getAuditLogPDA(token2022AssetId),
+        minters: tmewc.getMintersPDA(token2022AssetId),
+        minterInfo: tmewc.getMinterInfoPDA(minter.publicKey, token2022AssetId),
+      };
+
+      const initializeIx = await tmewc.initializeIx(
+        {
+          mint: token2022Mint,
+          config: token2022Accounts.config,
+          auditLog: token2022Accounts.auditLog,
+          guardians: tmewc.getGuardiansPDA(token2022AssetId),
+          minters: token2022Accounts.minters,
+          authority: authority.publicKey,
+          tmewcMetadata: tmewc.getTmewcMetadataPDA(token2022AssetId),
+          tokenProgram: spl.TOKEN_2022_PROGRAM_ID,
+        },
+        { assetId: token2022AssetId, name: "Token-2022 Asset", symbol: "T22" }
+      );
+      await expectIxSuccess([initializeIx], [authority]);
+
+      const mintInfo = await program.provider.connection.getAccountInfo(
+        token2022Mint
+      );
+      expect(mintInfo.owner).to.eql(spl.TOKEN_2022_PROGRAM_ID);
+
+      const addMinterIx = await tmewc.addMinterIx({
+        ...token2022Accounts,
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([addMinterIx], [authority]);
+
+      // Receive: mint to a Token-2022 account.
+      const recipientToken = await getOrCreateAta(
+        authority,
+        token2022Mint,
+        recipient.publicKey,
+        spl.TOKEN_2022_PROGRAM_ID
+      );
+      const mintIx = await tmewc.mintIx(
+        {
+          mint: token2022Mint,
+          config: token2022Accounts.config,
+          minterInfo: token2022Accounts.minterInfo,
+          minter: minter.publicKey,
+          recipientToken,
+          tokenProgram: spl.TOKEN_2022_PROGRAM_ID,
+        },
+        new anchor.BN(300)
+      );
+      await expectIxSuccess([mintIx], [txPayer, minter]);
+      expect(
+        await getTokenBalance(recipientToken, spl.TOKEN_2022_PROGRAM_ID)
+      ).to.equal(BigInt(300));
+
+      // Send: the minted tokens move like any other Token-2022 balance.
+      const otherToken = await getOrCreateAta(
+        authority,
+        token2022Mint,
+        txPayer.publicKey,
+        spl.TOKEN_2022_PROGRAM_ID
+      );
+      const transferIx = spl.createTransferCheckedInstruction(
+        recipientToken,
+        token2022Mint,
+        otherToken,
+        recipient.publicKey,
+        120,
+        8,
+        [],
+        spl.TOKEN_2022_PROGRAM_ID
+      );
+      await expectIxSuccess([transferIx], [recipient]);
+      expect(
+        await getTokenBalance(recipientToken, spl.TOKEN_2022_PROGRAM_ID)
+      ).to.equal(BigInt(180));
+      expect(
+        await getTokenBalance(otherToken, spl.TOKEN_2022_PROGRAM_ID)
+      ).to.equal(BigInt(120));
+
+      const removeMinterIx = await tmewc.removeMinterIx({
+        ...token2022Accounts,
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([removeMinterIx], [authority]);
+    });
+  });
+
+  describe("mint authority transfer", () => {
+    // Exercise the hand-off on the second asset so tMEWC keeps minting.
+    const assetId = 1;
+    const assetAccounts = {
+      mint: tmewc.getMintPDA(assetId),
+      config: tmewc.getConfigPDA(assetId),
+      auditLog: tmewc.getAuditLogPDA(assetId),
+      tmewcMetadata: tmewc.getTmewcMetadataPDA(assetId),
+    };
+    const successorAuthority = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("successor")],
+      program.programId
+    )[0];
+
+    it("cannot execute without a scheduled transfer", async () => {
+      const ix = await tmewc.executeMintAuthorityTransferIx({
+        ...assetAccounts,
+        authority: authority.publicKey,
+      });
+      await expectIxFail([ix], [authority], "NoMintAuthorityTransferScheduled");
+    });
+
+    it("cannot schedule without authority", async () => {
+      const ix = await tmewc.scheduleMintAuthorityTransferIx(
+        {
+          config: assetAccounts.config,
+          auditLog: assetAccounts.auditLog,
+          authority: imposter.publicKey,
+        },
+        successorAuthority
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("cannot schedule to a non-PDA successor", async () => {
+      const ix = await tmewc.scheduleMintAuthorityTransferIx(
+        {
+          config: assetAccounts.config,
+          auditLog: assetAccounts.auditLog,
+          authority: authority.publicKey,
+        },
+        imposter.publicKey
+      );
+      await expectIxFail([ix], [authority], "InvalidSuccessorAuthority");
+    });
+
+    it("schedule transfer", async () => {
+      const ix = await tmewc.scheduleMintAuthorityTransferIx(
+        {
+          config: assetAccounts.config,
+          auditLog: assetAccounts.auditLog,
+          authority: authority.publicKey,
+        },
+        successorAuthority
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const configState = await tmewc.getConfigData(assetId);
+      expect(configState.successorAuthority).to.eql(successorAuthority);
+      expect(configState.mintAuthorityTransferAt.toNumber()).to.be.greaterThan(
+        Date.now() / 1000
+      );
+      expect(configState.mintAuthorityTransferred).to.be.false;
+    });
+
+    it("cannot execute before the delay", async () => {
+      const ix = await tmewc.executeMintAuthorityTransferIx({
+        ...assetAccounts,
+        authority: authority.publicKey,
+      });
+      await expectIxFail(
+        [ix],
+        [authority],
+        "MintAuthorityTransferNotExecutable"
+      );
+    });
+
+    it("cannot cancel without authority", async () => {
+      const ix = await tmewc.cancelMintAuthorityTransferIx({
+        config: assetAccounts.config,
+        auditLog: assetAccounts.auditLog,
+        authority: imposter.publicKey,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("cancel transfer", async () => {
+      const ix = await tmewc.cancelMintAuthorityTransferIx({
+        config: assetAccounts.config,
+        auditLog: assetAccounts.auditLog,
+        authority: authority.publicKey,
+      });
+      const txSig = await expectIxSuccess([ix], [authority]);
+
+      const event = await expectEvent(txSig, "MintAuthorityTransferCancelled");
+      expect(event.successorAuthority).to.eql(successorAuthority);
+
+      const configState = await tmewc.getConfigData(assetId);
+      expect(configState.successorAuthority).to.be.null;
+      expect(configState.mintAuthorityTransferAt.toNumber()).to.equal(0);
+      expect(configState.mintAuthorityTransferred).to.be.false;
+
+      // Nothing is left to execute.
+      const executeIx = await tmewc.executeMintAuthorityTransferIx({
+        ...assetAccounts,
+        authority: authority.publicKey,
+      });
+      await expectIxFail(
+        [executeIx],
+        [authority],
+        "NoMintAuthorityTransferScheduled"
+      );
+    });
+  });
+
+  describe("eth authority", () => {
+    const ethPrivateKey = randomBytes(32);
+    const ethAuthority = tmewc.getEthAuthorityPDA();
+    let takeIxes: anchor.web3.TransactionInstruction[];
+
+    it("cannot execute without eth authority", async () => {
+      const takeIx = await tmewc.takeAuthorityIx({
+        pendingAuthority: ethAuthority,
+      });
+      const ixes = await tmewc.executeAsEthAuthorityIxs(
+        {},
+        takeIx,
+        ethPrivateKey
+      );
+      await expectIxFail(ixes, [txPayer], "NoEthAuthority");
+    });
+
+    it("hand authority to eth authority", async () => {
+      const setIx = await tmewc.setEthAuthorityIx(
+        {
+          authority: authority.publicKey,
+        },
+        ethAddressFromPrivateKey(ethPrivateKey)
+      );
+      const changeIx = await tmewc.changeAuthorityIx({
+        authority: authority.publicKey,
+        newAuthority: ethAuthority,
+      });
+      await expectIxSuccess([setIx, changeIx], [authority]);
+
+      // Anyone can relay the Ethereum authority's signature.
+      const takeIx = await tmewc.takeAuthorityIx({
+        pendingAuthority: ethAuthority,
+      });
+      takeIxes = await tmewc.executeAsEthAuthorityIxs(
+        {},
+        takeIx,
+        ethPrivateKey
+      );
+      await expectIxSuccess(takeIxes, [txPayer]);
+
+      await tmewc.checkConfig({
+        authority: ethAuthority,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2500),
+        paused: false,
+        pendingAuthority: null,
+      });
+      const configState = await tmewc.getConfigData();
+      expect(configState.ethAuthorityNonce.toNumber()).to.equal(1);
+    });
+
+    it("cannot replay eth authorization", async () => {
+      await expectIxFail(takeIxes, [imposter], "InvalidEthAuthorization");
+    });
+
+    it("cannot execute without signature verification", async () => {
+      const changeIx = await tmewc.changeAuthorityIx({
+        authority: ethAuthority,
+        newAuthority: imposter.publicKey,
+      });
+      const [, executeIx] = await tmewc.executeAsEthAuthorityIxs(
+        {},
+        changeIx,
+        ethPrivateKey
+      );
+      await expectIxFail([executeIx], [txPayer], "InvalidEthAuthorization");
+    });
+
+    it("cannot execute with another eth key", async () => {
+      const changeIx = await tmewc.changeAuthorityIx({
+        authority: ethAuthority,
+        newAuthority: imposter.publicKey,
+      });
+      const ixes = await tmewc.executeAsEthAuthorityIxs(
+        {},
+        changeIx,
+        randomBytes(32)
+      );
+      await expectIxFail(ixes, [txPayer], "InvalidEthAuthorization");
+    });
+
+    it("cannot execute with a signature for another cluster", async () => {
+      const changeIx = await tmewc.changeAuthorityIx({
+        authority: ethAuthority,
+        newAuthority: imposter.publicKey,
+      });
+      const [, executeIx] = await tmewc.executeAsEthAuthorityIxs(
+        {},
+        changeIx,
+        ethPrivateKey
+      );
+      const { ethAuthorityNonce } = await tmewc.getConfigData();
+      const verifyIx = ethAuthorizationIx(
+        TMEWC_ETH_AUTHORITY_DOMAIN,
+        tmewc.getConfigPDA(),
+        ethAuthorityNonce,
+        changeIx,
+        ethPrivateKey,
+        "devnet"
+      );
+      await expectIxFail(
+        [verifyIx, executeIx],
+        [txPayer],
+        "InvalidEthAuthorization"
+      );
+    });
+
+    it("cannot execute with other account flags", async () => {
+      const changeIx = await tmewc.changeAuthorityIx({
+        authority: ethAuthority,
+        newAuthority: authority.publicKey,
+      });
+      const [verifyIx, executeIx] = await tmewc.executeAsEthAuthorityIxs(
+        {},
+        changeIx,
+        ethPrivateKey
+      );
+
+      // The relayer cannot make an account writable that was signed read-only.
+      const newAuthorityKey = executeIx.keys.find((key) =>
+        key.pubkey.equals(authority.publicKey)
+      );
+      newAuthorityKey.isWritable = true;
+      await expectIxFail(
+        [verifyIx, executeIx],
+        [txPayer],
+        "InvalidEthAuthorization"
+      );
+    });
+
+    it("hand authority back with an Ethereum wallet signature", async () => {
+      const changeIx = await tmewc.changeAuthorityIx({
+        authority: ethAuthority,
+        newAuthority: authority.publicKey,
+      });
+      const [, executeIx] = await tmewc.executeAsEthAuthorityIxs(
+        {},
+        changeIx,
+        ethPrivateKey
+      );
+
+      // Sign the digest like an Ethereum wallet, with `personal_sign`.
+      const { ethAuthorityNonce } = await tmewc.getConfigData();
+      const digest = ethAuthorizationDigest(
+        TMEWC_ETH_AUTHORITY_DOMAIN,
+        tmewc.getConfigPDA(),
+        ethAuthorityNonce,
+        changeIx
+      );
+      const signature = await new ethers.Wallet(ethPrivateKey).signMessage(
+        digest
+      );
+      await expectIxSuccess(
+        [ethSignatureIx(digest, signature), executeIx],
+        [txPayer]
+      );
+
+      const takeIx = await tmewc.takeAuthorityIx({
+        pendingAuthority: authority.publicKey,
+      });
+      const unsetIx = await tmewc.setEthAuthorityIx(
+        {
+          authority: authority.publicKey,
+        },
+        null
+      );
+      await expectIxSuccess([takeIx, unsetIx], [authority]);
+
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2500),
+        paused: false,
+        pendingAuthority: null,
+      });
+      const configState = await tmewc.getConfigData();
+      expect(configState.ethAuthority).is.null;
+    });
+  });
+
+  describe("governance", () => {
+    const governanceEmitter = randomBytes(32);
+    const governance = new MockEmitter(governanceEmitter.toString("hex"), 2, 0);
+    const governanceAuthority = tmewc.getGovernanceAuthorityPDA();
+
+    it("cannot execute without governance emitter", async () => {
+      const takeIx = await tmewc.takeAuthorityIx({
+        pendingAuthority: governanceAuthority,
+      });
+      const signedVaa = await mockGovernanceVaa(
+        txPayer,
+        governance,
+        tmewc.getGovernancePayload(takeIx)
+      );
+      const ix = await tmewc.executeGovernanceVaaIx(
+        { payer: txPayer.publicKey },
+        signedVaa,
+        takeIx
+      );
+      await expectIxFail([ix], [txPayer], "NoGovernanceEmitter");
+    });
+
+    it("cannot register governance emitter as imposter", async () => {
+      const ix = await tmewc.updateGovernanceEmitterIx(
+        {
+          authority: imposter.publicKey,
+        },
+        governanceEmitter
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("hand authority to governance", async () => {
+      const updateIx = await tmewc.updateGovernanceEmitterIx(
+        {
+          authority: authority.publicKey,
+        },
+        governanceEmitter
+      );
+      const changeIx = await tmewc.changeAuthorityIx({
+        authority: authority.publicKey,
+        newAuthority: governanceAuthority,
+      });
+      await expectIxSuccess([updateIx, changeIx], [authority]);
+
+      // Anyone can relay the governance VAA.
+      const takeIx = await tmewc.takeAuthorityIx({
+        pendingAuthority: governanceAuthority,
+      });
+      const signedVaa = await mockGovernanceVaa(
+        txPayer,
+        governance,
+        tmewc.getGovernancePayload(takeIx)
+      );
+      const ix = await tmewc.executeGovernanceVaaIx(
+        { payer: txPayer.publicKey },
+        signedVaa,
+        takeIx
+      );
+      await expectIxSuccess([ix], [txPayer]);
+
+      await tmewc.checkConfig({
+        authority: governanceAuthority,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2500),
+        paused: false,
+        pendingAuthority: null,
+      });
+
+      // The same VAA cannot be executed twice.
+      await expectIxFail([ix], [imposter, txPayer], "already in use");
+    });
+
+    it("cannot execute vaa from another emitter", async () => {
+      const changeIx = await tmewc.changeAuthorityIx({
+        authority: governanceAuthority,
+        newAuthority: imposter.publicKey,
+      });
+      const imposterGovernance = new MockEmitter(
+        randomBytes(32).toString("hex"),
+        2,
+        0
+      );
+      const signedVaa = await mockGovernanceVaa(
+        txPayer,
+        imposterGovernance,
+        tmewc.getGovernancePayload(changeIx)
+      );
+      const ix = await tmewc.executeGovernanceVaaIx(
+        { payer: txPayer.publicKey },
+        signedVaa,
+        changeIx
+      );
+      await expectIxFail([ix], [txPayer], "InvalidGovernanceEmitter");
+    });
+
+    it("cannot execute with other accounts", async () => {
+      const changeIx = await tmewc.changeAuthorityIx({
+        authority: governanceAuthority,
+        newAuthority: authority.publicKey,
+      });
+      const signedVaa = await mockGovernanceVaa(
+        txPayer,
+        governance,
+        tmewc.getGovernancePayload(changeIx)
+      );
+      const imposterIx = await tmewc.changeAuthorityIx({
+        authority: governanceAuthority,
+        newAuthority: imposter.publicKey,
+      });
+      const ix = await tmewc.executeGovernanceVaaIx(
+        { payer: txPayer.publicKey },
+        signedVaa,
+        imposterIx
+      );
+      await expectIxFail([ix], [txPayer], "GovernanceAccountsMismatch");
+    });
+
+    it("hand authority back", async () => {
+      const changeIx = await tmewc.changeAuthorityIx({
+        authority: governanceAuthority,
+        newAuthority: authority.publicKey,
+      });
+      const signedVaa = await mockGovernanceVaa(
+        txPayer,
+        governance,
+        tmewc.getGovernancePayload(changeIx)
+      );
+      const ix = await tmewc.executeGovernanceVaaIx(
+        { payer: txPayer.publicKey },
+        signedVaa,
+        changeIx
+      );
+      await expectIxSuccess([ix], [txPayer]);
+
+      const takeIx = await tmewc.takeAuthorityIx({
+        pendingAuthority: authority.publicKey,
+      });
+      await expectIxSuccess([takeIx], [authority]);
+
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2500),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("roles", () => {
+    const pauser = anchor.web3.Keypair.generate();
+    const unpauser = anchor.web3.Keypair.generate();
+    const roleMinter = anchor.web3.Keypair.generate();
+    const limitAdmin = anchor.web3.Keypair.generate();
+    const metadataAdmin = anchor.web3.Keypair.generate();
+    const freezeAdmin = anchor.web3.Keypair.generate();
+
+    it("cannot grant role without authority", async () => {
+      const ix = await tmewc.grantRoleIx(
+        {
+          authority: imposter.publicKey,
+          member: pauser.publicKey,
+        },
+        { pauser: {} }
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("grant roles", async () => {
+      const ixes = await Promise.all(
+        [
+          { member: pauser, role: { pauser: {} } },
+          { member: unpauser, role: { unpauser: {} } },
+          { member: roleMinter, role: { minter: {} } },
+          { member: limitAdmin, role: { limitAdmin: {} } },
+          { member: metadataAdmin, role: { metadataAdmin: {} } },
+          { member: freezeAdmin, role: { freezeAdmin: {} } },
+        ].map(({ member, role }) =>
+          tmewc.grantRoleIx(
+            {
+              authority: authority.publicKey,
+              member: member.publicKey,
+            },
+            role
+          )
+        )
+      );
+      await expectIxSuccess(ixes, [authority]);
+
+      const roleAssignment = await program.account.roleAssignment.fetch(
+        tmewc.getRoleAssignmentPDA({ pauser: {} }, pauser.publicKey)
+      );
+      expect(roleAssignment.role).to.eql({ pauser: {} });
+      expect(roleAssignment.member).to.eql(pauser.publicKey);
+
+      // Role minters are listed and counted like any other minter.
+      const mintersState = await program.account.minters.fetch(
+        tmewc.getMintersPDA()
+      );
+      expect(mintersState.keys).to.eql([roleMinter.publicKey]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2500),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+
+    it("cannot add minter holding the minter role", async () => {
+      const ix = await tmewc.addMinterIx({
+        authority: authority.publicKey,
+        minter: roleMinter.publicKey,
+      });
+      await expectIxFail([ix], [authority], "MinterAlreadyExists");
+    });
+
+    it("cannot grant minter role without minters", async () => {
+      const ix = await tmewc.grantRoleIx(
+        {
+          authority: authority.publicKey,
+          minters: null,
+          member: imposter.publicKey,
+        },
+        { minter: {} }
+      );
+      await expectIxFail([ix], [authority], "InvalidMinters");
+    });
+
+    it("cannot pause without guardian info or pauser role", async () => {
+      const pauseIx = await tmewc.pauseIx({
+        guardian: pauser.publicKey,
+        guardianInfo: null,
+      });
+      await expectIxFail([pauseIx], [txPayer, pauser], "SignerNotGuardian");
+    });
+
+    it("pause with pauser role", async () => {
+      const pauseIx = await tmewc.pauseIx({
+        guardian: pauser.publicKey,
+        guardianInfo: null,
+        roleAssignment: tmewc.getRoleAssignmentPDA(
+          { pauser: {} },
+          pauser.publicKey
+        ),
+      });
+      await expectIxSuccess([pauseIx], [txPayer, pauser]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2500),
+        paused: true,
+        pendingAuthority: null,
+      });
+    });
+
+    it("cannot unpause with another role", async () => {
+      const unpauseIx = await tmewc.unpauseIx({
+        authority: pauser.publicKey,
+      });
+      await expectIxFail([unpauseIx], [txPayer, pauser], "IsNotAuthority");
+
+      const cannotUnpauseIx = await tmewc.unpauseIx({
+        authority: pauser.publicKey,
+        roleAssignment: tmewc.getRoleAssignmentPDA(
+          { unpauser: {} },
+          pauser.publicKey
+        ),
+      });
+      await expectIxFail(
+        [cannotUnpauseIx],
+        [txPayer, pauser],
+        "AccountNotInitialized"
+      );
+    });
+
+    it("unpause with unpauser role", async () => {
+      const unpauseIx = await tmewc.unpauseIx({
+        authority: unpauser.publicKey,
+        roleAssignment: tmewc.getRoleAssignmentPDA(
+          { unpauser: {} },
+          unpauser.publicKey
+        ),
+      });
+      await expectIxSuccess([unpauseIx], [txPayer, unpauser]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2500),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+
+    it("cannot mint without minter info or minter role", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        recipient.publicKey,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const mintIx = await tmewc.mintIx(
+        {
+          minter: roleMinter.publicKey,
+          minterInfo: null,
+          recipientToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxFail([mintIx], [txPayer, roleMinter], "SignerNotMinter");
+    });
+
+    it("mint with minter role", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        recipient.publicKey,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const mintIx = await tmewc.mintIx(
+        {
+          minter: roleMinter.publicKey,
+          minterInfo: null,
+          roleAssignment: tmewc.getRoleAssignmentPDA(
+            { minter: {} },
+            roleMinter.publicKey
+          ),
+          recipientToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxSuccess([mintIx], [txPayer, roleMinter]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2600),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+
+    it("update limits with limit admin role", async () => {
+      const updateIx = await tmewc.updateAnomalyGuardIx(
+        {
+          authority: limitAdmin.publicKey,
+          roleAssignment: tmewc.getRoleAssignmentPDA(
+            { limitAdmin: {} },
+            limitAdmin.publicKey
+          ),
+        },
+        {
+          maxMintAmount: new anchor.BN(0),
+          mintWindowLimit: new anchor.BN(0),
+          mintWindowDuration: 0,
+        }
+      );
+      await expectIxSuccess([updateIx], [txPayer, limitAdmin]);
+
+      const cannotUpdateIx = await tmewc.updateOptimisticMintingDelayIx(
+        {
+          authority: limitAdmin.publicKey,
+        },
+        0
+      );
+      await expectIxFail(
+        [cannotUpdateIx],
+        [txPayer, limitAdmin],
+        "IsNotAuthority"
+      );
+    });
+
+    it("update metadata with metadata admin role", async () => {
+      const args = {
+        name: "Threshold MEWC",
+        symbol: "tMEWC",
+        uri: "https://example.com/tmewc.json",
+      };
+      const cannotUpdateIx = await tmewc.updateMetadataIx(
+        { authority: limitAdmin.publicKey },
+        args
+      );
+      await expectIxFail(
+        [cannotUpdateIx],
+        [txPayer, limitAdmin],
+        "IsNotAuthority"
+      );
+
+      const updateIx = await tmewc.updateMetadataIx(
+        {
+          authority: metadataAdmin.publicKey,
+          roleAssignment: tmewc.getRoleAssignmentPDA(
+            { metadataAdmin: {} },
+            metadataAdmin.publicKey
+          ),
+        },
+        args
+      );
+      const txSig = await expectIxSuccess([updateIx], [txPayer, metadataAdmin]);
+      const event = await expectEvent(txSig, "MetadataUpdated");
+      expect(event.name).to.equal(args.name);
+
+      const metadata = await Metadata.fromAccountAddress(
+        program.provider.connection,
+        tmewc.getTmewcMetadataPDA()
+      );
+      expect(metadata.data.name.replace(/\0/g, "")).to.equal(args.name);
+      expect(metadata.data.uri.replace(/\0/g, "")).to.equal(args.uri);
+    });
+
+    it("freeze and thaw with freeze admin role", async () => {
+      const connection = program.provider.connection;
+      const tokenAccount = spl.getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        recipient.publicKey,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const cannotFreezeIx = await tmewc.freezeTokenAccountIx({
+        authority: limitAdmin.publicKey,
+        tokenAccount,
+      });
+      await expectIxFail(
+        [cannotFreezeIx],
+        [txPayer, limitAdmin],
+        "IsNotAuthority"
+      );
+
+      const freezeIx = await tmewc.freezeTokenAccountIx({
+        authority: freezeAdmin.publicKey,
+        roleAssignment: tmewc.getRoleAssignmentPDA(
+          { freezeAdmin: {} },
+          freezeAdmin.publicKey
+        ),
+        tokenAccount,
+      });
+      const txSig = await expectIxSuccess([freezeIx], [txPayer, freezeAdmin]);
+      const event = await expectEvent(txSig, "TokenAccountFrozen");
+      expect(event.tokenAccount).to.eql(tokenAccount);
+
+      let tokenState = await spl.getAccount(
+        connection,
+        tokenAccount,
+        undefined,
+        TMEWC_TOKEN_PROGRAM
+      );
+      expect(tokenState.isFrozen).to.be.true;
+
+      // The authority can thaw without holding the role.
+      const thawIx = await tmewc.thawTokenAccountIx({
+        authority: authority.publicKey,
+        tokenAccount,
+      });
+      await expectIxSuccess([thawIx], [txPayer, authority]);
+      tokenState = await spl.getAccount(
+        connection,
+        tokenAccount,
+        undefined,
+        TMEWC_TOKEN_PROGRAM
+      );
+      expect(tokenState.isFrozen).to.be.false;
+
+      const { nextIndex, entries } = await tmewc.getAuditLogData();
+      const lastEntry = entries[(nextIndex + entries.length - 1) % 64];
+      expect(lastEntry.action).to.eql({ thawTokenAccount: {} });
+      expect(lastEntry.target).to.eql(tokenAccount);
+    });
+
+    it("revoke roles", async () => {
+      const ixes = await Promise.all(
+        [
+          { member: pauser, role: { pauser: {} } },
+          { member: unpauser, role: { unpauser: {} } },
+          { member: roleMinter, role: { minter: {} } },
+          { member: limitAdmin, role: { limitAdmin: {} } },
+          { member: metadataAdmin, role: { metadataAdmin: {} } },
+          { member: freezeAdmin, role: { freezeAdmin: {} } },
+        ].map(({ member, role }) =>
+          tmewc.revokeRoleIx(
+            {
+              authority: authority.publicKey,
+              member: member.publicKey,
+            },
+            role
+          )
+        )
+      );
+      await expectIxSuccess(ixes, [authority]);
+
+      const pauseIx = await tmewc.pauseIx({
+        guardian: pauser.publicKey,
+        guardianInfo: null,
+        roleAssignment: tmewc.getRoleAssignmentPDA(
+          { pauser: {} },
+          pauser.publicKey
+        ),
+      });
+      await expectIxFail([pauseIx], [txPayer, pauser], "AccountNotInitialized");
+
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2600),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("renouncing", () => {
+    it("add minter and guardian", async () => {
+      const addMinterIx = await tmewc.addMinterIx({
+        authority: authority.publicKey,
+        minter: anotherMinter.publicKey,
+      });
+      const addGuardianIx = await tmewc.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: anotherGuardian.publicKey,
+      });
+      await expectIxSuccess([addMinterIx, addGuardianIx], [authority]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 1,
+        supply: BigInt(2600),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+
+    it("cannot renounce another minter", async () => {
+      const minterInfo = tmewc.getMinterInfoPDA(anotherMinter.publicKey);
+      const renounceIx = await tmewc.renounceMinterIx({
+        minterInfo,
+        minter: imposter.publicKey,
+      });
+      await expectIxFail([renounceIx], [imposter], "ConstraintSeeds");
+    });
+
+    it("renounce minter", async () => {
+      const renounceIx = await tmewc.renounceMinterIx({
+        minter: anotherMinter.publicKey,
+      });
+      await expectIxSuccess([renounceIx], [txPayer, anotherMinter]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 1,
+        supply: BigInt(2600),
+        paused: false,
+        pendingAuthority: null,
+      });
+      const mustBeNull = await tmewc
+        .checkMinterInfo(anotherMinter.publicKey)
+        .catch((_) => null);
+      assert(mustBeNull === null, "minter info found");
+    });
+
+    it("cannot renounce another guardian", async () => {
+      const guardianInfo = tmewc.getGuardianInfoPDA(anotherGuardian.publicKey);
+      const renounceIx = await tmewc.renounceGuardianIx({
+        guardianInfo,
+        guardian: imposter.publicKey,
+      });
+      await expectIxFail([renounceIx], [imposter], "ConstraintSeeds");
+    });
+
+    it("renounce guardian", async () => {
+      const renounceIx = await tmewc.renounceGuardianIx({
+        guardian: anotherGuardian.publicKey,
+      });
+      await expectIxSuccess([renounceIx], [txPayer, anotherGuardian]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2600),
+        paused: false,
+        pendingAuthority: null,
+      });
+      const mustBeNull = await tmewc
+        .checkGuardianInfo(anotherGuardian.publicKey)
+        .catch((_) => null);
+      assert(mustBeNull === null, "guardian info found");
+    });
+
+    it("grant roles to renounce", async () => {
+      const ixes = await Promise.all(
+        [
+          { member: anotherMinter, role: { minter: {} } },
+          { member: anotherGuardian, role: { pauser: {} } },
+        ].map(({ member, role }) =>
+          tmewc.grantRoleIx(
+            {
+              authority: authority.publicKey,
+              member: member.publicKey,
+            },
+            role
+          )
+        )
+      );
+      await expectIxSuccess(ixes, [authority]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2600),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+
+    it("cannot renounce another member's role", async () => {
+      const renounceIx = await tmewc.renounceRoleIx(
+        {
+          roleAssignment: tmewc.getRoleAssignmentPDA(
+            { pauser: {} },
+            anotherGuardian.publicKey
+          ),
+          member: imposter.publicKey,
+        },
+        { pauser: {} }
+      );
+      await expectIxFail([renounceIx], [imposter], "ConstraintSeeds");
+    });
+
+    it("cannot renounce minter role without minters", async () => {
+      const renounceIx = await tmewc.renounceRoleIx(
+        {
+          minters: null,
+          member: anotherMinter.publicKey,
+        },
+        { minter: {} }
+      );
+      await expectIxFail(
+        [renounceIx],
+        [txPayer, anotherMinter],
+        "InvalidMinters"
+      );
+    });
+
+    it("renounce roles", async () => {
+      const renounceMinterIx = await tmewc.renounceRoleIx(
+        { member: anotherMinter.publicKey },
+        { minter: {} }
+      );
+      const txSig = await expectIxSuccess(
+        [renounceMinterIx],
+        [txPayer, anotherMinter]
+      );
+      const event = await expectEvent(txSig, "RoleRenounced");
+      expect(event.role).to.eql({ minter: {} });
+      expect(event.member).to.eql(anotherMinter.publicKey);
+
+      const renouncePauserIx = await tmewc.renounceRoleIx(
+        { member: anotherGuardian.publicKey },
+        { pauser: {} }
+      );
+      await expectIxSuccess([renouncePauserIx], [txPayer, anotherGuardian]);
+
+      // Renouncing the minter role delists the member, like revoking it.
+      const mintersState = await program.account.minters.fetch(
+        tmewc.getMintersPDA()
+      );
+      expect(mintersState.keys).to.be.empty;
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2600),
+        paused: false,
+        pendingAuthority: null,
+      });
+
+      const roleAssignment = await program.provider.connection.getAccountInfo(
+        tmewc.getRoleAssignmentPDA({ minter: {} }, anotherMinter.publicKey)
+      );
+      expect(roleAssignment).to.be.null;
+
+      // A renounced role cannot be revoked.
+      const revokeIx = await tmewc.revokeRoleIx(
+        {
+          authority: authority.publicKey,
+          member: anotherMinter.publicKey,
+        },
+        { minter: {} }
+      );
+      await expectIxFail([revokeIx], [authority], "AccountNotInitialized");
+    });
+  });
+
+  describe("proof of reserve", () => {
+    const attester = anchor.web3.Keypair.generate();
+    const reserveAttestation = tmewc.getReserveAttestationPDA();
+
+    async function mintWithAttestationIx(amount: number) {
+      return tmewc.mintIx(
+        {
+          minter: minter.publicKey,
+          reserveAttestation,
+          recipientToken: spl.getAssociatedTokenAddressSync(
+            tmewc.getMintPDA(),
+            recipient.publicKey,
+            false,
+            TMEWC_TOKEN_PROGRAM
+          ),
+        },
+        new anchor.BN(amount)
+      );
+    }
+
+    it("cannot update reserve attester without authority", async () => {
+      const ix = await tmewc.updateReserveAttesterIx(
+        {
+          authority: imposter.publicKey,
+        },
+        imposter.publicKey,
+        3600
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("enable proof of reserve", async () => {
+      const updateIx = await tmewc.updateReserveAttesterIx(
+        {
+          authority: authority.publicKey,
+        },
+        attester.publicKey,
+        3600
+      );
+      const addMinterIx = await tmewc.addMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([updateIx, addMinterIx], [authority]);
+
+      const configState = await tmewc.getConfigData();
+      expect(configState.reserveAttester).to.eql(attester.publicKey);
+      expect(configState.maxReserveStaleness).to.equal(3600);
+    });
+
+    it("cannot mint without reserve attestation", async () => {
+      const mintIx = await tmewc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken: spl.getAssociatedTokenAddressSync(
+            tmewc.getMintPDA(),
+            recipient.publicKey,
+            false,
+            TMEWC_TOKEN_PROGRAM
+          ),
+        },
+        new anchor.BN(100)
+      );
+      await expectIxFail(
+        [mintIx],
+        [txPayer, minter],
+        "ReserveAttestationRequired"
+      );
+    });
+
+    it("cannot mint before reserve is attested", async () => {
+      const mintIx = await mintWithAttestationIx(100);
+      await expectIxFail(
+        [mintIx],
+        [txPayer, minter],
+        "ReserveAttestationStale"
+      );
+    });
+
+    it("cannot attest reserve as imposter", async () => {
+      const ix = await tmewc.attestReserveIx(
+        {
+          attester: imposter.publicKey,
+        },
+        BigInt(1000000)
+      );
+      await expectIxFail([ix], [imposter], "IsNotReserveAttester");
+    });
+
+    it("mint up to attested reserve", async () => {
+      const attestIx = await tmewc.attestReserveIx(
+        {
+          attester: attester.publicKey,
+        },
+        BigInt(2700)
+      );
+      await expectIxSuccess([attestIx], [txPayer, attester]);
+
+      const mintIx = await mintWithAttestationIx(100);
+      await expectIxSuccess([mintIx], [txPayer, minter]);
+      await tmewc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2700),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+
+    it("cannot mint above attested reserve", async () => {
+      const mintIx = await mintWithAttestationIx(1);
+      await expectIxFail([mintIx], [txPayer, minter], "ReserveExceeded");
+    });
+
+    it("cannot attest reserve observed in the future", async () => {
+      const ix = await tmewc.attestReserveIx(
+        {
+          attester: attester.publicKey,
+        },
+        BigInt(1000000),
+        Math.floor(Date.now() / 1000) + 3600
+      );
+      await expectIxFail(
+        [ix],
+        [txPayer, attester],
+        "InvalidReserveObservation"
+      );
+    });
+
+    it("cannot attest an older reserve observation", async () => {
+      const { attestedAt } = await tmewc.getReserveAttestationData();
+      const ix = await tmewc.attestReserveIx(
+        {
+          attester: attester.publicKey,
+        },
+        BigInt(1000000),
+        attestedAt.toNumber()
+      );
+      await expectIxFail(
+        [ix],
+        [txPayer, attester],
+        "InvalidReserveObservation"
+      );
+    });
+
+    it("disable proof of reserve", async () => {
+      const updateIx = await tmewc.updateReserveAttesterIx(
+        {
+          authority: authority.publicKey,
+        },
+        null,
+        0
+      );
+      const removeMinterIx = await tmewc.removeMinterIx({
+        authority: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([updateIx, removeMinterIx], [authority]);
+
+      const configState = await tmewc.getConfigData();
+      expect(configState.reserveAttester).is.null;
+    });
+  });
 });
@@ -1,19 +1,41 @@
-import { MockEthereumTokenBridge } from "@certusone/wormhole-sdk/lib/cjs/mock";
+import { parseVaa } from "@certusone/wormhole-sdk";
+import {
+  MockEmitter,
+  MockEthereumTokenBridge,
+} from "@certusone/wormhole-sdk/lib/cjs/mock";
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMint,
+} from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
+import { randomBytes } from "crypto";
+import { ethers } from "ethers";
 import { WormholeGateway } from "../target/types/wormhole_gateway";
 import {
   ETHEREUM_TOKEN_BRIDGE_ADDRESS,
+  GATEWAY_ETH_AUTHORITY_DOMAIN,
+  LEGACY_GATEWAY_ADDRESS,
+  LEGACY_GATEWAY_CHAIN,
+  MISMATCHED_TOKEN_PROGRAM,
+  TMEWC_TOKEN_PROGRAM,
+  UNLISTED_GATEWAY_ADDRESS,
+  UNLISTED_GATEWAY_CHAIN,
   WORMHOLE_GATEWAY_PROGRAM_ID,
   WRAPPED_TMEWC_MINT,
+  ethAddressFromPrivateKey,
+  ethAuthorizationDigest,
+  ethSignatureIx,
   ethereumGatewaySendTmewc,
+  expectEvent,
   expectIxFail,
   expectIxSuccess,
   generatePayer,
   getOrCreateAta,
+  mockGovernanceVaa,
   preloadWrappedTmewc,
   transferLamports,
 } from "./helpers";
@@ -30,15 +52,19 @@ async function setup(
   const gatewayWrappedTmewcToken = wormholeGateway.getWrappedTmewcTokenPDA();
   const tokenBridgeSender = wormholeGateway.getTokenBridgeSenderPDA();
 
-  await program.methods
+  return program.methods
     .initialize(new anchor.BN(mintingLimit.toString()))
     .accounts({
       authority: authority.publicKey,
       custodian,
+      auditLog: wormholeGateway.getAuditLogPDA(),
+      gatewayRegistry: wormholeGateway.getGatewayRegistryPDA(),
       tmewcMint,
       wrappedTmewcMint: WRAPPED_TMEWC_MINT,
       wrappedTmewcToken: gatewayWrappedTmewcToken,
       tokenBridgeSender,
+      eventAuthority: wormholeGateway.getEventAuthorityPDA(),
+      program: program.programId,
     })
     .rpc();
 }
@@ -91,8 +117,20 @@ describe("wormhole-gateway", () => {
       // Max amount of TMEWC that can be minted.
       const mintingLimit = BigInt(10000);
 
+      // The suite runs against whichever token program owns the tMEWC mint.
+      const mintInfo = await connection.getAccountInfo(tmewcMint);
+      expect(mintInfo.owner).to.eql(TMEWC_TOKEN_PROGRAM);
+
+      // The tMEWC tests leave some supply behind.
+      const { supply } = await getMint(
+        connection,
+        tmewcMint,
+        undefined,
+        TMEWC_TOKEN_PROGRAM
+      );
+
       // Initialize the program.
-      await setup(program, authority, mintingLimit);
+      const txSig = await setup(program, authority, mintingLimit);
       await wormholeGateway.checkCustodian({
         authority: authority.publicKey,
         mintingLimit,
@@ -102,16 +140,25 @@ describe("wormhole-gateway", () => {
         authority: authority.publicKey,
         numMinters: 0,
         numGuardians: 0,
-        supply: BigInt(2000),
+        supply,
         paused: false,
         pendingAuthority: null,
       });
 
+      const initialized = await expectEvent(txSig, "Initialized");
+      expect(initialized.authority).to.eql(authority.publicKey);
+      expect(initialized.tmewcMint).to.eql(tmewcMint);
+      expect(initialized.wrappedTmewcMint).to.eql(WRAPPED_TMEWC_MINT);
+      expect(initialized.mintingLimit.toString()).to.equal(
+        mintingLimit.toString()
+      );
+
       // Also set up common token account.
       await getOrCreateAta(
         authority,
         tmewc.getMintPDA(),
-        commonTokenOwner.publicKey
+        commonTokenOwner.publicKey,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Give the impostor some lamports.
@@ -147,12 +194,16 @@ describe("wormhole-gateway", () => {
         authority: authority.publicKey,
         newAuthority: newAuthority.publicKey,
       });
-      await expectIxSuccess([changeIx], [authority]);
+      const txSig = await expectIxSuccess([changeIx], [authority]);
       await wormholeGateway.checkCustodian({
         authority: authority.publicKey,
         mintingLimit: BigInt(10000),
         pendingAuthority: newAuthority.publicKey,
       });
+
+      const staged = await expectEvent(txSig, "AuthorityChangeStaged");
+      expect(staged.authority).to.eql(authority.publicKey);
+      expect(staged.pendingAuthority).to.eql(newAuthority.publicKey);
     });
 
     it("take as new authority", async () => {
@@ -162,12 +213,16 @@ describe("wormhole-gateway", () => {
       const takeIx = await wormholeGateway.takeAuthorityIx({
         pendingAuthority: newAuthority.publicKey,
       });
-      await expectIxSuccess([takeIx], [newAuthority]);
+      const txSig = await expectIxSuccess([takeIx], [newAuthority]);
       await wormholeGateway.checkCustodian({
         authority: newAuthority.publicKey,
         mintingLimit: BigInt(10000),
         pendingAuthority: null,
       });
+
+      const changed = await expectEvent(txSig, "AuthorityChanged");
+      expect(changed.oldAuthority).to.eql(authority.publicKey);
+      expect(changed.newAuthority).to.eql(newAuthority.publicKey);
     });
 
     it("change pending authority back to original authority", async () => {
@@ -226,6 +281,28 @@ describe("wormhole-gateway", () => {
         pendingAuthority: null,
       });
     });
+
+    it("change and cancel authority change", async () => {
+      const changeIx = await wormholeGateway.changeAuthorityIx({
+        authority: authority.publicKey,
+        newAuthority: newAuthority.publicKey,
+      });
+      await expectIxSuccess([changeIx], [authority]);
+
+      const cancelIx = await wormholeGateway.cancelAuthorityChangeIx({
+        authority: authority.publicKey,
+      });
+      const txSig = await expectIxSuccess([cancelIx], [authority]);
+      await wormholeGateway.checkCustodian({
+        authority: authority.publicKey,
+        mintingLimit: BigInt(10000),
+        pendingAuthority: null,
+      });
+
+      const cancelled = await expectEvent(txSig, "AuthorityChangeCancelled");
+      expect(cancelled.authority).to.eql(authority.publicKey);
+      expect(cancelled.pendingAuthority).to.eql(newAuthority.publicKey);
+    });
   });
 
   describe("minting limit", () => {
@@ -257,10 +334,268 @@ describe("wormhole-gateway", () => {
       );
       await expectIxFail([failingIx], [imposter], "IsNotAuthority");
     });
+
+    it("audit log records minting limit update", async () => {
+      const { nextIndex, entries } = await wormholeGateway.getAuditLogData();
+      expect(entries).has.length(nextIndex);
+
+      const [initialized] = entries;
+      expect(initialized.action).to.eql({ initialize: {} });
+      expect(initialized.actor).to.eql(authority.publicKey);
+
+      const lastEntry = entries[entries.length - 1];
+      expect(lastEntry.action).to.eql({ updateMintingLimit: {} });
+      expect(lastEntry.actor).to.eql(authority.publicKey);
+    });
+  });
+
+  describe("migration", () => {
+    it("cannot migrate custodian (not authority)", async () => {
+      const ix = await wormholeGateway.migrateCustodianIx({
+        authority: imposter.publicKey,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("migrate custodian", async () => {
+      const custodianBefore = await wormholeGateway.getCustodianData();
+
+      // An up-to-date custodian is left as is.
+      const ix = await wormholeGateway.migrateCustodianIx({
+        authority: authority.publicKey,
+      });
+      await expectIxSuccess([ix], [authority]);
+      expect(await wormholeGateway.getCustodianData()).to.eql(custodianBefore);
+
+      const { entries } = await wormholeGateway.getAuditLogData();
+      const lastEntry = entries[entries.length - 1];
+      expect(lastEntry.action).to.eql({ migrateCustodian: {} });
+      expect(lastEntry.actor).to.eql(authority.publicKey);
+      expect(lastEntry.target).to.eql(custodian);
+    });
+  });
+
+  describe("eth authority", () => {
+    const ethPrivateKey = randomBytes(32);
+    const ethAuthority = wormholeGateway.getEthAuthorityPDA();
+
+    it("hand authority to eth authority", async () => {
+      const setIx = await wormholeGateway.setEthAuthorityIx(
+        {
+          authority: authority.publicKey,
+        },
+        ethAddressFromPrivateKey(ethPrivateKey)
+      );
+      const changeIx = await wormholeGateway.changeAuthorityIx({
+        authority: authority.publicKey,
+        newAuthority: ethAuthority,
+      });
+      await expectIxSuccess([setIx, changeIx], [authority]);
+
+      const takeIx = await wormholeGateway.takeAuthorityIx({
+        pendingAuthority: ethAuthority,
+      });
+      const ixes = await wormholeGateway.executeAsEthAuthorityIxs(
+        {},
+        takeIx,
+        ethPrivateKey
+      );
+      await expectIxSuccess(ixes, [txPayer]);
+      await wormholeGateway.checkCustodian({
+        authority: ethAuthority,
+        mintingLimit: BigInt(20000),
+        pendingAuthority: null,
+      });
+    });
+
+    it("update minting limit as eth authority", async () => {
+      const updateIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: ethAuthority,
+        },
+        BigInt(20000)
+      );
+      const ixes = await wormholeGateway.executeAsEthAuthorityIxs(
+        {},
+        updateIx,
+        ethPrivateKey
+      );
+      await expectIxSuccess(ixes, [txPayer]);
+
+      // Another key's signature is not accepted.
+      const failingIxes = await wormholeGateway.executeAsEthAuthorityIxs(
+        {},
+        updateIx,
+        randomBytes(32)
+      );
+      await expectIxFail(failingIxes, [txPayer], "InvalidEthAuthorization");
+    });
+
+    it("hand authority back with an Ethereum wallet signature", async () => {
+      const changeIx = await wormholeGateway.changeAuthorityIx({
+        authority: ethAuthority,
+        newAuthority: authority.publicKey,
+      });
+      const [, executeIx] = await wormholeGateway.executeAsEthAuthorityIxs(
+        {},
+        changeIx,
+        ethPrivateKey
+      );
+
+      // Sign the digest like an Ethereum wallet, with `personal_sign`.
+      const { ethAuthorityNonce } = await wormholeGateway.getCustodianData();
+      const digest = ethAuthorizationDigest(
+        GATEWAY_ETH_AUTHORITY_DOMAIN,
+        custodian,
+        ethAuthorityNonce,
+        changeIx
+      );
+      const signature = await new ethers.Wallet(ethPrivateKey).signMessage(
+        digest
+      );
+      await expectIxSuccess(
+        [ethSignatureIx(digest, signature), executeIx],
+        [txPayer]
+      );
+
+      const takeIx = await wormholeGateway.takeAuthorityIx({
+        pendingAuthority: authority.publicKey,
+      });
+      const unsetIx = await wormholeGateway.setEthAuthorityIx(
+        {
+          authority: authority.publicKey,
+        },
+        null
+      );
+      await expectIxSuccess([takeIx, unsetIx], [authority]);
+      await wormholeGateway.checkCustodian({
+        authority: authority.publicKey,
+        mintingLimit: BigInt(20000),
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("governance", () => {
+    const governanceEmitter = randomBytes(32);
+    const governance = new MockEmitter(governanceEmitter.toString("hex"), 2, 0);
+    const governanceAuthority = wormholeGateway.getGovernanceAuthorityPDA();
+
+    it("hand authority to governance", async () => {
+      const updateIx = await wormholeGateway.updateGovernanceEmitterIx(
+        {
+          authority: authority.publicKey,
+        },
+        governanceEmitter
+      );
+      const changeIx = await wormholeGateway.changeAuthorityIx({
+        authority: authority.publicKey,
+        newAuthority: governanceAuthority,
+      });
+      await expectIxSuccess([updateIx, changeIx], [authority]);
+
+      const takeIx = await wormholeGateway.takeAuthorityIx({
+        pendingAuthority: governanceAuthority,
+      });
+      const signedVaa = await mockGovernanceVaa(
+        txPayer,
+        governance,
+        wormholeGateway.getGovernancePayload(takeIx)
+      );
+      const ix = await wormholeGateway.executeGovernanceVaaIx(
+        { payer: txPayer.publicKey },
+        signedVaa,
+        takeIx
+      );
+      await expectIxSuccess([ix], [txPayer]);
+      await wormholeGateway.checkCustodian({
+        authority: governanceAuthority,
+        mintingLimit: BigInt(20000),
+        pendingAuthority: null,
+      });
+    });
+
+    it("update minting limit through governance", async () => {
+      const updateIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: governanceAuthority,
+        },
+        BigInt(20000)
+      );
+      const signedVaa = await mockGovernanceVaa(
+        txPayer,
+        governance,
+        wormholeGateway.getGovernancePayload(updateIx)
+      );
+      const ix = await wormholeGateway.executeGovernanceVaaIx(
+        { payer: txPayer.publicKey },
+        signedVaa,
+        updateIx
+      );
+      await expectIxSuccess([ix], [txPayer]);
+
+      // The same VAA cannot be executed twice.
+      await expectIxFail([ix], [imposter, txPayer], "already in use");
+    });
+
+    it("cannot execute vaa from another emitter", async () => {
+      const updateIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: governanceAuthority,
+        },
+        BigInt(0)
+      );
+      const imposterGovernance = new MockEmitter(
+        randomBytes(32).toString("hex"),
+        2,
+        0
+      );
+      const signedVaa = await mockGovernanceVaa(
+        txPayer,
+        imposterGovernance,
+        wormholeGateway.getGovernancePayload(updateIx)
+      );
+      const ix = await wormholeGateway.executeGovernanceVaaIx(
+        { payer: txPayer.publicKey },
+        signedVaa,
+        updateIx
+      );
+      await expectIxFail([ix], [txPayer], "InvalidGovernanceEmitter");
+    });
+
+    it("hand authority back", async () => {
+      const changeIx = await wormholeGateway.changeAuthorityIx({
+        authority: governanceAuthority,
+        newAuthority: authority.publicKey,
+      });
+      const signedVaa = await mockGovernanceVaa(
+        txPayer,
+        governance,
+        wormholeGateway.getGovernancePayload(changeIx)
+      );
+      const ix = await wormholeGateway.executeGovernanceVaaIx(
+        { payer: txPayer.publicKey },
+        signedVaa,
+        changeIx
+      );
+      await expectIxSuccess([ix], [txPayer]);
+
+      const takeIx = await wormholeGateway.takeAuthorityIx({
+        pendingAuthority: authority.publicKey,
+      });
+      await expectIxSuccess([takeIx], [authority]);
+      await wormholeGateway.checkCustodian({
+        authority: authority.publicKey,
+        mintingLimit: BigInt(20000),
+        pendingAuthority: null,
+      });
+    });
   });
 
   describe("gateway address", () => {
     const chain = 2;
+    const otherChain = 4;
+    const otherAddress = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
 
     it("gateway does not exist", async () => {
       // demonstrate gateway address does not exist
@@ -268,6 +603,7 @@ describe("wormhole-gateway", () => {
         wormholeGateway.getGatewayInfoPDA(chain)
       );
       expect(gatewayInfo).is.null;
+      expect(await wormholeGateway.getGateways()).is.empty;
     });
 
     it("set initial gateway address", async () => {
@@ -294,6 +630,11 @@ describe("wormhole-gateway", () => {
       );
       await expectIxSuccess([secondIx], [authority]);
       await wormholeGateway.checkGateway(chain, goodAddress);
+
+      // The registry lists the gateway once.
+      expect(await wormholeGateway.getGateways()).to.eql([
+        { chain, address: goodAddress, disabled: false },
+      ]);
     });
 
     it("cannot update gateway address (not authority)", async () => {
@@ -307,83 +648,374 @@ describe("wormhole-gateway", () => {
       );
       await expectIxFail([failingIx], [imposter], "IsNotAuthority");
     });
-  });
 
-  describe("deposit wrapped tmewc", () => {
-    it("cannot deposit wrapped tmewc (custodian not a minter)", async () => {
-      // Set up new wallet
-      const payer = await generatePayer(authority);
+    it("disable gateway", async () => {
+      const updateIx = await wormholeGateway.updateGatewayAddress(
+        {
+          authority: authority.publicKey,
+        },
+        { chain: otherChain, address: otherAddress }
+      );
+      const ix = await wormholeGateway.disableGatewayIx(
+        {
+          authority: authority.publicKey,
+        },
+        otherChain
+      );
+      await expectIxSuccess([updateIx, ix], [authority]);
+      await wormholeGateway.checkGateway(otherChain, otherAddress, true);
 
-      // Check wrapped tMEWC mint.
-      const recipientWrappedToken = await preloadWrappedTmewc(
-        payer,
-        ethereumTokenBridge,
-        BigInt("100000000000"),
-        payer.publicKey
+      const gateways = await wormholeGateway.getGateways();
+      expect(gateways.map((gateway) => gateway.chain)).to.eql([
+        chain,
+        otherChain,
+      ]);
+      expect(gateways[1]).to.eql({
+        chain: otherChain,
+        address: otherAddress,
+        disabled: true,
+      });
+    });
+
+    it("get gateways by page", async () => {
+      expect(await wormholeGateway.getGatewaysPage(1, 1)).to.eql([
+        { chain: otherChain, address: otherAddress, disabled: true },
+      ]);
+      expect(await wormholeGateway.getGatewaysPage(2, 1)).is.empty;
+
+      // Pages are capped to fit in the return data.
+      const gateways = await wormholeGateway.getGatewaysPage(0, 1000);
+      expect(gateways.map((gateway) => gateway.chain)).to.eql([
+        chain,
+        otherChain,
+      ]);
+    });
+
+    it("cannot disable gateway (already disabled)", async () => {
+      const ix = await wormholeGateway.disableGatewayIx(
+        {
+          authority: authority.publicKey,
+        },
+        otherChain
       );
+      await expectIxFail([ix], [authority], "GatewayDisabled");
+    });
 
-      const recipientToken = await getOrCreateAta(
-        payer,
-        tmewcMint,
-        payer.publicKey
+    it("cannot send tmewc to gateway (gateway disabled)", async () => {
+      const sender = commonTokenOwner.publicKey;
+      const ix = await wormholeGateway.sendTmewcGatewayIx(
+        {
+          senderToken: getAssociatedTokenAddressSync(
+            tmewc.getMintPDA(),
+            sender,
+            false,
+            TMEWC_TOKEN_PROGRAM
+          ),
+          sender,
+        },
+        {
+          amount: new anchor.BN(100),
+          recipientChain: otherChain,
+          recipient: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
+          nonce: 420,
+        }
       );
+      await expectIxFail([ix], [commonTokenOwner], "GatewayDisabled");
+    });
 
-      const depositAmount = BigInt(500);
+    it("update disabled gateway address", async () => {
+      // Updating the address does not enable the gateway again.
+      const ix = await wormholeGateway.updateGatewayAddress(
+        {
+          authority: authority.publicKey,
+        },
+        { chain: otherChain, address: otherAddress }
+      );
+      await expectIxSuccess([ix], [authority]);
+      await wormholeGateway.checkGateway(otherChain, otherAddress, true);
 
-      // Attempt to deposit before the custodian is a minter.
-      const ix = await wormholeGateway.depositWormholeTmewcIx(
+      const gateways = await wormholeGateway.getGateways();
+      expect(gateways[1].disabled).is.true;
+    });
+
+    it("cannot enable gateway (not authority)", async () => {
+      const ix = await wormholeGateway.enableGatewayIx(
         {
-          recipientWrappedToken,
-          recipientToken,
-          recipient: payer.publicKey,
+          authority: imposter.publicKey,
         },
-        depositAmount
+        otherChain
       );
-      await expectIxFail([ix], [payer], "AccountNotInitialized");
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
     });
 
-    it("deposit wrapped tokens", async () => {
-      // Set up new wallet
-      const payer = await generatePayer(authority);
+    it("enable gateway", async () => {
+      const ix = await wormholeGateway.enableGatewayIx(
+        {
+          authority: authority.publicKey,
+        },
+        otherChain
+      );
+      await expectIxSuccess([ix], [authority]);
+      await wormholeGateway.checkGateway(otherChain, otherAddress);
+    });
 
-      // Check wrapped tMEWC mint.
-      const recipientWrappedToken = await preloadWrappedTmewc(
-        payer,
-        ethereumTokenBridge,
-        BigInt("100000000000"),
-        payer.publicKey
+    it("cannot enable gateway (not disabled)", async () => {
+      const ix = await wormholeGateway.enableGatewayIx(
+        {
+          authority: authority.publicKey,
+        },
+        otherChain
       );
+      await expectIxFail([ix], [authority], "GatewayNotDisabled");
+    });
 
-      const recipientToken = await getOrCreateAta(
-        payer,
-        tmewcMint,
-        payer.publicKey
+    it("cannot remove gateway (not authority)", async () => {
+      const ix = await wormholeGateway.removeGatewayIx(
+        {
+          authority: imposter.publicKey,
+        },
+        otherChain
       );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
 
-      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+    it("remove gateway", async () => {
+      const ix = await wormholeGateway.removeGatewayIx(
+        {
+          authority: authority.publicKey,
+        },
+        otherChain
+      );
+      await expectIxSuccess([ix], [authority]);
 
-      const depositAmount = BigInt(500);
+      const gatewayInfo = await connection.getAccountInfo(
+        wormholeGateway.getGatewayInfoPDA(otherChain)
+      );
+      expect(gatewayInfo).is.null;
 
-      const ix = await wormholeGateway.depositWormholeTmewcIx(
+      const gateways = await wormholeGateway.getGateways();
+      expect(gateways.map((gateway) => gateway.chain)).to.eql([chain]);
+    });
+
+    it("cannot migrate gateway info (not authority)", async () => {
+      const ix = await wormholeGateway.migrateGatewayInfoIx(
         {
-          recipientWrappedToken,
-          recipientToken,
-          recipient: payer.publicKey,
+          authority: imposter.publicKey,
         },
-        depositAmount
+        LEGACY_GATEWAY_CHAIN
       );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
 
-      // Add custodian as minter.
-      const addMinterIx = await tmewc.addMinterIx({
-        authority: authority.publicKey,
-        minter: custodian,
-      });
-      await expectIxSuccess([addMinterIx], [authority]);
-      await tmewc.checkConfig({
+    it("migrate gateway info", async () => {
+      const gatewayInfo = wormholeGateway.getGatewayInfoPDA(
+        LEGACY_GATEWAY_CHAIN
+      );
+
+      // The gateway info predates `disabled`, so it cannot be used as is.
+      const { data: legacyData } = await connection.getAccountInfo(gatewayInfo);
+      expect(legacyData).has.length(41);
+      const cannotDisableIx = await wormholeGateway.disableGatewayIx(
+        {
+          authority: authority.publicKey,
+        },
+        LEGACY_GATEWAY_CHAIN
+      );
+      await expectIxFail(
+        [cannotDisableIx],
+        [authority],
+        "AccountDidNotDeserialize"
+      );
+
+      const ix = await wormholeGateway.migrateGatewayInfoIx(
+        {
+          authority: authority.publicKey,
+        },
+        LEGACY_GATEWAY_CHAIN
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const { data } = await connection.getAccountInfo(gatewayInfo);
+      expect(data).has.length(78);
+      await wormholeGateway.checkGateway(
+        LEGACY_GATEWAY_CHAIN,
+        LEGACY_GATEWAY_ADDRESS
+      );
+      const gatewayInfoState = await wormholeGateway.getGatewayInfo(
+        LEGACY_GATEWAY_CHAIN
+      );
+      const { outboundLimit } = gatewayInfoState;
+      expect(outboundLimit.maxTransferAmount.isZero()).to.be.true;
+      expect(outboundLimit.windowLimit.isZero()).to.be.true;
+
+      const { entries } = await wormholeGateway.getAuditLogData();
+      const lastEntry = entries[entries.length - 1];
+      expect(lastEntry.action).to.eql({ migrateGatewayInfo: {} });
+      expect(lastEntry.target).to.eql(gatewayInfo);
+
+      // A migrated gateway info is left as is.
+      const againIx = await wormholeGateway.migrateGatewayInfoIx(
+        {
+          authority: authority.publicKey,
+        },
+        LEGACY_GATEWAY_CHAIN
+      );
+      await expectIxSuccess([againIx], [txPayer, authority]);
+      const gatewayInfoAgain = await wormholeGateway.getGatewayInfo(
+        LEGACY_GATEWAY_CHAIN
+      );
+      expect(gatewayInfoAgain).to.eql(gatewayInfoState);
+    });
+
+    it("cannot list gateways (mismatched gateway infos)", async () => {
+      const ix = await wormholeGateway.migrateCustodianIx(
+        {
+          authority: authority.publicKey,
+        },
+        [UNLISTED_GATEWAY_CHAIN]
+      );
+      ix.keys[ix.keys.length - 1].pubkey =
+        wormholeGateway.getGatewayInfoPDA(LEGACY_GATEWAY_CHAIN);
+      await expectIxFail([ix], [authority], "InvalidGatewayInfo");
+
+      // Every chain needs its gateway info.
+      ix.keys.pop();
+      await expectIxFail([ix], [authority], "InvalidGatewayInfo");
+    });
+
+    it("list gateways registered before the registry", async () => {
+      // Migrated gateway infos are listed with their status.
+      const disableIx = await wormholeGateway.disableGatewayIx(
+        {
+          authority: authority.publicKey,
+        },
+        LEGACY_GATEWAY_CHAIN
+      );
+      await expectIxSuccess([disableIx], [authority]);
+      expect(await wormholeGateway.getGateways()).has.length(1);
+
+      const ix = await wormholeGateway.migrateCustodianIx(
+        {
+          authority: authority.publicKey,
+        },
+        [LEGACY_GATEWAY_CHAIN, UNLISTED_GATEWAY_CHAIN]
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      // Gateway infos that predate `disabled` are listed as enabled.
+      const { data } = await connection.getAccountInfo(
+        wormholeGateway.getGatewayInfoPDA(UNLISTED_GATEWAY_CHAIN)
+      );
+      expect(data).has.length(41);
+      const gateways = await wormholeGateway.getGateways();
+      expect(gateways.slice(1)).to.eql([
+        {
+          chain: LEGACY_GATEWAY_CHAIN,
+          address: LEGACY_GATEWAY_ADDRESS,
+          disabled: true,
+        },
+        {
+          chain: UNLISTED_GATEWAY_CHAIN,
+          address: UNLISTED_GATEWAY_ADDRESS,
+          disabled: false,
+        },
+      ]);
+
+      // Listing them again changes nothing.
+      const againIx = await wormholeGateway.migrateCustodianIx(
+        {
+          authority: authority.publicKey,
+        },
+        [UNLISTED_GATEWAY_CHAIN]
+      );
+      await expectIxSuccess([againIx], [txPayer, authority]);
+      expect(await wormholeGateway.getGateways()).to.eql(gateways);
+    });
+  });
+
+  describe("deposit wrapped tmewc", () => {
+    it("cannot deposit wrapped tmewc (custodian not a minter)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Check wrapped tMEWC mint.
+      const recipientWrappedToken = await preloadWrappedTmewc(
+        payer,
+        ethereumTokenBridge,
+        BigInt("100000000000"),
+        payer.publicKey
+      );
+
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tmewcMint,
+        payer.publicKey,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      const depositAmount = BigInt(500);
+
+      // Attempt to deposit before the custodian is a minter.
+      const ix = await wormholeGateway.depositWormholeTmewcIx(
+        {
+          recipientWrappedToken,
+          recipientToken,
+          recipient: payer.publicKey,
+        },
+        depositAmount
+      );
+      await expectIxFail([ix], [payer], "AccountNotInitialized");
+    });
+
+    it("deposit wrapped tokens", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Check wrapped tMEWC mint.
+      const recipientWrappedToken = await preloadWrappedTmewc(
+        payer,
+        ethereumTokenBridge,
+        BigInt("100000000000"),
+        payer.publicKey
+      );
+
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tmewcMint,
+        payer.publicKey,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      const depositAmount = BigInt(500);
+
+      const ix = await wormholeGateway.depositWormholeTmewcIx(
+        {
+          recipientWrappedToken,
+          recipientToken,
+          recipient: payer.publicKey,
+        },
+        depositAmount
+      );
+
+      // Add custodian as minter.
+      const { supply: supplyBefore } = await getMint(
+        connection,
+        tmewcMint,
+        undefined,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const addMinterIx = await tmewc.addMinterIx({
+        authority: authority.publicKey,
+        minter: custodian,
+      });
+      await expectIxSuccess([addMinterIx], [authority]);
+      await tmewc.checkConfig({
         authority: authority.publicKey,
         numMinters: 1,
         numGuardians: 0,
-        supply: BigInt(2000),
+        supply: supplyBefore,
         paused: false,
         pendingAuthority: null,
       });
@@ -391,23 +1023,23 @@ describe("wormhole-gateway", () => {
       // Check token account balances before deposit.
       const [wrappedBefore, tmewcBefore, gatewayBefore] = await Promise.all([
         getAccount(connection, recipientWrappedToken),
-        getAccount(connection, recipientToken),
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
         getAccount(connection, gatewayWrappedTmewcToken),
       ]);
 
-      await expectIxSuccess([ix], [payer]);
+      const txSig = await expectIxSuccess([ix], [payer]);
       await tmewc.checkConfig({
         authority: authority.publicKey,
         numMinters: 1,
         numGuardians: 0,
-        supply: BigInt(2500),
+        supply: supplyBefore + depositAmount,
         paused: false,
         pendingAuthority: null,
       });
 
       const [wrappedAfter, tmewcAfter, gatewayAfter] = await Promise.all([
         getAccount(connection, recipientWrappedToken),
-        getAccount(connection, recipientToken),
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
         getAccount(connection, gatewayWrappedTmewcToken),
       ]);
 
@@ -423,9 +1055,19 @@ describe("wormhole-gateway", () => {
       expect(gatewayAfter.amount).to.equal(
         gatewayBefore.amount + depositAmount
       );
+
+      // The deposit is visible from both programs' events.
+      const deposited = await expectEvent(txSig, "WormholeTmewcDeposited");
+      expect(deposited.depositor).to.eql(payer.publicKey);
+      expect(deposited.amount.toString()).to.equal(depositAmount.toString());
+
+      const minted = await expectEvent(txSig, "Minted");
+      expect(minted.minter).to.eql(custodian);
+      expect(minted.recipient).to.eql(payer.publicKey);
+      expect(minted.amount.toString()).to.equal(depositAmount.toString());
     });
 
-    it("cannot deposit wrapped tmewc (minting limit exceeded)", async () => {
+    it("cannot deposit wrapped tmewc (mismatched token program)", async () => {
       // Set up new wallet
       const payer = await generatePayer(authority);
 
@@ -440,9 +1082,42 @@ describe("wormhole-gateway", () => {
       const recipientToken = await getOrCreateAta(
         payer,
         tmewcMint,
+        payer.publicKey,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      // The token program not owning the tMEWC mint must be rejected.
+      const failingIx = await wormholeGateway.depositWormholeTmewcIx(
+        {
+          recipientWrappedToken,
+          recipientToken,
+          recipient: payer.publicKey,
+          tmewcTokenProgram: MISMATCHED_TOKEN_PROGRAM,
+        },
+        BigInt(500)
+      );
+      await expectIxFail([failingIx], [payer], "ConstraintMintTokenProgram");
+    });
+
+    it("cannot deposit wrapped tmewc (minting limit exceeded)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Check wrapped tMEWC mint.
+      const recipientWrappedToken = await preloadWrappedTmewc(
+        payer,
+        ethereumTokenBridge,
+        BigInt("100000000000"),
         payer.publicKey
       );
 
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tmewcMint,
+        payer.publicKey,
+        TMEWC_TOKEN_PROGRAM
+      );
+
       // Cannot deposit past minting limit.
       const failingIx = await wormholeGateway.depositWormholeTmewcIx(
         {
@@ -470,7 +1145,8 @@ describe("wormhole-gateway", () => {
       const recipientToken = await getOrCreateAta(
         payer,
         tmewcMint,
-        payer.publicKey
+        payer.publicKey,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Check minted amount before deposit.
@@ -521,7 +1197,9 @@ describe("wormhole-gateway", () => {
       const recipient = commonTokenOwner.publicKey;
       const recipientToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Get foreign gateway.
@@ -543,7 +1221,7 @@ describe("wormhole-gateway", () => {
       );
 
       const [tmewcBefore, gatewayBefore] = await Promise.all([
-        getAccount(connection, recipientToken),
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
         getAccount(connection, gatewayWrappedTmewcToken),
       ]);
 
@@ -555,10 +1233,10 @@ describe("wormhole-gateway", () => {
         },
         signedVaa
       );
-      await expectIxSuccess([ix], [payer]);
+      const txSig = await expectIxSuccess([ix], [payer]);
 
       const [tmewcAfter, gatewayAfter] = await Promise.all([
-        getAccount(connection, recipientToken),
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
         getAccount(connection, gatewayWrappedTmewcToken),
       ]);
 
@@ -570,6 +1248,37 @@ describe("wormhole-gateway", () => {
       const mintedAmountAfter = await wormholeGateway.getMintedAmount();
       expect(mintedAmountAfter).to.equal(mintedAmountBefore + sentAmount);
 
+      // The event is carried by an inner instruction that the gateway invokes
+      // on itself, signed by its event authority, so it survives log
+      // truncation in this CPI-heavy transaction.
+      await connection.confirmTransaction(txSig, "confirmed");
+      const tx = await connection.getTransaction(txSig, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const accountKeys = tx.transaction.message.getAccountKeys();
+      const eventIxs = tx.meta.innerInstructions
+        .flatMap(({ instructions }) => instructions)
+        .filter(
+          (inner) =>
+            accountKeys.get(inner.programIdIndex).equals(program.programId) &&
+            accountKeys
+              .get(inner.accounts[0])
+              .equals(wormholeGateway.getEventAuthorityPDA())
+        );
+      const events = eventIxs.map((inner) =>
+        program.coder.events.decode(
+          Buffer.from(anchor.utils.bytes.bs58.decode(inner.data))
+            .subarray(8)
+            .toString("base64")
+        )
+      );
+      const received = events.find(
+        (event) => event.name === "WormholeTmewcReceived"
+      );
+      expect(received.data.receiver).to.eql(recipient);
+      expect(received.data.amount.toString()).to.equal(sentAmount.toString());
+
       // Save vaa.
       replayVaa = signedVaa;
     });
@@ -582,7 +1291,9 @@ describe("wormhole-gateway", () => {
       const recipient = commonTokenOwner.publicKey;
       const recipientToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       const ix = await wormholeGateway.receiveTmewcIx(
@@ -606,7 +1317,9 @@ describe("wormhole-gateway", () => {
       const recipient = commonTokenOwner.publicKey;
       const recipientToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
       const recipientWrappedToken = getAssociatedTokenAddressSync(
         WRAPPED_TMEWC_MINT,
@@ -658,7 +1371,7 @@ describe("wormhole-gateway", () => {
       // check the balance of the recipient's wrapped tmewc yet,
       // since the contract will create the ATA.
       const [tmewcBefore, gatewayBefore] = await Promise.all([
-        getAccount(connection, recipientToken),
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
         getAccount(connection, gatewayWrappedTmewcToken),
       ]);
 
@@ -675,7 +1388,7 @@ describe("wormhole-gateway", () => {
       // Check token accounts after receiving wrapped tmewc. We should
       // be able to fetch the recipient's wrapped tmewc now.
       const [tmewcAfter, wrappedTmewcAfter, gatewayAfter] = await Promise.all([
-        getAccount(connection, recipientToken),
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
         getAccount(connection, recipientWrappedToken),
         getAccount(connection, gatewayWrappedTmewcToken),
       ]);
@@ -698,7 +1411,9 @@ describe("wormhole-gateway", () => {
       const recipient = commonTokenOwner.publicKey;
       const recipientToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
       const recipientWrappedToken = await getOrCreateAta(
         payer,
@@ -725,33 +1440,915 @@ describe("wormhole-gateway", () => {
         recipient
       );
 
-      // Set the mint limit to a value smaller than sentAmount.
-      const newLimit = sentAmount - BigInt(69);
-      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+      // Set the mint limit to a value smaller than sentAmount.
+      const newLimit = sentAmount - BigInt(69);
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        newLimit
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+      await wormholeGateway.checkCustodian({
+        authority: authority.publicKey,
+        mintingLimit: newLimit,
+        pendingAuthority: null,
+      });
+
+      // Balance check before receiving wrapped tmewc. If this
+      // line successfully executes, then the recipient's
+      // wrapped tmewc account already exists.
+      const [tmewcBefore, wrappedTmewcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, gatewayWrappedTmewcToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      // Check token accounts after receiving wrapped tmewc.
+      const [tmewcAfter, wrappedTmewcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, gatewayWrappedTmewcToken),
+      ]);
+
+      // Check minted amount.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore);
+
+      // Check balance change.
+      expect(tmewcAfter.amount).to.equal(tmewcBefore.amount);
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount);
+      expect(wrappedTmewcAfter.amount).to.equal(
+        wrappedTmewcBefore.amount + sentAmount
+      );
+    });
+
+    it("cannot update inbound rate limit (not authority)", async () => {
+      const ix = await wormholeGateway.updateInboundRateLimitIx(
+        {
+          authority: imposter.publicKey,
+        },
+        BigInt(1000),
+        3600
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("receive wrapped tmewc (inbound rate limit exceeded)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const recipientWrappedToken = getAssociatedTokenAddressSync(
+        WRAPPED_TMEWC_MINT,
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Raise the minting limit so that only the rate limit applies.
+      const { mintingLimit } = await wormholeGateway.getCustodianData();
+      const raiseLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(1000000)
+      );
+      const rateLimitIx = await wormholeGateway.updateInboundRateLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(1000),
+        3600
+      );
+      await expectIxSuccess([raiseLimitIx, rateLimitIx], [authority]);
+
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      // Create transfer VAA exceeding the rate limit.
+      const sentAmount = BigInt(1500);
+      const signedVaa = await ethereumGatewaySendTmewc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const [tmewcBefore, wrappedTmewcBefore] = await Promise.all([
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
+        getAccount(connection, recipientWrappedToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const [tmewcAfter, wrappedTmewcAfter] = await Promise.all([
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
+        getAccount(connection, recipientWrappedToken),
+      ]);
+
+      // Wormhole tMEWC is sent instead of minting.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore);
+      expect(tmewcAfter.amount).to.equal(tmewcBefore.amount);
+      expect(wrappedTmewcAfter.amount).to.equal(
+        wrappedTmewcBefore.amount + sentAmount
+      );
+
+      // Deposits are subject to the same rate limit.
+      const depositIx = await wormholeGateway.depositWormholeTmewcIx(
+        {
+          recipientWrappedToken,
+          recipientToken,
+          recipient,
+        },
+        BigInt(1001)
+      );
+      await expectIxFail(
+        [depositIx],
+        [commonTokenOwner],
+        "InboundRateLimitExceeded"
+      );
+
+      // Restore the minting limit and disable the rate limit.
+      const restoreLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(mintingLimit.toString())
+      );
+      const disableRateLimitIx = await wormholeGateway.updateInboundRateLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(0),
+        0
+      );
+      await expectIxSuccess([restoreLimitIx, disableRateLimitIx], [authority]);
+    });
+
+    it("receive tmewc (large transfer cancelled and refunded)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Hold transfers above 1000 for an hour, reviewed by a guardian.
+      const delayIx = await wormholeGateway.updateLargeTransferDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(1000),
+        3600
+      );
+      const addGuardianIx = await wormholeGateway.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian2Keys.publicKey,
+      });
+      await expectIxSuccess([delayIx, addGuardianIx], [authority]);
+
+      const sentAmount = BigInt(1200);
+      const signedVaa = await ethereumGatewaySendTmewc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+      const messageHash = parseVaa(signedVaa).hash;
+      const pendingReceipt = wormholeGateway.getPendingReceiptPDA(messageHash);
+
+      // Large transfers must be queued.
+      const failingIx = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([failingIx], [payer], "InvalidPendingReceipt");
+
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+      const tmewcBefore = await getAccount(
+        connection,
+        recipientToken,
+        undefined,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      const ix = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+          pendingReceipt,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      // Nothing is minted yet.
+      const tmewcAfter = await getAccount(
+        connection,
+        recipientToken,
+        undefined,
+        TMEWC_TOKEN_PROGRAM
+      );
+      expect(tmewcAfter.amount).to.equal(tmewcBefore.amount);
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore);
+
+      const receipt = await wormholeGateway.getPendingReceipt(messageHash);
+      expect(receipt.payer).to.eql(payer.publicKey);
+      expect(receipt.recipient).to.eql(recipient);
+      expect(receipt.amount.toString()).to.equal(sentAmount.toString());
+
+      // Cannot release during the delay.
+      const releaseIx = await wormholeGateway.releasePendingReceiptIx({
+        payer: payer.publicKey,
+        pendingReceipt,
+        recipientToken,
+        recipient,
+      });
+      await expectIxFail([releaseIx], [payer], "PendingReceiptDelayed");
+
+      // Only guardians can cancel.
+      const imposterIx = await wormholeGateway.cancelPendingReceiptIx({
+        guardian: imposter.publicKey,
+        pendingReceipt,
+      });
+      await expectIxFail([imposterIx], [imposter], "AccountNotInitialized");
+
+      const cancelIx = await wormholeGateway.cancelPendingReceiptIx({
+        guardian: guardian2Keys.publicKey,
+        pendingReceipt,
+      });
+      await expectIxSuccess([cancelIx], [authority, guardian2Keys]);
+
+      // The receipt is kept so that its Wormhole tMEWC can be recovered.
+      const cancelledReceipt = await wormholeGateway.getPendingReceipt(
+        messageHash
+      );
+      expect(cancelledReceipt.cancelled).is.true;
+
+      await expectIxFail(
+        [cancelIx],
+        [payer, guardian2Keys],
+        "PendingReceiptCancelled"
+      );
+      await expectIxFail(
+        [releaseIx],
+        [authority, payer],
+        "PendingReceiptCancelled"
+      );
+
+      // The authority recovers the Wormhole tMEWC for the recipient.
+      const recipientWrappedToken = getAssociatedTokenAddressSync(
+        WRAPPED_TMEWC_MINT,
+        recipient
+      );
+
+      // Only the authority can refund.
+      const imposterRefundIx = await wormholeGateway.refundPendingReceiptIx({
+        pendingReceipt,
+        recipient,
+        authority: imposter.publicKey,
+      });
+      await expectIxFail([imposterRefundIx], [imposter], "IsNotAuthority");
+
+      const [wrappedTmewcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, gatewayWrappedTmewcToken),
+      ]);
+
+      const ix = await wormholeGateway.refundPendingReceiptIx({
+        pendingReceipt,
+        recipient,
+        authority: authority.publicKey,
+      });
+      const txSig = await expectIxSuccess([ix], [authority]);
+
+      const refunded = await expectEvent(txSig, "PendingReceiptRefunded");
+      expect(refunded.recipient).to.eql(recipient);
+      expect(refunded.amount.toString()).to.equal(sentAmount.toString());
+
+      // The Wormhole tMEWC leaves custody for the recipient.
+      const [wrappedTmewcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, gatewayWrappedTmewcToken),
+      ]);
+      expect(wrappedTmewcAfter.amount).to.equal(
+        wrappedTmewcBefore.amount + sentAmount
+      );
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount - sentAmount);
+      expect(await wormholeGateway.getMintedAmount()).to.equal(
+        mintedAmountAfter
+      );
+
+      const receiptInfo = await connection.getAccountInfo(pendingReceipt);
+      expect(receiptInfo).to.be.null;
+    });
+
+    it("receive tmewc (large transfer released)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Raise the minting limit and let large transfers be released at once.
+      const { mintingLimit } = await wormholeGateway.getCustodianData();
+      const raiseLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(1000000)
+      );
+      const delayIx = await wormholeGateway.updateLargeTransferDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(1000),
+        0
+      );
+      await expectIxSuccess([raiseLimitIx, delayIx], [authority]);
+
+      const sentAmount = BigInt(1300);
+      const signedVaa = await ethereumGatewaySendTmewc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+      const messageHash = parseVaa(signedVaa).hash;
+      const pendingReceipt = wormholeGateway.getPendingReceiptPDA(messageHash);
+
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+      const tmewcBefore = await getAccount(
+        connection,
+        recipientToken,
+        undefined,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      const ix = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+          pendingReceipt,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      // Anyone can release once the delay has passed.
+      const releaseIx = await wormholeGateway.releasePendingReceiptIx({
+        payer: txPayer.publicKey,
+        pendingReceipt,
+        recipientToken,
+        recipient,
+      });
+      await expectIxSuccess([releaseIx], [txPayer]);
+
+      const tmewcAfter = await getAccount(
+        connection,
+        recipientToken,
+        undefined,
+        TMEWC_TOKEN_PROGRAM
+      );
+      expect(tmewcAfter.amount).to.equal(tmewcBefore.amount + sentAmount);
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore + sentAmount);
+
+      const receiptInfo = await connection.getAccountInfo(pendingReceipt);
+      expect(receiptInfo).to.be.null;
+
+      // Restore the minting limit, disable the delay and remove the guardian.
+      const restoreLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(mintingLimit.toString())
+      );
+      const disableDelayIx = await wormholeGateway.updateLargeTransferDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(0),
+        0
+      );
+      const removeGuardianIx = await wormholeGateway.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardian2Keys.publicKey,
+      });
+      await expectIxSuccess(
+        [restoreLimitIx, disableDelayIx, removeGuardianIx],
+        [authority]
+      );
+    });
+
+    it("receive tmewc (over limit escrowed)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const pendingMint = wormholeGateway.getPendingMintPDA(recipient);
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Escrow transfers and leave no room to mint.
+      const { mintingLimit } = await wormholeGateway.getCustodianData();
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+      const escrowIx = await wormholeGateway.updateOverLimitModeIx(
+        {
+          authority: authority.publicKey,
+        },
+        { overLimitMode: { escrow: {} }, partialMint: false }
+      );
+      const lowerLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        mintedAmountBefore
+      );
+      await expectIxSuccess([escrowIx, lowerLimitIx], [authority]);
+
+      const sentAmount = BigInt(700);
+      const signedVaa = await ethereumGatewaySendTmewc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      // The pending mint is required in escrow mode.
+      const failingIx = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([failingIx], [payer], "InvalidPendingMint");
+
+      // Funding the pending mint's address beforehand does not block escrow.
+      await transferLamports(imposter, pendingMint, 1000000);
+
+      const [tmewcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
+        getAccount(connection, gatewayWrappedTmewcToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+          pendingMint,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      // Wormhole tMEWC stays in custody for the recipient.
+      const [tmewcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
+        getAccount(connection, gatewayWrappedTmewcToken),
+      ]);
+      expect(tmewcAfter.amount).to.equal(tmewcBefore.amount);
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount + sentAmount);
+
+      const claim = await wormholeGateway.getPendingMint(recipient);
+      expect(claim.payer).to.eql(payer.publicKey);
+      expect(claim.recipient).to.eql(recipient);
+      expect(claim.amount.toString()).to.equal(sentAmount.toString());
+
+      // Cannot process the pending mint until the limit is raised.
+      const processIx = await wormholeGateway.processPendingMintIx({
+        recipientToken,
+        recipient,
+      });
+      await expectIxFail([processIx], [txPayer], "MintingLimitExceeded");
+
+      const raiseLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        mintedAmountBefore + sentAmount
+      );
+      await expectIxSuccess([raiseLimitIx], [authority]);
+
+      // Anyone can process the pending mint.
+      await expectIxSuccess([processIx], [txPayer]);
+
+      const tmewcProcessed = await getAccount(
+        connection,
+        recipientToken,
+        undefined,
+        TMEWC_TOKEN_PROGRAM
+      );
+      expect(tmewcProcessed.amount).to.equal(tmewcBefore.amount + sentAmount);
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore + sentAmount);
+
+      const claimInfo = await connection.getAccountInfo(pendingMint);
+      expect(claimInfo).to.be.null;
+
+      // Restore the minting limit and send Wormhole tMEWC again.
+      const restoreLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(mintingLimit.toString())
+      );
+      const sendWrappedIx = await wormholeGateway.updateOverLimitModeIx(
+        {
+          authority: authority.publicKey,
+        },
+        { overLimitMode: { sendWrapped: {} }, partialMint: false }
+      );
+      await expectIxSuccess([restoreLimitIx, sendWrappedIx], [authority]);
+    });
+
+    it("receive tmewc (partially minted)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const recipientWrappedToken = getAssociatedTokenAddressSync(
+        WRAPPED_TMEWC_MINT,
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Mint up to the limit, leaving room for 400.
+      const { mintingLimit } = await wormholeGateway.getCustodianData();
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+      const partialIx = await wormholeGateway.updateOverLimitModeIx(
+        {
+          authority: authority.publicKey,
+        },
+        { overLimitMode: { sendWrapped: {} }, partialMint: true }
+      );
+      const limitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        mintedAmountBefore + BigInt(400)
+      );
+      await expectIxSuccess([partialIx, limitIx], [authority]);
+
+      const sentAmount = BigInt(1000);
+      const signedVaa = await ethereumGatewaySendTmewc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const [tmewcBefore, wrappedTmewcBefore] = await Promise.all([
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
+        getAccount(connection, recipientWrappedToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const [tmewcAfter, wrappedTmewcAfter] = await Promise.all([
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
+        getAccount(connection, recipientWrappedToken),
+      ]);
+
+      // Only the excess is sent as Wormhole tMEWC.
+      expect(tmewcAfter.amount).to.equal(tmewcBefore.amount + BigInt(400));
+      expect(wrappedTmewcAfter.amount).to.equal(
+        wrappedTmewcBefore.amount + BigInt(600)
+      );
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore + BigInt(400));
+
+      // Restore the minting limit and disable partial minting.
+      const restoreLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(mintingLimit.toString())
+      );
+      const allOrNothingIx = await wormholeGateway.updateOverLimitModeIx(
+        {
+          authority: authority.publicKey,
+        },
+        { overLimitMode: { sendWrapped: {} }, partialMint: false }
+      );
+      await expectIxSuccess([restoreLimitIx, allOrNothingIx], [authority]);
+    });
+
+    it("receive tmewc (reserve attestation stale)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const recipientWrappedToken = getAssociatedTokenAddressSync(
+        WRAPPED_TMEWC_MINT,
+        recipient
+      );
+      const tmewcReserveAttestation = tmewc.getReserveAttestationPDA();
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Enable proof of reserve without tolerating any staleness, so the last
+      // attestation is already stale.
+      const enableIx = await tmewc.updateReserveAttesterIx(
+        {
+          authority: authority.publicKey,
+        },
+        imposter.publicKey,
+        0
+      );
+      await expectIxSuccess([enableIx], [authority]);
+
+      const sentAmount = BigInt(100);
+      const signedVaa = await ethereumGatewaySendTmewc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      // The attestation must be provided.
+      const failingIx = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail(
+        [failingIx],
+        [payer],
+        "InvalidTmewcReserveAttestation"
+      );
+
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+      const [tmewcBefore, wrappedTmewcBefore] = await Promise.all([
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
+        getAccount(connection, recipientWrappedToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          tmewcReserveAttestation,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const [tmewcAfter, wrappedTmewcAfter] = await Promise.all([
+        getAccount(connection, recipientToken, undefined, TMEWC_TOKEN_PROGRAM),
+        getAccount(connection, recipientWrappedToken),
+      ]);
+
+      // Nothing is minted, so the transfer is sent as Wormhole tMEWC.
+      expect(tmewcAfter.amount).to.equal(tmewcBefore.amount);
+      expect(wrappedTmewcAfter.amount).to.equal(
+        wrappedTmewcBefore.amount + sentAmount
+      );
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore);
+
+      // Disable proof of reserve.
+      const disableIx = await tmewc.updateReserveAttesterIx(
+        {
+          authority: authority.publicKey,
+        },
+        null,
+        0
+      );
+      await expectIxSuccess([disableIx], [authority]);
+    });
+
+    it("receive tmewc (gateway allowlist)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const gatewayInfo = wormholeGateway.getGatewayInfoPDA(2);
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Enable the allowlist and leave room to mint.
+      const { mintingLimit } = await wormholeGateway.getCustodianData();
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      const allowlistIx = await wormholeGateway.setGatewayAllowlistIx(
+        {
+          authority: authority.publicKey,
+        },
+        true
+      );
+      const raiseLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        mintedAmount + BigInt(200)
+      );
+      await expectIxSuccess([allowlistIx, raiseLimitIx], [authority]);
+
+      // Transfers from another contract are rejected.
+      const imposterVaa = await ethereumGatewaySendTmewc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(100),
+        Array.from(randomBytes(32)),
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+      const imposterIx = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          gatewayInfo,
+          recipientToken,
+          recipient,
+        },
+        imposterVaa
+      );
+      await expectIxFail([imposterIx], [payer], "InvalidSendingGateway");
+
+      const sentAmount = BigInt(200);
+      const signedVaa = await ethereumGatewaySendTmewc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      // The sending gateway must be provided.
+      const failingIx = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([failingIx], [payer], "InvalidSendingGateway");
+
+      // Disabled gateways cannot send tMEWC either.
+      const disableIx = await wormholeGateway.disableGatewayIx(
+        {
+          authority: authority.publicKey,
+        },
+        2
+      );
+      await expectIxSuccess([disableIx], [authority]);
+
+      const disabledIx = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          gatewayInfo,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([disabledIx], [payer], "GatewayDisabled");
+
+      const enableIx = await wormholeGateway.enableGatewayIx(
         {
           authority: authority.publicKey,
         },
-        newLimit
+        2
       );
-      await expectIxSuccess([updateLimitIx], [authority]);
-      await wormholeGateway.checkCustodian({
-        authority: authority.publicKey,
-        mintingLimit: newLimit,
-        pendingAuthority: null,
-      });
+      await expectIxSuccess([enableIx], [authority]);
 
-      // Balance check before receiving wrapped tmewc. If this
-      // line successfully executes, then the recipient's
-      // wrapped tmewc account already exists.
-      const [tmewcBefore, wrappedTmewcBefore, gatewayBefore] = await Promise.all([
-        getAccount(connection, recipientToken),
-        getAccount(connection, recipientWrappedToken),
-        getAccount(connection, gatewayWrappedTmewcToken),
-      ]);
+      const tmewcBefore = await getAccount(
+        connection,
+        recipientToken,
+        undefined,
+        TMEWC_TOKEN_PROGRAM
+      );
 
       const ix = await wormholeGateway.receiveTmewcIx(
         {
           payer: payer.publicKey,
+          gatewayInfo,
           recipientToken,
           recipient,
         },
@@ -759,23 +2356,28 @@ describe("wormhole-gateway", () => {
       );
       await expectIxSuccess([ix], [payer]);
 
-      // Check token accounts after receiving wrapped tmewc.
-      const [tmewcAfter, wrappedTmewcAfter, gatewayAfter] = await Promise.all([
-        getAccount(connection, recipientToken),
-        getAccount(connection, recipientWrappedToken),
-        getAccount(connection, gatewayWrappedTmewcToken),
-      ]);
-
-      // Check minted amount.
-      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
-      expect(mintedAmountAfter).to.equal(mintedAmountBefore);
+      const tmewcAfter = await getAccount(
+        connection,
+        recipientToken,
+        undefined,
+        TMEWC_TOKEN_PROGRAM
+      );
+      expect(tmewcAfter.amount).to.equal(tmewcBefore.amount + sentAmount);
 
-      // Check balance change.
-      expect(tmewcAfter.amount).to.equal(tmewcBefore.amount);
-      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount);
-      expect(wrappedTmewcAfter.amount).to.equal(
-        wrappedTmewcBefore.amount + sentAmount
+      // Restore the minting limit and disable the allowlist.
+      const restoreLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(mintingLimit.toString())
+      );
+      const disableAllowlistIx = await wormholeGateway.setGatewayAllowlistIx(
+        {
+          authority: authority.publicKey,
+        },
+        false
       );
+      await expectIxSuccess([restoreLimitIx, disableAllowlistIx], [authority]);
     });
 
     it("cannot receive non-tmewc transfers", async () => {
@@ -786,7 +2388,9 @@ describe("wormhole-gateway", () => {
       const recipient = commonTokenOwner.publicKey;
       const recipientToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Get foreign gateway.
@@ -825,7 +2429,9 @@ describe("wormhole-gateway", () => {
       const recipient = commonTokenOwner.publicKey;
       const recipientToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        recipient
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Get foreign gateway.
@@ -863,7 +2469,8 @@ describe("wormhole-gateway", () => {
       const defaultTokenAccount = await getOrCreateAta(
         payer,
         tmewc.getMintPDA(),
-        recipient
+        recipient,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Get foreign gateway.
@@ -899,12 +2506,14 @@ describe("wormhole-gateway", () => {
       const sender = commonTokenOwner.publicKey;
       const senderToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        sender
+        sender,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Check token accounts.
       const [senderTmewcBefore, gatewayBefore] = await Promise.all([
-        getAccount(connection, senderToken),
+        getAccount(connection, senderToken, undefined, TMEWC_TOKEN_PROGRAM),
         getAccount(connection, gatewayWrappedTmewcToken),
       ]);
 
@@ -934,7 +2543,7 @@ describe("wormhole-gateway", () => {
 
       // Check token accounts after sending tmewc.
       const [senderTmewcAfter, gatewayAfter] = await Promise.all([
-        getAccount(connection, senderToken),
+        getAccount(connection, senderToken, undefined, TMEWC_TOKEN_PROGRAM),
         getAccount(connection, gatewayWrappedTmewcToken),
       ]);
 
@@ -954,7 +2563,9 @@ describe("wormhole-gateway", () => {
       const sender = commonTokenOwner.publicKey;
       const senderToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        sender
+        sender,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Get destination gateway.
@@ -990,7 +2601,9 @@ describe("wormhole-gateway", () => {
       const sender = commonTokenOwner.publicKey;
       const senderToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        sender
+        sender,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Get destination gateway.
@@ -1020,7 +2633,9 @@ describe("wormhole-gateway", () => {
       const sender = commonTokenOwner.publicKey;
       const senderToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        sender
+        sender,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Get destination gateway.
@@ -1049,7 +2664,9 @@ describe("wormhole-gateway", () => {
       const sender = commonTokenOwner.publicKey;
       const senderToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        sender
+        sender,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Get destination gateway.
@@ -1072,6 +2689,121 @@ describe("wormhole-gateway", () => {
       );
       await expectIxFail([ix], [commonTokenOwner], "AccountNotInitialized");
     });
+
+    it("cannot send tmewc to gateway (mismatched token program)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        sender,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      // Get destination gateway.
+      const recipientChain = 2;
+      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const nonce = 420;
+
+      // The token program not owning the tMEWC mint must be rejected.
+      const sendAmount = BigInt(69);
+      const ix = await wormholeGateway.sendTmewcGatewayIx(
+        {
+          senderToken,
+          sender,
+          tmewcTokenProgram: MISMATCHED_TOKEN_PROGRAM,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          nonce,
+        }
+      );
+      await expectIxFail(
+        [ix],
+        [commonTokenOwner],
+        "ConstraintMintTokenProgram"
+      );
+    });
+
+    it("cannot update outbound limit (not authority)", async () => {
+      const ix = await wormholeGateway.updateOutboundLimitIx(
+        { authority: imposter.publicKey },
+        2,
+        {
+          maxTransferAmount: BigInt(300),
+          windowLimit: BigInt(500),
+          windowDuration: 3600,
+        }
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("send tmewc to gateway within outbound limit", async () => {
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        sender,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      const recipientChain = 2;
+      const sendIx = (amount: bigint) =>
+        wormholeGateway.sendTmewcGatewayIx(
+          {
+            senderToken,
+            sender,
+          },
+          {
+            amount: new anchor.BN(amount.toString()),
+            recipientChain,
+            recipient: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
+            nonce: 420,
+          }
+        );
+
+      const updateLimitIx = await wormholeGateway.updateOutboundLimitIx(
+        { authority: authority.publicKey },
+        recipientChain,
+        {
+          maxTransferAmount: BigInt(300),
+          windowLimit: BigInt(500),
+          windowDuration: 3600,
+        }
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+
+      // Too much for a single transfer.
+      await expectIxFail(
+        [await sendIx(BigInt(301))],
+        [commonTokenOwner],
+        "OutboundTransferLimitExceeded"
+      );
+
+      // This should work.
+      await expectIxSuccess([await sendIx(BigInt(300))], [commonTokenOwner]);
+
+      // Too much for the rest of the window.
+      await expectIxFail(
+        [await sendIx(BigInt(201))],
+        [commonTokenOwner],
+        "OutboundWindowLimitExceeded"
+      );
+
+      // Zero thresholds disable the limit again.
+      const resetLimitIx = await wormholeGateway.updateOutboundLimitIx(
+        { authority: authority.publicKey },
+        recipientChain,
+        {
+          maxTransferAmount: BigInt(0),
+          windowLimit: BigInt(0),
+          windowDuration: 0,
+        }
+      );
+      await expectIxSuccess([resetLimitIx], [authority]);
+    });
   });
 
   describe("send wrapped tmewc", () => {
@@ -1080,12 +2812,14 @@ describe("wormhole-gateway", () => {
       const sender = commonTokenOwner.publicKey;
       const senderToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        sender
+        sender,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Check token accounts.
       const [senderTmewcBefore, gatewayBefore] = await Promise.all([
-        getAccount(connection, senderToken),
+        getAccount(connection, senderToken, undefined, TMEWC_TOKEN_PROGRAM),
         getAccount(connection, gatewayWrappedTmewcToken),
       ]);
 
@@ -1116,7 +2850,7 @@ describe("wormhole-gateway", () => {
 
       // Check token accounts after sending tmewc.
       const [senderTmewcAfter, gatewayAfter] = await Promise.all([
-        getAccount(connection, senderToken),
+        getAccount(connection, senderToken, undefined, TMEWC_TOKEN_PROGRAM),
         getAccount(connection, gatewayWrappedTmewcToken),
       ]);
 
@@ -1136,7 +2870,9 @@ describe("wormhole-gateway", () => {
       const sender = commonTokenOwner.publicKey;
       const senderToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        sender
+        sender,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Get destination gateway.
@@ -1173,7 +2909,9 @@ describe("wormhole-gateway", () => {
       const sender = commonTokenOwner.publicKey;
       const senderToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        sender
+        sender,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Get destination gateway.
@@ -1204,7 +2942,9 @@ describe("wormhole-gateway", () => {
       const sender = commonTokenOwner.publicKey;
       const senderToken = getAssociatedTokenAddressSync(
         tmewc.getMintPDA(),
-        sender
+        sender,
+        false,
+        TMEWC_TOKEN_PROGRAM
       );
 
       // Get destination gateway.
@@ -1228,5 +2968,329 @@ describe("wormhole-gateway", () => {
       );
       await expectIxFail([ix], [commonTokenOwner], "ZeroRecipient");
     });
+
+    it("cannot send wrapped tmewc (outbound limit exceeded)", async () => {
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        sender,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      const sendIx = (recipientChain: number) =>
+        wormholeGateway.sendTmewcWrappedIx(
+          {
+            senderToken,
+            sender,
+          },
+          {
+            amount: new anchor.BN(301),
+            recipientChain,
+            recipient: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
+            arbiterFee: new anchor.BN(0),
+            nonce: 420,
+          }
+        );
+
+      const limit = {
+        maxTransferAmount: BigInt(300),
+        windowLimit: BigInt(0),
+        windowDuration: 0,
+      };
+      const noLimit = {
+        maxTransferAmount: BigInt(0),
+        windowLimit: BigInt(0),
+        windowDuration: 0,
+      };
+
+      // Chains without a gateway are subject to the default limit.
+      const defaultLimitIx = await wormholeGateway.updateDefaultOutboundLimitIx(
+        { authority: authority.publicKey },
+        limit
+      );
+      await expectIxSuccess([defaultLimitIx], [authority]);
+      await expectIxFail(
+        [await sendIx(69)],
+        [commonTokenOwner],
+        "OutboundTransferLimitExceeded"
+      );
+
+      // Chains with a gateway are subject to the gateway's limit instead.
+      const resetDefaultLimitIx =
+        await wormholeGateway.updateDefaultOutboundLimitIx(
+          { authority: authority.publicKey },
+          noLimit
+        );
+      const gatewayLimitIx = await wormholeGateway.updateOutboundLimitIx(
+        { authority: authority.publicKey },
+        2,
+        limit
+      );
+      await expectIxSuccess([resetDefaultLimitIx, gatewayLimitIx], [authority]);
+      await expectIxFail(
+        [await sendIx(2)],
+        [commonTokenOwner],
+        "OutboundTransferLimitExceeded"
+      );
+
+      const resetGatewayLimitIx = await wormholeGateway.updateOutboundLimitIx(
+        { authority: authority.publicKey },
+        2,
+        noLimit
+      );
+      await expectIxSuccess([resetGatewayLimitIx], [authority]);
+    });
+
+    it("cannot update default outbound limit (not authority)", async () => {
+      const ix = await wormholeGateway.updateDefaultOutboundLimitIx(
+        { authority: imposter.publicKey },
+        {
+          maxTransferAmount: BigInt(300),
+          windowLimit: BigInt(0),
+          windowDuration: 0,
+        }
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+  });
+
+  describe("pause", () => {
+    const sendIx = (amount: bigint) =>
+      wormholeGateway.sendTmewcGatewayIx(
+        {
+          senderToken: getAssociatedTokenAddressSync(
+            tmewc.getMintPDA(),
+            commonTokenOwner.publicKey,
+            false,
+            TMEWC_TOKEN_PROGRAM
+          ),
+          sender: commonTokenOwner.publicKey,
+        },
+        {
+          amount: new anchor.BN(amount.toString()),
+          recipientChain: 2,
+          recipient: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
+          nonce: 420,
+        }
+      );
+
+    it("cannot add guardian (not authority)", async () => {
+      const ix = await wormholeGateway.addGuardianIx({
+        authority: imposter.publicKey,
+        guardian: guardianKeys.publicKey,
+      });
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("add guardian", async () => {
+      const ix = await wormholeGateway.addGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardianKeys.publicKey,
+      });
+      await expectIxSuccess([ix], [authority]);
+    });
+
+    it("cannot pause (not guardian)", async () => {
+      const ix = await wormholeGateway.pauseIx(
+        { guardian: imposter.publicKey },
+        { inbound: true }
+      );
+      await expectIxFail([ix], [imposter], "AccountNotInitialized");
+    });
+
+    it("cannot pause (no flags)", async () => {
+      const ix = await wormholeGateway.pauseIx(
+        { guardian: guardianKeys.publicKey },
+        {}
+      );
+      await expectIxFail([ix], [authority, guardianKeys], "NoPauseFlags");
+    });
+
+    it("pause inbound", async () => {
+      const ix = await wormholeGateway.pauseIx(
+        { guardian: guardianKeys.publicKey },
+        { inbound: true }
+      );
+      await expectIxSuccess([ix], [authority, guardianKeys]);
+      await wormholeGateway.checkPaused({
+        ...wormholeGateway.NO_PAUSE_FLAGS,
+        inbound: true,
+      });
+    });
+
+    it("cannot receive tmewc (inbound paused)", async () => {
+      const payer = await generatePayer(authority);
+
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tmewc.getMintPDA(),
+        recipient,
+        false,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const signedVaa = await ethereumGatewaySendTmewc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(100),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const ix = await wormholeGateway.receiveTmewcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([ix], [payer], "InboundPaused");
+    });
+
+    it("send tmewc while inbound is paused", async () => {
+      const ix = await sendIx(BigInt(100));
+      await expectIxSuccess([ix], [commonTokenOwner]);
+    });
+
+    it("pause outbound and deposits", async () => {
+      const ix = await wormholeGateway.pauseIx(
+        { guardian: guardianKeys.publicKey },
+        { outbound: true, deposit: true }
+      );
+      await expectIxSuccess([ix], [authority, guardianKeys]);
+      await wormholeGateway.checkPaused({
+        inbound: true,
+        outbound: true,
+        deposit: true,
+      });
+    });
+
+    it("cannot send tmewc (outbound paused)", async () => {
+      const ix = await sendIx(BigInt(101));
+      await expectIxFail([ix], [commonTokenOwner], "OutboundPaused");
+    });
+
+    it("cannot deposit wrapped tmewc (deposits paused)", async () => {
+      const payer = await generatePayer(authority);
+
+      const recipientWrappedToken = await preloadWrappedTmewc(
+        payer,
+        ethereumTokenBridge,
+        BigInt("100000000000"),
+        payer.publicKey
+      );
+
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tmewcMint,
+        payer.publicKey,
+        TMEWC_TOKEN_PROGRAM
+      );
+
+      const ix = await wormholeGateway.depositWormholeTmewcIx(
+        {
+          recipientWrappedToken,
+          recipientToken,
+          recipient: payer.publicKey,
+        },
+        BigInt(100)
+      );
+      await expectIxFail([ix], [payer], "DepositPaused");
+    });
+
+    it("cannot unpause (not authority)", async () => {
+      const ix = await wormholeGateway.unpauseIx(
+        { authority: imposter.publicKey },
+        { inbound: true }
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("unpause", async () => {
+      const ix = await wormholeGateway.unpauseIx(
+        { authority: authority.publicKey },
+        { outbound: true }
+      );
+      await expectIxSuccess([ix], [authority]);
+      await wormholeGateway.checkPaused({
+        inbound: true,
+        outbound: false,
+        deposit: true,
+      });
+
+      const unpauseAllIx = await wormholeGateway.unpauseIx(
+        { authority: authority.publicKey },
+        { inbound: true, deposit: true }
+      );
+      await expectIxSuccess([unpauseAllIx], [authority]);
+      await wormholeGateway.checkPaused(wormholeGateway.NO_PAUSE_FLAGS);
+    });
+
+    it("remove guardian", async () => {
+      const ix = await wormholeGateway.removeGuardianIx({
+        authority: authority.publicKey,
+        guardian: guardianKeys.publicKey,
+      });
+      await expectIxSuccess([ix], [authority]);
+
+      const pauseIx = await wormholeGateway.pauseIx(
+        { guardian: guardianKeys.publicKey },
+        { inbound: true }
+      );
+      await expectIxFail(
+        [pauseIx],
+        [authority, guardianKeys],
+        "AccountNotInitialized"
+      );
+    });
+  });
+
+  describe("supply checkpoints", () => {
+    it("cannot record tMEWC checkpoint without the gateway", async () => {
+      const ix = await tmewc.checkpointIx(
+        {
+          payer: txPayer.publicKey,
+        },
+        new anchor.BN(1)
+      );
+      await expectIxFail([ix], [txPayer], "InvalidGatewayCustodian");
+    });
+
+    it("record supply checkpoint", async () => {
+      const ix = await wormholeGateway.checkpointSupplyIx({
+        payer: txPayer.publicKey,
+      });
+      await expectIxSuccess([ix], [txPayer]);
+
+      const { supply } = await getMint(
+        connection,
+        tmewcMint,
+        undefined,
+        TMEWC_TOKEN_PROGRAM
+      );
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+
+      const { nextIndex, checkpoints } = await tmewc.getSupplyCheckpointsData();
+      expect(nextIndex).to.equal(1);
+      expect(checkpoints).has.length(1);
+      expect(checkpoints[0].supply.toString()).to.equal(supply.toString());
+      expect(checkpoints[0].gatewayMintedAmount.toString()).to.equal(
+        mintedAmount.toString()
+      );
+    });
+
+    it("cannot record supply checkpoint (too early)", async () => {
+      const ix = await wormholeGateway.checkpointSupplyIx({
+        payer: txPayer.publicKey,
+      });
+      await expectIxFail([ix], [txPayer], "CheckpointTooEarly");
+    });
   });
 });
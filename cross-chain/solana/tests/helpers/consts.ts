@@ -1,3 +1,4 @@
+import { TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
 
 export const TMEWC_PROGRAM_ID = new PublicKey(
@@ -7,6 +8,60 @@ export const WORMHOLE_GATEWAY_PROGRAM_ID = new PublicKey(
   "8H9F5JGbEMyERycwaGuzLS5MQnV7dn2wm2h6egJ3Leiu"
 );
 
+export const TMEWC_ASSET_ID = 0;
+
+// Token program owning the tMEWC mint. Set TMEWC_TOKEN_2022 to run the suites
+// against a Token-2022 tMEWC mint.
+export const TMEWC_TOKEN_PROGRAM = process.env.TMEWC_TOKEN_2022
+  ? TOKEN_2022_PROGRAM_ID
+  : TOKEN_PROGRAM_ID;
+
+// The other token program, which must be rejected for the tMEWC mint.
+export const MISMATCHED_TOKEN_PROGRAM = TMEWC_TOKEN_PROGRAM.equals(
+  TOKEN_PROGRAM_ID
+)
+  ? TOKEN_2022_PROGRAM_ID
+  : TOKEN_PROGRAM_ID;
+
+// Cluster of the default (mainnet) build the tests run against.
+export const CLUSTER = "mainnet-beta";
+
+export const TMEWC_ETH_AUTHORITY_DOMAIN = "tmewc eth authority";
+export const TMEWC_PAUSE_SIGNATURE_DOMAIN = "tmewc pause";
+export const GATEWAY_ETH_AUTHORITY_DOMAIN = "wormhole-gateway eth authority";
+
+export const TMEWC_GOVERNANCE_MODULE = "TMEWC";
+export const GATEWAY_GOVERNANCE_MODULE = "WormholeGateway";
+
+// Owner of a minter info created before `optimistic` was appended, loaded from
+// tests/accounts.
+export const LEGACY_MINTER = new PublicKey(
+  "HJcwo5ZR5Zh1zmwB6soxMsyfCvp6A6rQCByijvLW8Gie"
+);
+
+// Owner of a guardian info created before `pause_nonce` and `epoch` were
+// appended, loaded from tests/accounts.
+export const LEGACY_GUARDIAN = new PublicKey(
+  "FBfNmfwPvA6nNtj15GDiU2bgEd171qEyZ8H9rK4mVTAP"
+);
+
+// Chain of a gateway info created before `disabled` and `outbound_limit` were
+// appended, loaded from tests/accounts.
+export const LEGACY_GATEWAY_CHAIN = 30;
+export const LEGACY_GATEWAY_ADDRESS = Array.from(
+  Buffer.alloc(32, "cafebabe", "hex")
+);
+
+// Chain of another gateway info created before `disabled` was appended, left
+// for `migrate_custodian` to list in the gateway registry as is.
+export const UNLISTED_GATEWAY_CHAIN = 31;
+export const UNLISTED_GATEWAY_ADDRESS = Array.from(
+  Buffer.alloc(32, "feedface", "hex")
+);
+
+// Most gateways `get_gateways` returns at once, so they fit in the return data.
+export const MAX_GATEWAYS_PER_PAGE = 29;
+
 export const CORE_BRIDGE_PROGRAM_ID = new PublicKey(
   "worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth"
 );
@@ -1,70 +1,237 @@
-import { BN, Program, Wallet, workspace } from "@coral-xyz/anchor";
+import { parseVaa } from "@certusone/wormhole-sdk";
+import * as coreBridge from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
+import { BN, IdlTypes, Program, Wallet, workspace } from "@coral-xyz/anchor";
 import { getMint } from "@solana/spl-token";
-import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import {
+  Ed25519Program,
+  Keypair,
+  PublicKey,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  TransactionInstruction,
+} from "@solana/web3.js";
 import { config, expect } from "chai";
 import { Tmewc } from "../../target/types/tmewc";
-import { TMEWC_PROGRAM_ID } from "./consts";
+import {
+  CLUSTER,
+  CORE_BRIDGE_PROGRAM_ID,
+  TMEWC_ASSET_ID,
+  TMEWC_ETH_AUTHORITY_DOMAIN,
+  TMEWC_GOVERNANCE_MODULE,
+  TMEWC_PAUSE_SIGNATURE_DOMAIN,
+  TMEWC_PROGRAM_ID,
+  TMEWC_TOKEN_PROGRAM,
+} from "./consts";
 import { PROGRAM_ID as METADATA_PROGRAM_ID } from "@metaplex-foundation/mpl-token-metadata";
+import { ethAuthorizationIx, governanceMessage } from "./utils";
 
-export function getConfigPDA(): PublicKey {
+function encodeAssetId(assetId: number): Buffer {
+  // tMEWC's accounts predate asset IDs and keep their original addresses.
+  if (assetId === TMEWC_ASSET_ID) {
+    return Buffer.alloc(0);
+  }
+
+  const encodedAssetId = Buffer.alloc(2);
+  encodedAssetId.writeUInt16LE(assetId);
+  return encodedAssetId;
+}
+
+export function getConfigPDA(assetId = TMEWC_ASSET_ID): PublicKey {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("config")],
+    [Buffer.from("config"), encodeAssetId(assetId)],
     TMEWC_PROGRAM_ID
   )[0];
 }
 
-export function getMintPDA(): PublicKey {
+export function getMintPDA(assetId = TMEWC_ASSET_ID): PublicKey {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("tmewc-mint")],
+    [Buffer.from("tmewc-mint"), encodeAssetId(assetId)],
     TMEWC_PROGRAM_ID
   )[0];
 }
 
-export function getTmewcMetadataPDA(): PublicKey {
+export function getTmewcMetadataPDA(assetId = TMEWC_ASSET_ID): PublicKey {
   return PublicKey.findProgramAddressSync(
     [
       Buffer.from("metadata"),
       METADATA_PROGRAM_ID.toBuffer(),
-      getMintPDA().toBuffer(),
+      getMintPDA(assetId).toBuffer(),
     ],
     METADATA_PROGRAM_ID
   )[0];
 }
 
-export function getMinterInfoPDA(minter: PublicKey): PublicKey {
+export function getMinterInfoPDA(
+  minter: PublicKey,
+  assetId = TMEWC_ASSET_ID
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("minter-info"), encodeAssetId(assetId), minter.toBuffer()],
+    TMEWC_PROGRAM_ID
+  )[0];
+}
+
+export function getGuardianInfoPDA(
+  guardian: PublicKey,
+  assetId = TMEWC_ASSET_ID
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("guardian-info"), encodeAssetId(assetId), guardian.toBuffer()],
+    TMEWC_PROGRAM_ID
+  )[0];
+}
+
+export type Role = IdlTypes<Tmewc>["Role"];
+
+// Variants in the order of the program's `Role` enum.
+const ROLES = [
+  "minter",
+  "pauser",
+  "unpauser",
+  "metadataAdmin",
+  "limitAdmin",
+];
+
+export function getRoleAssignmentPDA(
+  role: Role,
+  member: PublicKey,
+  assetId = TMEWC_ASSET_ID
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("role-assignment"),
+      encodeAssetId(assetId),
+      Buffer.from([ROLES.indexOf(Object.keys(role)[0])]),
+      member.toBuffer(),
+    ],
+    TMEWC_PROGRAM_ID
+  )[0];
+}
+
+export function getGuardiansPDA(assetId = TMEWC_ASSET_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("guardians"), encodeAssetId(assetId)],
+    TMEWC_PROGRAM_ID
+  )[0];
+}
+
+export function getMintersPDA(assetId = TMEWC_ASSET_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("minters"), encodeAssetId(assetId)],
+    TMEWC_PROGRAM_ID
+  )[0];
+}
+
+export function getEventAuthorityPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority")],
+    TMEWC_PROGRAM_ID
+  )[0];
+}
+
+export function getMintRequestPDA(id: BN, assetId = TMEWC_ASSET_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("mint-request"),
+      encodeAssetId(assetId),
+      id.toArrayLike(Buffer, "le", 8),
+    ],
+    TMEWC_PROGRAM_ID
+  )[0];
+}
+
+export function getAuditLogPDA(assetId = TMEWC_ASSET_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("audit-log"), encodeAssetId(assetId)],
+    TMEWC_PROGRAM_ID
+  )[0];
+}
+
+export function getSupplyCheckpointsPDA(assetId = TMEWC_ASSET_ID): PublicKey {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("minter-info"), minter.toBuffer()],
+    [Buffer.from("supply-checkpoints"), encodeAssetId(assetId)],
     TMEWC_PROGRAM_ID
   )[0];
 }
 
-export function getGuardianInfoPDA(guardian: PublicKey): PublicKey {
+export function getEthAuthorityPDA(assetId = TMEWC_ASSET_ID): PublicKey {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("guardian-info"), guardian.toBuffer()],
+    [Buffer.from("eth-authority"), encodeAssetId(assetId)],
     TMEWC_PROGRAM_ID
   )[0];
 }
 
-export function getGuardiansPDA(): PublicKey {
+export function getReserveAttestationPDA(
+  assetId = TMEWC_ASSET_ID
+): PublicKey {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("guardians")],
+    [Buffer.from("reserve-attestation"), encodeAssetId(assetId)],
     TMEWC_PROGRAM_ID
   )[0];
 }
 
-export function getMintersPDA(): PublicKey {
+export function getGovernanceAuthorityPDA(
+  assetId = TMEWC_ASSET_ID
+): PublicKey {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("minters")],
+    [Buffer.from("governance-authority"), encodeAssetId(assetId)],
     TMEWC_PROGRAM_ID
   )[0];
 }
 
-export async function getConfigData() {
+export function getGovernanceReceiptPDA(
+  emitterChain: number,
+  emitterAddress: Buffer,
+  sequence: bigint
+): PublicKey {
+  const encodedChain = Buffer.alloc(2);
+  encodedChain.writeUInt16BE(emitterChain);
+  const encodedSequence = Buffer.alloc(8);
+  encodedSequence.writeBigUInt64BE(sequence);
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("governance-receipt"),
+      encodedChain,
+      emitterAddress,
+      encodedSequence,
+    ],
+    TMEWC_PROGRAM_ID
+  )[0];
+}
+
+export function getGovernancePayload(ix: TransactionInstruction): Buffer {
+  return governanceMessage(TMEWC_GOVERNANCE_MODULE, ix);
+}
+
+export async function getConfigData(assetId = TMEWC_ASSET_ID) {
   const program = workspace.Tmewc as Program<Tmewc>;
-  const config = getConfigPDA();
+  const config = getConfigPDA(assetId);
   return program.account.config.fetch(config);
 }
 
+export async function getMintRequestData(id: BN) {
+  const program = workspace.Tmewc as Program<Tmewc>;
+  const mintRequest = getMintRequestPDA(id);
+  return program.account.mintRequest.fetchNullable(mintRequest);
+}
+
+export async function getAuditLogData() {
+  const program = workspace.Tmewc as Program<Tmewc>;
+  const auditLog = getAuditLogPDA();
+  return program.account.auditLog.fetch(auditLog);
+}
+
+export async function getSupplyCheckpointsData() {
+  const program = workspace.Tmewc as Program<Tmewc>;
+  const supplyCheckpoints = getSupplyCheckpointsPDA();
+  return program.account.supplyCheckpoints.fetch(supplyCheckpoints);
+}
+
+export async function getReserveAttestationData() {
+  const program = workspace.Tmewc as Program<Tmewc>;
+  const reserveAttestation = getReserveAttestationPDA();
+  return program.account.reserveAttestation.fetch(reserveAttestation);
+}
+
 export async function checkConfig(expected: {
   authority: PublicKey;
   numMinters: number;
@@ -92,7 +259,9 @@ export async function checkConfig(expected: {
 
   const mintState = await getMint(
     program.provider.connection,
-    configState.mint
+    configState.mint,
+    undefined,
+    TMEWC_TOKEN_PROGRAM
   );
   expect(mintState.supply).to.equal(supply);
 
@@ -129,6 +298,7 @@ export async function checkGuardianInfo(guardian: PublicKey) {
 
 type AddGuardianContext = {
   config?: PublicKey;
+  auditLog?: PublicKey;
   authority: PublicKey;
   guardians?: PublicKey;
   guardianInfo?: PublicKey;
@@ -140,11 +310,22 @@ export async function addGuardianIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tmewc as Program<Tmewc>;
 
-  let { config, authority, guardians, guardianInfo, guardian } = accounts;
+  let {
+    config,
+    auditLog,
+    authority,
+    guardians,
+    guardianInfo,
+    guardian,
+  } = accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
   if (guardians === undefined) {
     guardians = getGuardiansPDA();
   }
@@ -157,16 +338,20 @@ export async function addGuardianIx(
     .addGuardian()
     .accounts({
       config,
+      auditLog,
       authority,
       guardians,
       guardianInfo,
       guardian,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
 
 type AddMinterContext = {
   config?: PublicKey;
+  auditLog?: PublicKey;
   authority: PublicKey;
   minters?: PublicKey;
   minterInfo?: PublicKey;
@@ -178,11 +363,15 @@ export async function addMinterIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tmewc as Program<Tmewc>;
 
-  let { config, authority, minters, minterInfo, minter } = accounts;
+  let { config, auditLog, authority, minters, minterInfo, minter } = accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
   if (minters === undefined) {
     minters = getMintersPDA();
   }
@@ -195,16 +384,20 @@ export async function addMinterIx(
     .addMinter()
     .accounts({
       config,
+      auditLog,
       authority,
       minters,
       minterInfo,
       minter,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
 
 type CancelAuthorityChange = {
   config?: PublicKey;
+  auditLog?: PublicKey;
   authority: PublicKey;
 };
 
@@ -213,22 +406,30 @@ export async function cancelAuthorityChangeIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tmewc as Program<Tmewc>;
 
-  let { config, authority } = accounts;
+  let { config, auditLog, authority } = accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
   return program.methods
     .cancelAuthorityChange()
     .accounts({
       config,
+      auditLog,
       authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
 
 type ChangeAuthorityContext = {
   config?: PublicKey;
+  auditLog?: PublicKey;
   authority: PublicKey;
   newAuthority: PublicKey;
 };
@@ -238,17 +439,24 @@ export async function changeAuthorityIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tmewc as Program<Tmewc>;
 
-  let { config, authority, newAuthority } = accounts;
+  let { config, auditLog, authority, newAuthority } = accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
   return program.methods
     .changeAuthority()
     .accounts({
       config,
+      auditLog,
       authority,
       newAuthority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
@@ -256,116 +464,189 @@ export async function changeAuthorityIx(
 type InitializeContext = {
   mint?: PublicKey;
   config?: PublicKey;
+  auditLog?: PublicKey;
   guardians?: PublicKey;
   minters?: PublicKey;
+  tmewcConfig?: PublicKey | null;
   authority: PublicKey;
   tmewcMetadata?: PublicKey;
   mplTokenMetadataProgram?: PublicKey;
+  tokenProgram?: PublicKey;
+};
+
+type InitializeArgs = {
+  assetId: number;
+  name: string;
+  symbol: string;
 };
 
 export async function initializeIx(
-  accounts: InitializeContext
+  accounts: InitializeContext,
+  args: InitializeArgs = {
+    assetId: TMEWC_ASSET_ID,
+    name: "tMEWC",
+    symbol: "tMEWC",
+  }
 ): Promise<TransactionInstruction> {
   const program = workspace.Tmewc as Program<Tmewc>;
 
   let {
     mint,
     config,
+    auditLog,
     guardians,
     minters,
+    tmewcConfig,
     authority,
     tmewcMetadata,
     mplTokenMetadataProgram,
+    tokenProgram,
   } = accounts;
 
   if (mint === undefined) {
-    mint = getMintPDA();
+    mint = getMintPDA(args.assetId);
   }
 
   if (config === undefined) {
-    config = getConfigPDA();
+    config = getConfigPDA(args.assetId);
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA(args.assetId);
   }
 
   if (guardians === undefined) {
-    guardians = getGuardiansPDA();
+    guardians = getGuardiansPDA(args.assetId);
   }
 
   if (minters === undefined) {
-    minters = getMintersPDA();
+    minters = getMintersPDA(args.assetId);
+  }
+
+  if (tmewcConfig === undefined) {
+    tmewcConfig =
+      args.assetId === TMEWC_ASSET_ID ? null : getConfigPDA(TMEWC_ASSET_ID);
   }
 
   if (tmewcMetadata === undefined) {
-    tmewcMetadata = getTmewcMetadataPDA();
+    tmewcMetadata = getTmewcMetadataPDA(args.assetId);
   }
 
   if (mplTokenMetadataProgram === undefined) {
     mplTokenMetadataProgram = METADATA_PROGRAM_ID;
   }
 
+  if (tokenProgram === undefined) {
+    tokenProgram = TMEWC_TOKEN_PROGRAM;
+  }
+
   return program.methods
-    .initialize()
+    .initialize(args)
     .accounts({
       mint,
       config,
+      auditLog,
       guardians,
       minters,
+      tmewcConfig,
       authority,
       tmewcMetadata,
       mplTokenMetadataProgram,
+      tokenProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
 
-type PauseContext = {
+type MigrateConfigContext = {
   config?: PublicKey;
-  guardianInfo?: PublicKey;
-  guardian: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
 };
 
-export async function pauseIx(
-  accounts: PauseContext
+export async function migrateConfigIx(
+  accounts: MigrateConfigContext
 ): Promise<TransactionInstruction> {
   const program = workspace.Tmewc as Program<Tmewc>;
 
-  let { config, guardianInfo, guardian } = accounts;
+  let { config, auditLog, authority } = accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
-  if (guardianInfo === undefined) {
-    guardianInfo = getGuardianInfoPDA(guardian);
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
   }
 
   return program.methods
-    .pause()
+    .migrateConfig()
     .accounts({
       config,
-      guardianInfo,
-      guardian,
+      auditLog,
+      authority,
     })
     .instruction();
 }
 
-type RemoveGuardianContext = {
+type MigrateMinterInfoContext = {
   config?: PublicKey;
+  auditLog?: PublicKey;
+  minterInfo?: PublicKey;
   authority: PublicKey;
-  guardians?: PublicKey;
+  minter: PublicKey;
+};
+
+export async function migrateMinterInfoIx(
+  accounts: MigrateMinterInfoContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, minterInfo, authority, minter } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .migrateMinterInfo()
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      minterInfo,
+      minter,
+    })
+    .instruction();
+}
+
+type MigrateGuardianInfoContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
   guardianInfo?: PublicKey;
+  authority: PublicKey;
   guardian: PublicKey;
 };
 
-export async function removeGuardianIx(
-  accounts: RemoveGuardianContext
+export async function migrateGuardianInfoIx(
+  accounts: MigrateGuardianInfoContext
 ): Promise<TransactionInstruction> {
   const program = workspace.Tmewc as Program<Tmewc>;
 
-  let { config, authority, guardians, guardianInfo, guardian } = accounts;
+  let { config, auditLog, guardianInfo, authority, guardian } = accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
-  if (guardians === undefined) {
-    guardians = getGuardiansPDA();
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
   }
 
   if (guardianInfo === undefined) {
@@ -373,138 +654,1485 @@ export async function removeGuardianIx(
   }
 
   return program.methods
-    .removeGuardian()
+    .migrateGuardianInfo()
     .accounts({
       config,
+      auditLog,
       authority,
-      guardians,
       guardianInfo,
       guardian,
     })
     .instruction();
 }
 
-type RemoveMinterContext = {
+type PauseContext = {
   config?: PublicKey;
-  authority: PublicKey;
-  minters?: PublicKey;
-  minterInfo?: PublicKey;
-  minter: PublicKey;
+  auditLog?: PublicKey;
+  guardianInfo?: PublicKey | null;
+  roleAssignment?: PublicKey | null;
+  guardian: PublicKey;
 };
 
-export async function removeMinterIx(
-  accounts: RemoveMinterContext
+export async function pauseIx(
+  accounts: PauseContext
 ): Promise<TransactionInstruction> {
   const program = workspace.Tmewc as Program<Tmewc>;
 
-  let { config, authority, minters, minterInfo, minter } = accounts;
+  let { config, auditLog, guardianInfo, roleAssignment, guardian } = accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
-  if (minters === undefined) {
-    minters = getMintersPDA();
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
   }
 
-  if (minterInfo === undefined) {
-    minterInfo = getMinterInfoPDA(minter);
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
+  }
+
+  if (roleAssignment === undefined) {
+    roleAssignment = null;
   }
 
   return program.methods
-    .removeMinter()
+    .pause()
     .accounts({
       config,
-      authority,
-      minters,
-      minterInfo,
-      minter,
+      auditLog,
+      guardianInfo,
+      roleAssignment,
+      guardian,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
 
-type TakeAuthorityContext = {
+type PauseWithSignatureContext = {
   config?: PublicKey;
-  pendingAuthority: PublicKey;
+  auditLog?: PublicKey;
+  guardianInfo?: PublicKey;
 };
-
-export async function takeAuthorityIx(
-  accounts: TakeAuthorityContext
-): Promise<TransactionInstruction> {
+// Returns the ed25519 signature verification followed by the pause
+// instruction, which have to be the first two instructions of the transaction.
+// The nonce defaults to the guardian's current pause nonce.
+export async function pauseWithSignatureIxs(
+  accounts: PauseWithSignatureContext,
+  guardian: Keypair,
+  expiry: number,
+  nonce?: bigint
+): Promise<TransactionInstruction[]> {
   const program = workspace.Tmewc as Program<Tmewc>;
 
-  let { config, pendingAuthority } = accounts;
+  let { config, auditLog, guardianInfo } = accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
-  return program.methods
-    .takeAuthority()
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian.publicKey);
+  }
+
+  const { epoch, pauseNonce } = await program.account.guardianInfo.fetch(
+    guardianInfo
+  );
+  if (nonce === undefined) {
+    nonce = BigInt(pauseNonce.toString());
+  }
+
+  const encodedNonce = Buffer.alloc(8);
+  encodedNonce.writeBigUInt64LE(nonce);
+  const encodedExpiry = Buffer.alloc(8);
+  encodedExpiry.writeBigInt64LE(BigInt(expiry));
+  const verifyIx = Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: guardian.secretKey,
+    message: Buffer.concat([
+      Buffer.from(TMEWC_PAUSE_SIGNATURE_DOMAIN),
+      Buffer.from(CLUSTER),
+      TMEWC_PROGRAM_ID.toBuffer(),
+      config.toBuffer(),
+      epoch.toArrayLike(Buffer, "le", 8),
+      encodedNonce,
+      encodedExpiry,
+    ]),
+  });
+
+  const pauseIx = await program.methods
+    .pauseWithSignature(new BN(nonce.toString()), new BN(expiry.toString()))
     .accounts({
       config,
-      pendingAuthority,
+      auditLog,
+      guardianInfo,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
+
+  return [verifyIx, pauseIx];
 }
 
-type UnpauseContext = {
+type RemoveGuardianContext = {
   config?: PublicKey;
+  auditLog?: PublicKey;
   authority: PublicKey;
+  guardians?: PublicKey;
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
 };
 
-export async function unpauseIx(
-  accounts: UnpauseContext
+export async function removeGuardianIx(
+  accounts: RemoveGuardianContext
 ): Promise<TransactionInstruction> {
   const program = workspace.Tmewc as Program<Tmewc>;
 
-  let { config, authority } = accounts;
+  let {
+    config,
+    auditLog,
+    authority,
+    guardians,
+    guardianInfo,
+    guardian,
+  } = accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (guardians === undefined) {
+    guardians = getGuardiansPDA();
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
+  }
+
   return program.methods
-    .unpause()
+    .removeGuardian()
     .accounts({
       config,
+      auditLog,
       authority,
+      guardians,
+      guardianInfo,
+      guardian,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
 
-type MintContext = {
-  mint?: PublicKey;
+type RenounceGuardianContext = {
   config?: PublicKey;
-  minterInfo?: PublicKey;
-  minter: PublicKey;
-  recipientToken: PublicKey;
+  auditLog?: PublicKey;
+  authority?: PublicKey;
+  guardians?: PublicKey;
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
 };
 
-export async function mintIx(
-  accounts: MintContext,
-  amount: BN
+export async function renounceGuardianIx(
+  accounts: RenounceGuardianContext
 ): Promise<TransactionInstruction> {
   const program = workspace.Tmewc as Program<Tmewc>;
 
-  let { mint, config, minterInfo, minter, recipientToken } = accounts;
-  if (mint === undefined) {
-    mint = getMintPDA();
-  }
-
+  let { config, auditLog, authority, guardians, guardianInfo, guardian } =
+    accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
-  if (minterInfo === undefined) {
-    minterInfo = getMinterInfoPDA(minter);
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  // The authority gets the rent back.
+  if (authority === undefined) {
+    ({ authority } = await program.account.config.fetch(config));
+  }
+
+  if (guardians === undefined) {
+    guardians = getGuardiansPDA();
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
   }
 
   return program.methods
-    .mint(amount)
+    .renounceGuardian()
     .accounts({
-      mint,
       config,
-      minterInfo,
-      minter,
-      recipientToken,
+      auditLog,
+      authority,
+      guardians,
+      guardianInfo,
+      guardian,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type RemoveMinterContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+  minters?: PublicKey;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+};
+
+export async function removeMinterIx(
+  accounts: RemoveMinterContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority, minters, minterInfo, minter } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (minters === undefined) {
+    minters = getMintersPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .removeMinter()
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      minters,
+      minterInfo,
+      minter,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type RenounceMinterContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority?: PublicKey;
+  minters?: PublicKey;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+};
+
+export async function renounceMinterIx(
+  accounts: RenounceMinterContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority, minters, minterInfo, minter } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  // The authority gets the rent back.
+  if (authority === undefined) {
+    ({ authority } = await program.account.config.fetch(config));
+  }
+
+  if (minters === undefined) {
+    minters = getMintersPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .renounceMinter()
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      minters,
+      minterInfo,
+      minter,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type TakeAuthorityContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  pendingAuthority: PublicKey;
+};
+
+export async function takeAuthorityIx(
+  accounts: TakeAuthorityContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, pendingAuthority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  return program.methods
+    .takeAuthority()
+    .accounts({
+      config,
+      auditLog,
+      pendingAuthority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type UnpauseContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey | null;
+};
+
+export async function unpauseIx(
+  accounts: UnpauseContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority, roleAssignment } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (roleAssignment === undefined) {
+    roleAssignment = null;
+  }
+
+  return program.methods
+    .unpause()
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      roleAssignment,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type MintContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  minterInfo?: PublicKey | null;
+  roleAssignment?: PublicKey | null;
+  reserveAttestation?: PublicKey | null;
+  minter: PublicKey;
+  recipientToken: PublicKey;
+  tokenProgram?: PublicKey;
+};
+
+export async function mintIx(
+  accounts: MintContext,
+  amount: BN
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let {
+    mint,
+    config,
+    minterInfo,
+    roleAssignment,
+    reserveAttestation,
+    minter,
+    recipientToken,
+    tokenProgram,
+  } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  if (roleAssignment === undefined) {
+    roleAssignment = null;
+  }
+
+  if (reserveAttestation === undefined) {
+    reserveAttestation = null;
+  }
+
+  if (tokenProgram === undefined) {
+    tokenProgram = TMEWC_TOKEN_PROGRAM;
+  }
+
+  return program.methods
+    .mint(amount)
+    .accounts({
+      mint,
+      config,
+      minterInfo,
+      roleAssignment,
+      reserveAttestation,
+      minter,
+      recipientToken,
+      tokenProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type UpdateAnomalyGuardContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey | null;
+};
+
+type UpdateAnomalyGuardArgs = {
+  maxMintAmount: BN;
+  mintWindowLimit: BN;
+  mintWindowDuration: number;
+};
+
+export async function updateAnomalyGuardIx(
+  accounts: UpdateAnomalyGuardContext,
+  args: UpdateAnomalyGuardArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority, roleAssignment } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (roleAssignment === undefined) {
+    roleAssignment = null;
+  }
+
+  return program.methods
+    .updateAnomalyGuard(args)
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      roleAssignment,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type CheckpointContext = {
+  config?: PublicKey;
+  mint?: PublicKey;
+  gatewayCustodian?: PublicKey | null;
+  supplyCheckpoints?: PublicKey;
+  payer: PublicKey;
+};
+
+export async function checkpointIx(
+  accounts: CheckpointContext,
+  gatewayMintedAmount: BN = new BN(0)
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, mint, gatewayCustodian, supplyCheckpoints, payer } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (gatewayCustodian === undefined) {
+    gatewayCustodian = null;
+  }
+
+  if (supplyCheckpoints === undefined) {
+    supplyCheckpoints = getSupplyCheckpointsPDA();
+  }
+
+  return program.methods
+    .checkpoint(gatewayMintedAmount)
+    .accounts({
+      config,
+      mint,
+      gatewayCustodian,
+      supplyCheckpoints,
+      payer,
+    })
+    .instruction();
+}
+
+type UpdateMinterModeContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+};
+
+export async function updateMinterModeIx(
+  accounts: UpdateMinterModeContext,
+  optimistic: boolean
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority, minterInfo, minter } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .updateMinterMode(optimistic)
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      minterInfo,
+      minter,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type UpdateOptimisticMintingDelayContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey | null;
+};
+
+export async function updateOptimisticMintingDelayIx(
+  accounts: UpdateOptimisticMintingDelayContext,
+  delay: number
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority, roleAssignment } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (roleAssignment === undefined) {
+    roleAssignment = null;
+  }
+
+  return program.methods
+    .updateOptimisticMintingDelay(delay)
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      roleAssignment,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type RequestMintContext = {
+  config?: PublicKey;
+  mint?: PublicKey;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+  mintRequest: PublicKey;
+  recipientToken: PublicKey;
+};
+
+export async function requestMintIx(
+  accounts: RequestMintContext,
+  amount: BN
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, mint, minterInfo, minter, mintRequest, recipientToken } =
+    accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .requestMint(amount)
+    .accounts({
+      config,
+      mint,
+      minterInfo,
+      minter,
+      mintRequest,
+      recipientToken,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type CancelMintRequestContext = {
+  mintRequest: PublicKey;
+  minter: PublicKey;
+  auditLog?: PublicKey;
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
+};
+
+export async function cancelMintRequestIx(
+  accounts: CancelMintRequestContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { mintRequest, minter, auditLog, guardianInfo, guardian } = accounts;
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
+  }
+
+  return program.methods
+    .cancelMintRequest()
+    .accounts({
+      mintRequest,
+      minter,
+      auditLog,
+      guardianInfo,
+      guardian,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type FinalizeMintContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  mintRequest: PublicKey;
+  minterInfo?: PublicKey;
+  reserveAttestation?: PublicKey | null;
+  minter: PublicKey;
+  recipientToken: PublicKey;
+  tokenProgram?: PublicKey;
+};
+
+export async function finalizeMintIx(
+  accounts: FinalizeMintContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let {
+    mint,
+    config,
+    mintRequest,
+    minterInfo,
+    reserveAttestation,
+    minter,
+    recipientToken,
+    tokenProgram,
+  } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  if (reserveAttestation === undefined) {
+    reserveAttestation = null;
+  }
+
+  if (tokenProgram === undefined) {
+    tokenProgram = TMEWC_TOKEN_PROGRAM;
+  }
+
+  return program.methods
+    .finalizeMint()
+    .accounts({
+      mint,
+      config,
+      mintRequest,
+      minterInfo,
+      reserveAttestation,
+      minter,
+      recipientToken,
+      tokenProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type UpdateMetadataContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey | null;
+  tmewcMetadata?: PublicKey;
+  mplTokenMetadataProgram?: PublicKey;
+};
+
+type UpdateMetadataArgs = {
+  name: string;
+  symbol: string;
+  uri: string;
+};
+
+export async function updateMetadataIx(
+  accounts: UpdateMetadataContext,
+  args: UpdateMetadataArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let {
+    config,
+    auditLog,
+    authority,
+    roleAssignment,
+    tmewcMetadata,
+    mplTokenMetadataProgram,
+  } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (roleAssignment === undefined) {
+    roleAssignment = null;
+  }
+
+  if (tmewcMetadata === undefined) {
+    tmewcMetadata = getTmewcMetadataPDA();
+  }
+
+  if (mplTokenMetadataProgram === undefined) {
+    mplTokenMetadataProgram = METADATA_PROGRAM_ID;
+  }
+
+  return program.methods
+    .updateMetadata(args)
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      roleAssignment,
+      tmewcMetadata,
+      mplTokenMetadataProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type FreezeTokenAccountContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey | null;
+  tokenAccount: PublicKey;
+  tokenProgram?: PublicKey;
+};
+
+export async function freezeTokenAccountIx(
+  accounts: FreezeTokenAccountContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let {
+    mint,
+    config,
+    auditLog,
+    authority,
+    roleAssignment,
+    tokenAccount,
+    tokenProgram,
+  } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (roleAssignment === undefined) {
+    roleAssignment = null;
+  }
+
+  if (tokenProgram === undefined) {
+    tokenProgram = TMEWC_TOKEN_PROGRAM;
+  }
+
+  return program.methods
+    .freezeTokenAccount()
+    .accounts({
+      mint,
+      config,
+      auditLog,
+      authority,
+      roleAssignment,
+      tokenAccount,
+      tokenProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type ThawTokenAccountContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey | null;
+  tokenAccount: PublicKey;
+  tokenProgram?: PublicKey;
+};
+
+export async function thawTokenAccountIx(
+  accounts: ThawTokenAccountContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let {
+    mint,
+    config,
+    auditLog,
+    authority,
+    roleAssignment,
+    tokenAccount,
+    tokenProgram,
+  } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (roleAssignment === undefined) {
+    roleAssignment = null;
+  }
+
+  if (tokenProgram === undefined) {
+    tokenProgram = TMEWC_TOKEN_PROGRAM;
+  }
+
+  return program.methods
+    .thawTokenAccount()
+    .accounts({
+      mint,
+      config,
+      auditLog,
+      authority,
+      roleAssignment,
+      tokenAccount,
+      tokenProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type ScheduleMintAuthorityTransferContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+};
+export async function scheduleMintAuthorityTransferIx(
+  accounts: ScheduleMintAuthorityTransferContext,
+  successorAuthority: PublicKey
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  return program.methods
+    .scheduleMintAuthorityTransfer(successorAuthority)
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type CancelMintAuthorityTransferContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function cancelMintAuthorityTransferIx(
+  accounts: CancelMintAuthorityTransferContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  return program.methods
+    .cancelMintAuthorityTransfer()
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type ExecuteMintAuthorityTransferContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+  tmewcMetadata?: PublicKey;
+  mplTokenMetadataProgram?: PublicKey;
+  tokenProgram?: PublicKey;
+};
+export async function executeMintAuthorityTransferIx(
+  accounts: ExecuteMintAuthorityTransferContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let {
+    mint,
+    config,
+    auditLog,
+    authority,
+    tmewcMetadata,
+    mplTokenMetadataProgram,
+    tokenProgram,
+  } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (tmewcMetadata === undefined) {
+    tmewcMetadata = getTmewcMetadataPDA();
+  }
+
+  if (mplTokenMetadataProgram === undefined) {
+    mplTokenMetadataProgram = METADATA_PROGRAM_ID;
+  }
+
+  if (tokenProgram === undefined) {
+    tokenProgram = TMEWC_TOKEN_PROGRAM;
+  }
+
+  return program.methods
+    .executeMintAuthorityTransfer()
+    .accounts({
+      mint,
+      config,
+      auditLog,
+      authority,
+      tmewcMetadata,
+      mplTokenMetadataProgram,
+      tokenProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type SetEthAuthorityContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+};
+export async function setEthAuthorityIx(
+  accounts: SetEthAuthorityContext,
+  ethAuthority: Buffer | null
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  return program.methods
+    .setEthAuthority(ethAuthority === null ? null : Array.from(ethAuthority))
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type ExecuteAsEthAuthorityContext = {
+  config?: PublicKey;
+  ethAuthority?: PublicKey;
+};
+// Returns the secp256k1 signature verification followed by the execute
+// instruction, which have to be the first two instructions of the transaction.
+export async function executeAsEthAuthorityIxs(
+  accounts: ExecuteAsEthAuthorityContext,
+  ix: TransactionInstruction,
+  ethPrivateKey: Buffer
+): Promise<TransactionInstruction[]> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, ethAuthority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (ethAuthority === undefined) {
+    ethAuthority = getEthAuthorityPDA();
+  }
+
+  const { ethAuthorityNonce } = await program.account.config.fetch(config);
+  const verifyIx = ethAuthorizationIx(
+    TMEWC_ETH_AUTHORITY_DOMAIN,
+    config,
+    ethAuthorityNonce,
+    ix,
+    ethPrivateKey
+  );
+
+  const executeIx = await program.methods
+    .executeAsEthAuthority(ix.data)
+    .accounts({
+      config,
+      ethAuthority,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .remainingAccounts(
+      ix.keys.map((key) => ({
+        pubkey: key.pubkey,
+        isSigner: key.isSigner && !key.pubkey.equals(ethAuthority),
+        isWritable: key.isWritable,
+      }))
+    )
+    .instruction();
+
+  return [verifyIx, executeIx];
+}
+
+type UpdateGovernanceEmitterContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+};
+export async function updateGovernanceEmitterIx(
+  accounts: UpdateGovernanceEmitterContext,
+  governanceEmitter: Buffer
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  return program.methods
+    .updateGovernanceEmitter(Array.from(governanceEmitter))
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type ExecuteGovernanceVaaContext = {
+  payer: PublicKey;
+  config?: PublicKey;
+  postedVaa?: PublicKey;
+  governanceReceipt?: PublicKey;
+  governanceAuthority?: PublicKey;
+};
+export async function executeGovernanceVaaIx(
+  accounts: ExecuteGovernanceVaaContext,
+  signedVaa: Buffer,
+  ix: TransactionInstruction
+): Promise<TransactionInstruction> {
+  const parsed = parseVaa(signedVaa);
+
+  const program = workspace.Tmewc as Program<Tmewc>;
+  let { payer, config, postedVaa, governanceReceipt, governanceAuthority } =
+    accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (postedVaa === undefined) {
+    postedVaa = coreBridge.derivePostedVaaKey(
+      CORE_BRIDGE_PROGRAM_ID,
+      parsed.hash
+    );
+  }
+
+  if (governanceReceipt === undefined) {
+    governanceReceipt = getGovernanceReceiptPDA(
+      parsed.emitterChain,
+      parsed.emitterAddress,
+      parsed.sequence
+    );
+  }
+
+  if (governanceAuthority === undefined) {
+    governanceAuthority = getGovernanceAuthorityPDA();
+  }
+
+  return program.methods
+    .executeGovernanceVaa(Array.from(parsed.hash))
+    .accounts({
+      payer,
+      config,
+      postedVaa,
+      governanceReceipt,
+      governanceAuthority,
+      coreBridgeProgram: CORE_BRIDGE_PROGRAM_ID,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .remainingAccounts(
+      ix.keys.map((key) => ({
+        pubkey: key.pubkey,
+        isSigner: key.isSigner && !key.pubkey.equals(governanceAuthority),
+        isWritable: key.isWritable,
+      }))
+    )
+    .instruction();
+}
+
+type RoleContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+  roleAssignment?: PublicKey;
+  minters?: PublicKey | null;
+  member: PublicKey;
+};
+export async function grantRoleIx(
+  accounts: RoleContext,
+  role: Role
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority, roleAssignment, minters, member } =
+    accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (roleAssignment === undefined) {
+    roleAssignment = getRoleAssignmentPDA(role, member);
+  }
+
+  // Only the minter role is listed in the minters account.
+  if (minters === undefined) {
+    minters = "minter" in role ? getMintersPDA() : null;
+  }
+
+  return program.methods
+    .grantRole(role)
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      roleAssignment,
+      minters,
+      member,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+export async function revokeRoleIx(
+  accounts: RoleContext,
+  role: Role
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority, roleAssignment, minters, member } =
+    accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (roleAssignment === undefined) {
+    roleAssignment = getRoleAssignmentPDA(role, member);
+  }
+
+  // Only the minter role is listed in the minters account.
+  if (minters === undefined) {
+    minters = "minter" in role ? getMintersPDA() : null;
+  }
+
+  return program.methods
+    .revokeRole(role)
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      roleAssignment,
+      minters,
+      member,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type RenounceRoleContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  authority?: PublicKey;
+  roleAssignment?: PublicKey;
+  minters?: PublicKey | null;
+  member: PublicKey;
+};
+
+export async function renounceRoleIx(
+  accounts: RenounceRoleContext,
+  role: Role
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, authority, roleAssignment, minters, member } =
+    accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  // The authority gets the rent back.
+  if (authority === undefined) {
+    ({ authority } = await program.account.config.fetch(config));
+  }
+
+  if (roleAssignment === undefined) {
+    roleAssignment = getRoleAssignmentPDA(role, member);
+  }
+
+  // Only the minter role is listed in the minters account.
+  if (minters === undefined) {
+    minters = "minter" in role ? getMintersPDA() : null;
+  }
+
+  return program.methods
+    .renounceRole(role)
+    .accounts({
+      config,
+      auditLog,
+      authority,
+      roleAssignment,
+      minters,
+      member,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type UpdateReserveAttesterContext = {
+  config?: PublicKey;
+  auditLog?: PublicKey;
+  reserveAttestation?: PublicKey;
+  authority: PublicKey;
+};
+export async function updateReserveAttesterIx(
+  accounts: UpdateReserveAttesterContext,
+  reserveAttester: PublicKey | null,
+  maxReserveStaleness: number
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  let { config, auditLog, reserveAttestation, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (reserveAttestation === undefined) {
+    reserveAttestation = getReserveAttestationPDA();
+  }
+
+  return program.methods
+    .updateReserveAttester(reserveAttester, maxReserveStaleness)
+    .accounts({
+      config,
+      auditLog,
+      reserveAttestation,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type AttestReserveContext = {
+  config?: PublicKey;
+  reserveAttestation?: PublicKey;
+  attester: PublicKey;
+};
+export async function attestReserveIx(
+  accounts: AttestReserveContext,
+  reserve: bigint,
+  observedAt?: number
+): Promise<TransactionInstruction> {
+  const program = workspace.Tmewc as Program<Tmewc>;
+
+  // Default to the cluster's clock, which the program checks against.
+  if (observedAt === undefined) {
+    const connection = program.provider.connection;
+    observedAt = await connection.getBlockTime(await connection.getSlot());
+  }
+
+  let { config, reserveAttestation, attester } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (reserveAttestation === undefined) {
+    reserveAttestation = getReserveAttestationPDA();
+  }
+
+  return program.methods
+    .attestReserve(new BN(reserve.toString()), new BN(observedAt))
+    .accounts({
+      config,
+      reserveAttestation,
+      attester,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
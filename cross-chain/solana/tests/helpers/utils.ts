@@ -4,14 +4,16 @@ import {
   tryNativeToHexString,
 } from "@certusone/wormhole-sdk";
 import {
+  MockEmitter,
   MockEthereumTokenBridge,
   MockGuardians,
 } from "@certusone/wormhole-sdk/lib/cjs/mock";
 import { NodeWallet } from "@certusone/wormhole-sdk/lib/cjs/solana";
 import * as coreBridge from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
-import { Program, web3, workspace } from "@coral-xyz/anchor";
+import { BN, Program, utils, web3, workspace } from "@coral-xyz/anchor";
 import {
   Account,
+  TOKEN_PROGRAM_ID,
   TokenAccountNotFoundError,
   createAssociatedTokenAccountIdempotentInstruction,
   getAccount,
@@ -20,14 +22,18 @@ import {
 import {
   Keypair,
   PublicKey,
+  Secp256k1Program,
   SystemProgram,
   Transaction,
   TransactionInstruction,
   sendAndConfirmTransaction,
 } from "@solana/web3.js";
 import { assert, expect } from "chai";
+import { ethers } from "ethers";
+import { Tmewc } from "../../target/types/tmewc";
 import { WormholeGateway } from "../../target/types/wormhole_gateway"; // This is only here to hack a connection.
 import {
+  CLUSTER,
   CORE_BRIDGE_PROGRAM_ID,
   ETHEREUM_TMEWC_ADDRESS,
   GUARDIAN_DEVNET_PRIVATE_KEYS,
@@ -70,21 +76,30 @@ export async function generatePayer(funder: Keypair, lamports?: number) {
 export async function getOrCreateAta(
   payer: Keypair,
   mint: PublicKey,
-  owner: PublicKey
+  owner: PublicKey,
+  tokenProgram = TOKEN_PROGRAM_ID
 ) {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   const connection = program.provider.connection;
 
-  const token = getAssociatedTokenAddressSync(mint, owner);
-  const tokenData: Account = await getAccount(connection, token).catch(
-    (err) => {
-      if (err instanceof TokenAccountNotFoundError) {
-        return null;
-      } else {
-        throw err;
-      }
-    }
+  const token = getAssociatedTokenAddressSync(
+    mint,
+    owner,
+    false,
+    tokenProgram
   );
+  const tokenData: Account = await getAccount(
+    connection,
+    token,
+    undefined,
+    tokenProgram
+  ).catch((err) => {
+    if (err instanceof TokenAccountNotFoundError) {
+      return null;
+    } else {
+      throw err;
+    }
+  });
 
   if (tokenData === null) {
     await web3.sendAndConfirmTransaction(
@@ -94,7 +109,8 @@ export async function getOrCreateAta(
           payer.publicKey,
           token,
           owner,
-          mint
+          mint,
+          tokenProgram
         )
       ),
       [payer]
@@ -104,11 +120,17 @@ export async function getOrCreateAta(
   return token;
 }
 
-export async function getTokenBalance(token: PublicKey): Promise<bigint> {
+export async function getTokenBalance(
+  token: PublicKey,
+  tokenProgram = TOKEN_PROGRAM_ID
+): Promise<bigint> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
-  return getAccount(program.provider.connection, token).then(
-    (account) => account.amount
-  );
+  return getAccount(
+    program.provider.connection,
+    token,
+    undefined,
+    tokenProgram
+  ).then((account) => account.amount);
 }
 
 export async function preloadWrappedTmewc(
@@ -224,9 +246,9 @@ export async function ethereumGatewaySendTmewc(
 export async function expectIxSuccess(
   ixes: TransactionInstruction[],
   signers: Keypair[]
-) {
+): Promise<string> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
-  await sendAndConfirmTransaction(
+  return sendAndConfirmTransaction(
     program.provider.connection,
     new Transaction().add(...ixes),
     signers
@@ -238,6 +260,64 @@ export async function expectIxSuccess(
   });
 }
 
+export async function simulateIxReturnData(
+  ixes: TransactionInstruction[],
+  signers: Keypair[]
+): Promise<Buffer> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  const { value } = await program.provider.connection.simulateTransaction(
+    new Transaction().add(...ixes),
+    signers
+  );
+  expect(value.err).is.null;
+  return Buffer.from(value.returnData.data[0], "base64");
+}
+
+// Prefix of the self-CPI instructions that carry Anchor events.
+const EVENT_IX_TAG = Buffer.from("e445a52e51cb9a1d", "hex");
+
+// Decode the events both programs emitted through self-CPI in a transaction.
+export async function getTransactionEvents(
+  txSig: string
+): Promise<{ name: string; data: any }[]> {
+  const programs = [
+    workspace.Tmewc as Program<Tmewc>,
+    workspace.WormholeGateway as Program<WormholeGateway>,
+  ];
+  const connection = programs[0].provider.connection;
+
+  await connection.confirmTransaction(txSig, "confirmed");
+  const tx = await connection.getTransaction(txSig, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const accountKeys = tx.transaction.message.getAccountKeys();
+
+  const events = [];
+  for (const { instructions } of tx.meta.innerInstructions) {
+    for (const ix of instructions) {
+      const programId = accountKeys.get(ix.programIdIndex);
+      const program = programs.find((p) => p.programId.equals(programId));
+      const data = Buffer.from(utils.bytes.bs58.decode(ix.data));
+      if (program !== undefined && data.subarray(0, 8).equals(EVENT_IX_TAG)) {
+        events.push(
+          program.coder.events.decode(data.subarray(8).toString("base64"))
+        );
+      }
+    }
+  }
+
+  return events;
+}
+
+// Find the single event with the given name in a transaction.
+export async function expectEvent(txSig: string, name: string) {
+  const events = await getTransactionEvents(txSig);
+  const matching = events.filter((event) => event.name === name);
+  expect(matching).has.length(1);
+  return matching[0].data;
+}
+
 export async function expectIxFail(
   ixes: TransactionInstruction[],
   signers: Keypair[],
@@ -280,3 +360,105 @@ export async function getTokenBridgeSequence() {
     )
     .then((tracker) => tracker.sequence);
 }
+
+export function ethAddressFromPrivateKey(privateKey: Buffer): Buffer {
+  const ix = Secp256k1Program.createInstructionWithPrivateKey({
+    privateKey,
+    message: Buffer.alloc(0),
+  });
+
+  // The Ethereum address directly follows the signature count and offsets.
+  return ix.data.subarray(12, 32);
+}
+
+// Digest the Ethereum authority signs with `personal_sign`. The accounts are
+// those of `ix`, which is executed with the eth authority PDA signing.
+export function ethAuthorizationDigest(
+  domain: string,
+  config: PublicKey,
+  nonce: BN,
+  ix: TransactionInstruction,
+  cluster = CLUSTER
+): Buffer {
+  const payload = Buffer.concat([
+    Buffer.from(domain),
+    Buffer.from(cluster),
+    ix.programId.toBuffer(),
+    config.toBuffer(),
+    nonce.toArrayLike(Buffer, "le", 8),
+    ix.data,
+    ...ix.keys.map((key) =>
+      Buffer.concat([
+        key.pubkey.toBuffer(),
+        Buffer.from([Number(key.isSigner), Number(key.isWritable)]),
+      ])
+    ),
+  ]);
+
+  return Buffer.from(ethers.utils.arrayify(ethers.utils.keccak256(payload)));
+}
+
+// Verifies a `personal_sign` signature over `digest`, as produced by Ethereum
+// wallets.
+export function ethSignatureIx(
+  digest: Buffer,
+  signature: string
+): TransactionInstruction {
+  const { r, s, recoveryParam } = ethers.utils.splitSignature(signature);
+
+  return Secp256k1Program.createInstructionWithEthAddress({
+    ethAddress: ethers.utils.verifyMessage(digest, signature),
+    message: Buffer.concat([
+      Buffer.from("\x19Ethereum Signed Message:\n32"),
+      digest,
+    ]),
+    signature: Buffer.from(ethers.utils.arrayify(ethers.utils.concat([r, s]))),
+    recoveryId: recoveryParam,
+  });
+}
+
+export function ethAuthorizationIx(
+  domain: string,
+  config: PublicKey,
+  nonce: BN,
+  ix: TransactionInstruction,
+  ethPrivateKey: Buffer,
+  cluster = CLUSTER
+): TransactionInstruction {
+  const digest = ethAuthorizationDigest(domain, config, nonce, ix, cluster);
+  const signature = new ethers.utils.SigningKey(ethPrivateKey).signDigest(
+    ethers.utils.hashMessage(digest)
+  );
+
+  return ethSignatureIx(digest, ethers.utils.joinSignature(signature));
+}
+
+export function governanceMessage(
+  module: string,
+  ix: TransactionInstruction
+): Buffer {
+  const encodedModule = Buffer.alloc(32);
+  encodedModule.write(module, 32 - module.length);
+
+  // Execute instruction action, Solana as target chain and number of accounts.
+  const header = Buffer.alloc(4);
+  header.writeUInt8(1, 0);
+  header.writeUInt16BE(1, 1);
+  header.writeUInt8(ix.keys.length, 3);
+
+  return Buffer.concat([
+    encodedModule,
+    header,
+    ...ix.keys.map((key) => key.pubkey.toBuffer()),
+    ix.data,
+  ]);
+}
+
+export async function mockGovernanceVaa(
+  payer: Keypair,
+  governance: MockEmitter,
+  payload: Buffer
+) {
+  const published = governance.publishMessage(0, payload, 1);
+  return mockSignAndPostVaa(payer, published);
+}
@@ -1,11 +1,12 @@
 import { parseVaa } from "@certusone/wormhole-sdk";
 import * as tokenBridge from "@certusone/wormhole-sdk/lib/cjs/solana/tokenBridge";
 import * as coreBridge from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
-import { BN, Program, workspace } from "@coral-xyz/anchor";
+import { BN, IdlTypes, Program, workspace } from "@coral-xyz/anchor";
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import {
   PublicKey,
   SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
   SYSVAR_RENT_PUBKEY,
   TransactionInstruction,
 } from "@solana/web3.js";
@@ -15,14 +16,33 @@ import {
   CORE_BRIDGE_DATA,
   CORE_BRIDGE_PROGRAM_ID,
   ETHEREUM_ENDPOINT,
+  GATEWAY_ETH_AUTHORITY_DOMAIN,
+  GATEWAY_GOVERNANCE_MODULE,
+  MAX_GATEWAYS_PER_PAGE,
   TMEWC_PROGRAM_ID,
+  TMEWC_TOKEN_PROGRAM,
   TOKEN_BRIDGE_PROGRAM_ID,
   WORMHOLE_GATEWAY_PROGRAM_ID,
   WRAPPED_TMEWC_ASSET,
   WRAPPED_TMEWC_MINT,
 } from "./consts";
 import * as tmewc from "./tmewc";
-import { getTokenBridgeCoreEmitter, getTokenBridgeSequence } from "./utils";
+import {
+  ethAuthorizationIx,
+  governanceMessage,
+  getTokenBridgeCoreEmitter,
+  getTokenBridgeSequence,
+} from "./utils";
+
+export type PauseFlags = IdlTypes<WormholeGateway>["PauseFlags"];
+
+export const NO_PAUSE_FLAGS: PauseFlags = {
+  inbound: false,
+  outbound: false,
+  deposit: false,
+};
+
+export type OverLimitMode = IdlTypes<WormholeGateway>["OverLimitMode"];
 
 export function getCustodianPDA(): PublicKey {
   return PublicKey.findProgramAddressSync(
@@ -31,6 +51,58 @@ export function getCustodianPDA(): PublicKey {
   )[0];
 }
 
+export function getEventAuthorityPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority")],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getAuditLogPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("audit-log")],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getEthAuthorityPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("eth-authority")],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getGovernanceAuthorityPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("governance-authority")],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getGovernanceReceiptPDA(
+  emitterChain: number,
+  emitterAddress: Buffer,
+  sequence: bigint
+): PublicKey {
+  const encodedChain = Buffer.alloc(2);
+  encodedChain.writeUInt16BE(emitterChain);
+  const encodedSequence = Buffer.alloc(8);
+  encodedSequence.writeBigUInt64BE(sequence);
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("governance-receipt"),
+      encodedChain,
+      emitterAddress,
+      encodedSequence,
+    ],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getGovernancePayload(ix: TransactionInstruction): Buffer {
+  return governanceMessage(GATEWAY_GOVERNANCE_MODULE, ix);
+}
+
 export function getCoreMessagePDA(sequence: bigint): PublicKey {
   const encodedSequence = Buffer.alloc(8);
   encodedSequence.writeBigUInt64LE(sequence);
@@ -49,6 +121,34 @@ export function getGatewayInfoPDA(targetChain: number): PublicKey {
   )[0];
 }
 
+export function getGuardianInfoPDA(guardian: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("guardian-info"), guardian.toBuffer()],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getGatewayRegistryPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("gateway-registry")],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getPendingMintPDA(recipient: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("pending-mint"), recipient.toBuffer()],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getPendingReceiptPDA(messageHash: Buffer): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("pending-receipt"), messageHash],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
 export function getWrappedTmewcTokenPDA(): PublicKey {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("wrapped-token")],
@@ -76,6 +176,12 @@ export async function getCustodianData() {
   return program.account.custodian.fetch(custodian);
 }
 
+export async function getAuditLogData() {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  const auditLog = getAuditLogPDA();
+  return program.account.auditLog.fetch(auditLog);
+}
+
 export async function checkCustodian(expected: {
   authority: PublicKey;
   mintingLimit: bigint;
@@ -90,6 +196,11 @@ export async function checkCustodian(expected: {
   expect(custodianState.pendingAuthority).to.eql(pendingAuthority);
 }
 
+export async function checkPaused(expected: PauseFlags) {
+  const custodianState = await getCustodianData();
+  expect(custodianState.paused).to.eql(expected);
+}
+
 export async function getMintedAmount(): Promise<bigint> {
   const custodianState = await getCustodianData();
   return BigInt(custodianState.mintedAmount.toString());
@@ -101,13 +212,50 @@ export async function getGatewayInfo(chain: number) {
   return program.account.gatewayInfo.fetch(gatewayInfo);
 }
 
-export async function checkGateway(chain: number, expectedAddress: number[]) {
+export async function getGatewaysPage(start: number, count: number) {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  return program.methods
+    .getGateways(start, count)
+    .accounts({ gatewayRegistry: getGatewayRegistryPDA() })
+    .view();
+}
+
+export async function getGateways() {
+  const gateways = [];
+  for (let start = 0; ; start += MAX_GATEWAYS_PER_PAGE) {
+    const page = await getGatewaysPage(start, MAX_GATEWAYS_PER_PAGE);
+    gateways.push(...page);
+    if (page.length < MAX_GATEWAYS_PER_PAGE) {
+      return gateways;
+    }
+  }
+}
+
+export async function getPendingMint(recipient: PublicKey) {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  const pendingMint = getPendingMintPDA(recipient);
+  return program.account.pendingMint.fetch(pendingMint);
+}
+
+export async function getPendingReceipt(messageHash: Buffer) {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  const pendingReceipt = getPendingReceiptPDA(messageHash);
+  return program.account.pendingReceipt.fetch(pendingReceipt);
+}
+
+export async function checkGateway(
+  chain: number,
+  expectedAddress: number[],
+  expectedDisabled = false
+) {
   const gatewayInfoState = await getGatewayInfo(chain);
   expect(gatewayInfoState.address).to.eql(expectedAddress);
+  expect(gatewayInfoState.disabled).to.equal(expectedDisabled);
 }
 
 type CancelAuthorityChange = {
   custodian?: PublicKey;
+  auditLog?: PublicKey;
   authority: PublicKey;
 };
 
@@ -116,22 +264,30 @@ export async function cancelAuthorityChangeIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
 
-  let { custodian, authority } = accounts;
+  let { custodian, auditLog, authority } = accounts;
   if (custodian === undefined) {
     custodian = getCustodianPDA();
   }
 
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
   return program.methods
     .cancelAuthorityChange()
     .accounts({
       custodian,
+      auditLog,
       authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
 
 type ChangeAuthorityContext = {
   custodian?: PublicKey;
+  auditLog?: PublicKey;
   authority: PublicKey;
   newAuthority: PublicKey;
 };
@@ -141,23 +297,31 @@ export async function changeAuthorityIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
 
-  let { custodian, authority, newAuthority } = accounts;
+  let { custodian, auditLog, authority, newAuthority } = accounts;
   if (custodian === undefined) {
     custodian = getCustodianPDA();
   }
 
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
   return program.methods
     .changeAuthority()
     .accounts({
       custodian,
+      auditLog,
       authority,
       newAuthority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
 
 type TakeAuthorityContext = {
   custodian?: PublicKey;
+  auditLog?: PublicKey;
   pendingAuthority: PublicKey;
 };
 
@@ -166,233 +330,732 @@ export async function takeAuthorityIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
 
-  let { custodian, pendingAuthority } = accounts;
+  let { custodian, auditLog, pendingAuthority } = accounts;
   if (custodian === undefined) {
     custodian = getCustodianPDA();
   }
 
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
   return program.methods
     .takeAuthority()
     .accounts({
       custodian,
+      auditLog,
       pendingAuthority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
 
-type UpdateMintingLimitContext = {
+type SetGatewayAllowlistContext = {
   custodian?: PublicKey;
+  auditLog?: PublicKey;
   authority: PublicKey;
 };
 
-export async function updateMintingLimitIx(
-  accounts: UpdateMintingLimitContext,
-  amount: bigint
+export async function setGatewayAllowlistIx(
+  accounts: SetGatewayAllowlistContext,
+  enabled: boolean
 ): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
 
-  let { custodian, authority } = accounts;
+  let { custodian, auditLog, authority } = accounts;
   if (custodian === undefined) {
     custodian = getCustodianPDA();
   }
 
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
   return program.methods
-    .updateMintingLimit(new BN(amount.toString()))
+    .setGatewayAllowlist(enabled)
     .accounts({
       custodian,
+      auditLog,
       authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
 
-type UpdateGatewayAddressContext = {
+type MigrateCustodianContext = {
   custodian?: PublicKey;
-  gatewayInfo?: PublicKey;
+  auditLog?: PublicKey;
+  gatewayRegistry?: PublicKey;
   authority: PublicKey;
 };
 
-type UpdateGatewayAddressArgs = {
-  chain: number;
-  address: number[];
+export async function migrateCustodianIx(
+  accounts: MigrateCustodianContext,
+  chains: number[] = []
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, auditLog, gatewayRegistry, authority } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (gatewayRegistry === undefined) {
+    gatewayRegistry = getGatewayRegistryPDA();
+  }
+
+  return program.methods
+    .migrateCustodian(chains)
+    .accounts({
+      custodian,
+      auditLog,
+      gatewayRegistry,
+      authority,
+    })
+    .remainingAccounts(
+      chains.map((chain) => ({
+        pubkey: getGatewayInfoPDA(chain),
+        isWritable: false,
+        isSigner: false,
+      }))
+    )
+    .instruction();
+}
+
+type MigrateGatewayInfoContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  gatewayInfo?: PublicKey;
+  authority: PublicKey;
 };
 
-export async function updateGatewayAddress(
-  accounts: UpdateGatewayAddressContext,
-  args: UpdateGatewayAddressArgs
-) {
+export async function migrateGatewayInfoIx(
+  accounts: MigrateGatewayInfoContext,
+  chain: number
+): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
-  let { custodian, gatewayInfo, authority } = accounts;
+  let { custodian, auditLog, gatewayInfo, authority } = accounts;
 
   if (custodian === undefined) {
     custodian = getCustodianPDA();
   }
 
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
   if (gatewayInfo === undefined) {
-    gatewayInfo = getGatewayInfoPDA(args.chain);
+    gatewayInfo = getGatewayInfoPDA(chain);
   }
 
   return program.methods
-    .updateGatewayAddress(args)
+    .migrateGatewayInfo(chain)
     .accounts({
       custodian,
+      auditLog,
       gatewayInfo,
       authority,
     })
     .instruction();
 }
 
-type DepositWormholeTmewcContext = {
+type UpdateMintingLimitContext = {
   custodian?: PublicKey;
-  wrappedTmewcToken?: PublicKey;
-  wrappedTmewcMint?: PublicKey;
-  tmewcMint?: PublicKey;
-  recipientWrappedToken: PublicKey;
-  recipientToken: PublicKey;
-  recipient: PublicKey;
-  tmewcConfig?: PublicKey;
-  tmewcMinterInfo?: PublicKey;
-  tmewcProgram?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
 };
 
-export async function depositWormholeTmewcIx(
-  accounts: DepositWormholeTmewcContext,
+export async function updateMintingLimitIx(
+  accounts: UpdateMintingLimitContext,
   amount: bigint
 ): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
-  let {
-    custodian,
-    wrappedTmewcToken,
-    wrappedTmewcMint,
-    tmewcMint,
-    recipientWrappedToken,
-    recipientToken,
-    recipient,
-    tmewcConfig,
-    tmewcMinterInfo,
-    tmewcProgram,
-  } = accounts;
 
+  let { custodian, auditLog, authority } = accounts;
   if (custodian === undefined) {
     custodian = getCustodianPDA();
   }
 
-  if (wrappedTmewcToken === undefined) {
-    wrappedTmewcToken = getWrappedTmewcTokenPDA();
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
   }
 
-  if (wrappedTmewcMint === undefined) {
-    wrappedTmewcMint = WRAPPED_TMEWC_MINT;
-  }
+  return program.methods
+    .updateMintingLimit(new BN(amount.toString()))
+    .accounts({
+      custodian,
+      auditLog,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
 
-  if (tmewcMint === undefined) {
-    tmewcMint = tmewc.getMintPDA();
-  }
+type UpdateInboundRateLimitContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+};
 
-  if (tmewcConfig === undefined) {
-    tmewcConfig = tmewc.getConfigPDA();
-  }
+export async function updateInboundRateLimitIx(
+  accounts: UpdateInboundRateLimitContext,
+  capacity: bigint,
+  windowDuration: number
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
 
-  if (tmewcMinterInfo === undefined) {
-    tmewcMinterInfo = tmewc.getMinterInfoPDA(custodian);
+  let { custodian, auditLog, authority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
   }
 
-  if (tmewcProgram === undefined) {
-    tmewcProgram = TMEWC_PROGRAM_ID;
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
   }
 
   return program.methods
-    .depositWormholeTmewc(new BN(amount.toString()))
+    .updateInboundRateLimit({
+      capacity: new BN(capacity.toString()),
+      windowDuration,
+    })
     .accounts({
       custodian,
-      wrappedTmewcToken,
-      wrappedTmewcMint,
-      tmewcMint,
-      recipientWrappedToken,
-      recipientToken,
-      recipient,
-      tmewcConfig,
-      tmewcMinterInfo,
-      tmewcProgram,
+      auditLog,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
 
-type ReceiveTmewcContext = {
-  payer: PublicKey;
+type UpdateLargeTransferDelayContext = {
   custodian?: PublicKey;
-  postedVaa?: PublicKey;
-  tokenBridgeClaim?: PublicKey;
-  wrappedTmewcToken?: PublicKey;
-  wrappedTmewcMint?: PublicKey;
-  tmewcMint?: PublicKey;
-  recipientToken: PublicKey;
-  recipient: PublicKey;
-  recipientWrappedToken?: PublicKey;
-  tmewcConfig?: PublicKey;
-  tmewcMinterInfo?: PublicKey;
-  tokenBridgeConfig?: PublicKey;
-  tokenBridgeRegisteredEmitter?: PublicKey;
-  //tokenBridgeRedeemer?: PublicKey;
-  tokenBridgeWrappedAsset?: PublicKey;
-  tokenBridgeMintAuthority?: PublicKey;
-  rent?: PublicKey;
-  tmewcProgram?: PublicKey;
-  tokenBridgeProgram?: PublicKey;
-  coreBridgeProgram?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
 };
 
-export async function receiveTmewcIx(
-  accounts: ReceiveTmewcContext,
-  signedVaa: Buffer
+export async function updateLargeTransferDelayIx(
+  accounts: UpdateLargeTransferDelayContext,
+  threshold: bigint,
+  delay: number
 ): Promise<TransactionInstruction> {
-  const parsed = parseVaa(signedVaa);
-
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
-  let {
-    payer,
-    custodian,
-    postedVaa,
-    tokenBridgeClaim,
-    wrappedTmewcToken,
-    wrappedTmewcMint,
-    tmewcMint,
-    recipientToken,
-    recipient,
-    recipientWrappedToken,
-    tmewcConfig,
-    tmewcMinterInfo,
-    tokenBridgeConfig,
-    tokenBridgeRegisteredEmitter,
-    //tokenBridgeRedeemer,
-    tokenBridgeWrappedAsset,
-    tokenBridgeMintAuthority,
-    rent,
-    tmewcProgram,
-    tokenBridgeProgram,
-    coreBridgeProgram,
-  } = accounts;
 
+  let { custodian, auditLog, authority } = accounts;
   if (custodian === undefined) {
     custodian = getCustodianPDA();
   }
 
-  if (postedVaa === undefined) {
-    postedVaa = coreBridge.derivePostedVaaKey(
-      CORE_BRIDGE_PROGRAM_ID,
-      parsed.hash
-    );
-  }
-
-  if (tokenBridgeClaim === undefined) {
-    tokenBridgeClaim = coreBridge.deriveClaimKey(
-      TOKEN_BRIDGE_PROGRAM_ID,
-      parsed.emitterAddress,
-      parsed.emitterChain,
-      parsed.sequence
-    );
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
   }
 
-  if (wrappedTmewcToken === undefined) {
+  return program.methods
+    .updateLargeTransferDelay({
+      threshold: new BN(threshold.toString()),
+      delay,
+    })
+    .accounts({
+      custodian,
+      auditLog,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type UpdateOverLimitModeContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+};
+
+type UpdateOverLimitModeArgs = {
+  overLimitMode: OverLimitMode;
+  partialMint: boolean;
+};
+
+export async function updateOverLimitModeIx(
+  accounts: UpdateOverLimitModeContext,
+  args: UpdateOverLimitModeArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, auditLog, authority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  return program.methods
+    .updateOverLimitMode(args)
+    .accounts({
+      custodian,
+      auditLog,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type UpdateGatewayAddressContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  gatewayInfo?: PublicKey;
+  gatewayRegistry?: PublicKey;
+  authority: PublicKey;
+};
+
+type UpdateGatewayAddressArgs = {
+  chain: number;
+  address: number[];
+};
+
+export async function updateGatewayAddress(
+  accounts: UpdateGatewayAddressContext,
+  args: UpdateGatewayAddressArgs
+) {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, auditLog, gatewayInfo, gatewayRegistry, authority } =
+    accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(args.chain);
+  }
+
+  if (gatewayRegistry === undefined) {
+    gatewayRegistry = getGatewayRegistryPDA();
+  }
+
+  return program.methods
+    .updateGatewayAddress(args)
+    .accounts({
+      custodian,
+      auditLog,
+      gatewayInfo,
+      gatewayRegistry,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type GatewayChainContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  gatewayInfo?: PublicKey;
+  gatewayRegistry?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function disableGatewayIx(
+  accounts: GatewayChainContext,
+  chain: number
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, auditLog, gatewayInfo, gatewayRegistry, authority } =
+    accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(chain);
+  }
+
+  if (gatewayRegistry === undefined) {
+    gatewayRegistry = getGatewayRegistryPDA();
+  }
+
+  return program.methods
+    .disableGateway(chain)
+    .accounts({
+      custodian,
+      auditLog,
+      gatewayInfo,
+      gatewayRegistry,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+export async function enableGatewayIx(
+  accounts: GatewayChainContext,
+  chain: number
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, auditLog, gatewayInfo, gatewayRegistry, authority } =
+    accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(chain);
+  }
+
+  if (gatewayRegistry === undefined) {
+    gatewayRegistry = getGatewayRegistryPDA();
+  }
+
+  return program.methods
+    .enableGateway(chain)
+    .accounts({
+      custodian,
+      auditLog,
+      gatewayInfo,
+      gatewayRegistry,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+export async function removeGatewayIx(
+  accounts: GatewayChainContext,
+  chain: number
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, auditLog, gatewayInfo, gatewayRegistry, authority } =
+    accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(chain);
+  }
+
+  if (gatewayRegistry === undefined) {
+    gatewayRegistry = getGatewayRegistryPDA();
+  }
+
+  return program.methods
+    .removeGateway(chain)
+    .accounts({
+      custodian,
+      auditLog,
+      gatewayInfo,
+      gatewayRegistry,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type UpdateOutboundLimitContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  gatewayInfo?: PublicKey;
+  authority: PublicKey;
+};
+
+type OutboundLimitArgs = {
+  maxTransferAmount: bigint;
+  windowLimit: bigint;
+  windowDuration: number;
+};
+
+export async function updateOutboundLimitIx(
+  accounts: UpdateOutboundLimitContext,
+  chain: number,
+  args: OutboundLimitArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, auditLog, gatewayInfo, authority } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(chain);
+  }
+
+  const { maxTransferAmount, windowLimit, windowDuration } = args;
+  return program.methods
+    .updateOutboundLimit({
+      chain,
+      maxTransferAmount: new BN(maxTransferAmount.toString()),
+      windowLimit: new BN(windowLimit.toString()),
+      windowDuration,
+    })
+    .accounts({
+      custodian,
+      auditLog,
+      gatewayInfo,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type UpdateDefaultOutboundLimitContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function updateDefaultOutboundLimitIx(
+  accounts: UpdateDefaultOutboundLimitContext,
+  args: OutboundLimitArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, auditLog, authority } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  const { maxTransferAmount, windowLimit, windowDuration } = args;
+  return program.methods
+    .updateDefaultOutboundLimit({
+      maxTransferAmount: new BN(maxTransferAmount.toString()),
+      windowLimit: new BN(windowLimit.toString()),
+      windowDuration,
+    })
+    .accounts({
+      custodian,
+      auditLog,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type DepositWormholeTmewcContext = {
+  custodian?: PublicKey;
+  wrappedTmewcToken?: PublicKey;
+  wrappedTmewcMint?: PublicKey;
+  tmewcMint?: PublicKey;
+  recipientWrappedToken: PublicKey;
+  recipientToken: PublicKey;
+  recipient: PublicKey;
+  tmewcConfig?: PublicKey;
+  tmewcMinterInfo?: PublicKey;
+  tmewcReserveAttestation?: PublicKey | null;
+  tmewcTokenProgram?: PublicKey;
+  tmewcProgram?: PublicKey;
+};
+
+export async function depositWormholeTmewcIx(
+  accounts: DepositWormholeTmewcContext,
+  amount: bigint
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let {
+    custodian,
+    wrappedTmewcToken,
+    wrappedTmewcMint,
+    tmewcMint,
+    recipientWrappedToken,
+    recipientToken,
+    recipient,
+    tmewcConfig,
+    tmewcMinterInfo,
+    tmewcReserveAttestation,
+    tmewcTokenProgram,
+    tmewcProgram,
+  } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (wrappedTmewcToken === undefined) {
+    wrappedTmewcToken = getWrappedTmewcTokenPDA();
+  }
+
+  if (wrappedTmewcMint === undefined) {
+    wrappedTmewcMint = WRAPPED_TMEWC_MINT;
+  }
+
+  if (tmewcMint === undefined) {
+    tmewcMint = tmewc.getMintPDA();
+  }
+
+  if (tmewcConfig === undefined) {
+    tmewcConfig = tmewc.getConfigPDA();
+  }
+
+  if (tmewcMinterInfo === undefined) {
+    tmewcMinterInfo = tmewc.getMinterInfoPDA(custodian);
+  }
+
+  if (tmewcReserveAttestation === undefined) {
+    tmewcReserveAttestation = null;
+  }
+
+  if (tmewcTokenProgram === undefined) {
+    tmewcTokenProgram = TMEWC_TOKEN_PROGRAM;
+  }
+
+  if (tmewcProgram === undefined) {
+    tmewcProgram = TMEWC_PROGRAM_ID;
+  }
+
+  return program.methods
+    .depositWormholeTmewc(new BN(amount.toString()))
+    .accounts({
+      custodian,
+      wrappedTmewcToken,
+      wrappedTmewcMint,
+      tmewcMint,
+      recipientWrappedToken,
+      recipientToken,
+      recipient,
+      tmewcConfig,
+      tmewcMinterInfo,
+      tmewcReserveAttestation,
+      tmewcEventAuthority: tmewc.getEventAuthorityPDA(),
+      tmewcTokenProgram,
+      tmewcProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type ReceiveTmewcContext = {
+  payer: PublicKey;
+  custodian?: PublicKey;
+  postedVaa?: PublicKey;
+  gatewayInfo?: PublicKey | null;
+  tokenBridgeClaim?: PublicKey;
+  wrappedTmewcToken?: PublicKey;
+  wrappedTmewcMint?: PublicKey;
+  tmewcMint?: PublicKey;
+  recipientToken: PublicKey;
+  recipient: PublicKey;
+  recipientWrappedToken?: PublicKey;
+  pendingMint?: PublicKey | null;
+  pendingReceipt?: PublicKey | null;
+  tmewcConfig?: PublicKey;
+  tmewcMinterInfo?: PublicKey;
+  tmewcReserveAttestation?: PublicKey | null;
+  tokenBridgeConfig?: PublicKey;
+  tokenBridgeRegisteredEmitter?: PublicKey;
+  //tokenBridgeRedeemer?: PublicKey;
+  tokenBridgeWrappedAsset?: PublicKey;
+  tokenBridgeMintAuthority?: PublicKey;
+  rent?: PublicKey;
+  tmewcProgram?: PublicKey;
+  tokenBridgeProgram?: PublicKey;
+  coreBridgeProgram?: PublicKey;
+  tmewcTokenProgram?: PublicKey;
+};
+
+export async function receiveTmewcIx(
+  accounts: ReceiveTmewcContext,
+  signedVaa: Buffer
+): Promise<TransactionInstruction> {
+  const parsed = parseVaa(signedVaa);
+
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let {
+    payer,
+    custodian,
+    postedVaa,
+    gatewayInfo,
+    tokenBridgeClaim,
+    wrappedTmewcToken,
+    wrappedTmewcMint,
+    tmewcMint,
+    recipientToken,
+    recipient,
+    recipientWrappedToken,
+    pendingMint,
+    pendingReceipt,
+    tmewcConfig,
+    tmewcMinterInfo,
+    tmewcReserveAttestation,
+    tokenBridgeConfig,
+    tokenBridgeRegisteredEmitter,
+    //tokenBridgeRedeemer,
+    tokenBridgeWrappedAsset,
+    tokenBridgeMintAuthority,
+    rent,
+    tmewcProgram,
+    tokenBridgeProgram,
+    coreBridgeProgram,
+    tmewcTokenProgram,
+  } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (postedVaa === undefined) {
+    postedVaa = coreBridge.derivePostedVaaKey(
+      CORE_BRIDGE_PROGRAM_ID,
+      parsed.hash
+    );
+  }
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = null;
+  }
+
+  if (tokenBridgeClaim === undefined) {
+    tokenBridgeClaim = coreBridge.deriveClaimKey(
+      TOKEN_BRIDGE_PROGRAM_ID,
+      parsed.emitterAddress,
+      parsed.emitterChain,
+      parsed.sequence
+    );
+  }
+
+  if (wrappedTmewcToken === undefined) {
     wrappedTmewcToken = getWrappedTmewcTokenPDA();
   }
 
@@ -411,6 +1074,14 @@ export async function receiveTmewcIx(
     );
   }
 
+  if (pendingMint === undefined) {
+    pendingMint = null;
+  }
+
+  if (pendingReceipt === undefined) {
+    pendingReceipt = null;
+  }
+
   if (tmewcConfig === undefined) {
     tmewcConfig = tmewc.getConfigPDA();
   }
@@ -419,6 +1090,10 @@ export async function receiveTmewcIx(
     tmewcMinterInfo = tmewc.getMinterInfoPDA(custodian);
   }
 
+  if (tmewcReserveAttestation === undefined) {
+    tmewcReserveAttestation = null;
+  }
+
   if (tokenBridgeConfig === undefined) {
     tokenBridgeConfig = tokenBridge.deriveTokenBridgeConfigKey(
       TOKEN_BRIDGE_PROGRAM_ID
@@ -461,20 +1136,29 @@ export async function receiveTmewcIx(
     coreBridgeProgram = CORE_BRIDGE_PROGRAM_ID;
   }
 
+  if (tmewcTokenProgram === undefined) {
+    tmewcTokenProgram = TMEWC_TOKEN_PROGRAM;
+  }
+
   return program.methods
     .receiveTmewc(Array.from(parsed.hash))
     .accounts({
       payer,
       custodian,
       postedVaa,
+      gatewayInfo,
       tokenBridgeClaim,
       wrappedTmewcToken,
       tmewcMint,
       recipientToken,
       recipient,
       recipientWrappedToken,
+      pendingMint,
+      pendingReceipt,
       tmewcConfig,
       tmewcMinterInfo,
+      tmewcReserveAttestation,
+      tmewcEventAuthority: tmewc.getEventAuthorityPDA(),
       wrappedTmewcMint,
       tokenBridgeConfig,
       tokenBridgeRegisteredEmitter,
@@ -485,6 +1169,272 @@ export async function receiveTmewcIx(
       tmewcProgram,
       tokenBridgeProgram,
       coreBridgeProgram,
+      tmewcTokenProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type ReleasePendingReceiptContext = {
+  payer: PublicKey;
+  custodian?: PublicKey;
+  pendingReceipt: PublicKey;
+  receiptPayer?: PublicKey;
+  wrappedTmewcToken?: PublicKey;
+  wrappedTmewcMint?: PublicKey;
+  tmewcMint?: PublicKey;
+  recipientToken: PublicKey;
+  recipient: PublicKey;
+  recipientWrappedToken?: PublicKey;
+  pendingMint?: PublicKey | null;
+  tmewcConfig?: PublicKey;
+  tmewcMinterInfo?: PublicKey;
+  tmewcReserveAttestation?: PublicKey | null;
+  tmewcProgram?: PublicKey;
+  tmewcTokenProgram?: PublicKey;
+};
+
+export async function releasePendingReceiptIx(
+  accounts: ReleasePendingReceiptContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let {
+    payer,
+    custodian,
+    pendingReceipt,
+    receiptPayer,
+    wrappedTmewcToken,
+    wrappedTmewcMint,
+    tmewcMint,
+    recipientToken,
+    recipient,
+    recipientWrappedToken,
+    pendingMint,
+    tmewcConfig,
+    tmewcMinterInfo,
+    tmewcReserveAttestation,
+    tmewcProgram,
+    tmewcTokenProgram,
+  } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (receiptPayer === undefined) {
+    receiptPayer = await program.account.pendingReceipt
+      .fetch(pendingReceipt)
+      .then((data) => data.payer);
+  }
+
+  if (wrappedTmewcToken === undefined) {
+    wrappedTmewcToken = getWrappedTmewcTokenPDA();
+  }
+
+  if (wrappedTmewcMint === undefined) {
+    wrappedTmewcMint = WRAPPED_TMEWC_MINT;
+  }
+
+  if (tmewcMint === undefined) {
+    tmewcMint = tmewc.getMintPDA();
+  }
+
+  if (recipientWrappedToken == undefined) {
+    recipientWrappedToken = getAssociatedTokenAddressSync(
+      wrappedTmewcMint,
+      recipient
+    );
+  }
+
+  if (pendingMint === undefined) {
+    pendingMint = null;
+  }
+
+  if (tmewcConfig === undefined) {
+    tmewcConfig = tmewc.getConfigPDA();
+  }
+
+  if (tmewcMinterInfo === undefined) {
+    tmewcMinterInfo = tmewc.getMinterInfoPDA(custodian);
+  }
+
+  if (tmewcReserveAttestation === undefined) {
+    tmewcReserveAttestation = null;
+  }
+
+  if (tmewcProgram === undefined) {
+    tmewcProgram = TMEWC_PROGRAM_ID;
+  }
+
+  if (tmewcTokenProgram === undefined) {
+    tmewcTokenProgram = TMEWC_TOKEN_PROGRAM;
+  }
+
+  return program.methods
+    .releasePendingReceipt()
+    .accounts({
+      payer,
+      custodian,
+      pendingReceipt,
+      receiptPayer,
+      wrappedTmewcToken,
+      wrappedTmewcMint,
+      tmewcMint,
+      recipientToken,
+      recipient,
+      recipientWrappedToken,
+      pendingMint,
+      tmewcConfig,
+      tmewcMinterInfo,
+      tmewcReserveAttestation,
+      tmewcEventAuthority: tmewc.getEventAuthorityPDA(),
+      tmewcProgram,
+      tmewcTokenProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type CheckpointSupplyContext = {
+  custodian?: PublicKey;
+  tmewcMint?: PublicKey;
+  tmewcConfig?: PublicKey;
+  tmewcSupplyCheckpoints?: PublicKey;
+  payer: PublicKey;
+};
+
+export async function checkpointSupplyIx(
+  accounts: CheckpointSupplyContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, tmewcMint, tmewcConfig, tmewcSupplyCheckpoints, payer } =
+    accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (tmewcMint === undefined) {
+    tmewcMint = tmewc.getMintPDA();
+  }
+
+  if (tmewcConfig === undefined) {
+    tmewcConfig = tmewc.getConfigPDA();
+  }
+
+  if (tmewcSupplyCheckpoints === undefined) {
+    tmewcSupplyCheckpoints = tmewc.getSupplyCheckpointsPDA();
+  }
+
+  return program.methods
+    .checkpointSupply()
+    .accounts({
+      custodian,
+      tmewcMint,
+      tmewcConfig,
+      tmewcSupplyCheckpoints,
+      payer,
+      tmewcProgram: TMEWC_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+type ProcessPendingMintContext = {
+  custodian?: PublicKey;
+  pendingMint?: PublicKey;
+  pendingMintPayer?: PublicKey;
+  wrappedTmewcMint?: PublicKey;
+  tmewcMint?: PublicKey;
+  recipientToken: PublicKey;
+  recipient: PublicKey;
+  tmewcConfig?: PublicKey;
+  tmewcMinterInfo?: PublicKey;
+  tmewcReserveAttestation?: PublicKey | null;
+  tmewcTokenProgram?: PublicKey;
+  tmewcProgram?: PublicKey;
+};
+
+export async function processPendingMintIx(
+  accounts: ProcessPendingMintContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let {
+    custodian,
+    pendingMint,
+    pendingMintPayer,
+    wrappedTmewcMint,
+    tmewcMint,
+    recipientToken,
+    recipient,
+    tmewcConfig,
+    tmewcMinterInfo,
+    tmewcReserveAttestation,
+    tmewcTokenProgram,
+    tmewcProgram,
+  } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (pendingMint === undefined) {
+    pendingMint = getPendingMintPDA(recipient);
+  }
+
+  if (pendingMintPayer === undefined) {
+    pendingMintPayer = await program.account.pendingMint
+      .fetch(pendingMint)
+      .then((data) => data.payer);
+  }
+
+  if (wrappedTmewcMint === undefined) {
+    wrappedTmewcMint = WRAPPED_TMEWC_MINT;
+  }
+
+  if (tmewcMint === undefined) {
+    tmewcMint = tmewc.getMintPDA();
+  }
+
+  if (tmewcConfig === undefined) {
+    tmewcConfig = tmewc.getConfigPDA();
+  }
+
+  if (tmewcMinterInfo === undefined) {
+    tmewcMinterInfo = tmewc.getMinterInfoPDA(custodian);
+  }
+
+  if (tmewcReserveAttestation === undefined) {
+    tmewcReserveAttestation = null;
+  }
+
+  if (tmewcTokenProgram === undefined) {
+    tmewcTokenProgram = TMEWC_TOKEN_PROGRAM;
+  }
+
+  if (tmewcProgram === undefined) {
+    tmewcProgram = TMEWC_PROGRAM_ID;
+  }
+
+  return program.methods
+    .processPendingMint()
+    .accounts({
+      custodian,
+      pendingMint,
+      pendingMintPayer,
+      wrappedTmewcMint,
+      tmewcMint,
+      recipientToken,
+      recipient,
+      tmewcConfig,
+      tmewcMinterInfo,
+      tmewcReserveAttestation,
+      tmewcEventAuthority: tmewc.getEventAuthorityPDA(),
+      tmewcTokenProgram,
+      tmewcProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
@@ -510,6 +1460,7 @@ type SendTmewcGatewayContext = {
   rent?: PublicKey;
   tokenBridgeProgram?: PublicKey;
   coreBridgeProgram?: PublicKey;
+  tmewcTokenProgram?: PublicKey;
 };
 
 type SendTmewcGatewayArgs = {
@@ -545,6 +1496,7 @@ export async function sendTmewcGatewayIx(
     rent,
     tokenBridgeProgram,
     coreBridgeProgram,
+    tmewcTokenProgram,
   } = accounts;
 
   if (custodian === undefined) {
@@ -629,6 +1581,10 @@ export async function sendTmewcGatewayIx(
     coreBridgeProgram = CORE_BRIDGE_PROGRAM_ID;
   }
 
+  if (tmewcTokenProgram === undefined) {
+    tmewcTokenProgram = TMEWC_TOKEN_PROGRAM;
+  }
+
   return program.methods
     .sendTmewcGateway(args)
     .accounts({
@@ -652,12 +1608,16 @@ export async function sendTmewcGatewayIx(
       rent,
       tokenBridgeProgram,
       coreBridgeProgram,
+      tmewcTokenProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }
 
 type SendTmewcWrappedContext = {
   custodian?: PublicKey;
+  gatewayInfo?: PublicKey;
   wrappedTmewcToken?: PublicKey;
   wrappedTmewcMint?: PublicKey;
   tmewcMint?: PublicKey;
@@ -675,6 +1635,7 @@ type SendTmewcWrappedContext = {
   rent?: PublicKey;
   tokenBridgeProgram?: PublicKey;
   coreBridgeProgram?: PublicKey;
+  tmewcTokenProgram?: PublicKey;
 };
 
 type SendTmewcWrappedArgs = {
@@ -692,6 +1653,7 @@ export async function sendTmewcWrappedIx(
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   let {
     custodian,
+    gatewayInfo,
     wrappedTmewcToken,
     wrappedTmewcMint,
     tmewcMint,
@@ -709,12 +1671,17 @@ export async function sendTmewcWrappedIx(
     rent,
     tokenBridgeProgram,
     coreBridgeProgram,
+    tmewcTokenProgram,
   } = accounts;
 
   if (custodian === undefined) {
     custodian = getCustodianPDA();
   }
 
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(args.recipientChain);
+  }
+
   if (wrappedTmewcToken === undefined) {
     wrappedTmewcToken = getWrappedTmewcTokenPDA();
   }
@@ -783,10 +1750,15 @@ export async function sendTmewcWrappedIx(
     coreBridgeProgram = CORE_BRIDGE_PROGRAM_ID;
   }
 
+  if (tmewcTokenProgram === undefined) {
+    tmewcTokenProgram = TMEWC_TOKEN_PROGRAM;
+  }
+
   return program.methods
     .sendTmewcWrapped(args)
     .accounts({
       custodian,
+      gatewayInfo,
       wrappedTmewcToken,
       wrappedTmewcMint,
       tmewcMint,
@@ -804,6 +1776,442 @@ export async function sendTmewcWrappedIx(
       rent,
       tokenBridgeProgram,
       coreBridgeProgram,
+      tmewcTokenProgram,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type SetEthAuthorityContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+};
+export async function setEthAuthorityIx(
+  accounts: SetEthAuthorityContext,
+  ethAuthority: Buffer | null
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, auditLog, authority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  return program.methods
+    .setEthAuthority(ethAuthority === null ? null : Array.from(ethAuthority))
+    .accounts({
+      custodian,
+      auditLog,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type ExecuteAsEthAuthorityContext = {
+  custodian?: PublicKey;
+  ethAuthority?: PublicKey;
+};
+// Returns the secp256k1 signature verification followed by the execute
+// instruction, which have to be the first two instructions of the transaction.
+export async function executeAsEthAuthorityIxs(
+  accounts: ExecuteAsEthAuthorityContext,
+  ix: TransactionInstruction,
+  ethPrivateKey: Buffer
+): Promise<TransactionInstruction[]> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, ethAuthority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (ethAuthority === undefined) {
+    ethAuthority = getEthAuthorityPDA();
+  }
+
+  const { ethAuthorityNonce } = await program.account.custodian.fetch(
+    custodian
+  );
+  const verifyIx = ethAuthorizationIx(
+    GATEWAY_ETH_AUTHORITY_DOMAIN,
+    custodian,
+    ethAuthorityNonce,
+    ix,
+    ethPrivateKey
+  );
+
+  const executeIx = await program.methods
+    .executeAsEthAuthority(ix.data)
+    .accounts({
+      custodian,
+      ethAuthority,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .remainingAccounts(
+      ix.keys.map((key) => ({
+        pubkey: key.pubkey,
+        isSigner: key.isSigner && !key.pubkey.equals(ethAuthority),
+        isWritable: key.isWritable,
+      }))
+    )
+    .instruction();
+
+  return [verifyIx, executeIx];
+}
+
+type UpdateGovernanceEmitterContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+};
+export async function updateGovernanceEmitterIx(
+  accounts: UpdateGovernanceEmitterContext,
+  governanceEmitter: Buffer
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, auditLog, authority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  return program.methods
+    .updateGovernanceEmitter(Array.from(governanceEmitter))
+    .accounts({
+      custodian,
+      auditLog,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type ExecuteGovernanceVaaContext = {
+  payer: PublicKey;
+  custodian?: PublicKey;
+  postedVaa?: PublicKey;
+  governanceReceipt?: PublicKey;
+  governanceAuthority?: PublicKey;
+};
+export async function executeGovernanceVaaIx(
+  accounts: ExecuteGovernanceVaaContext,
+  signedVaa: Buffer,
+  ix: TransactionInstruction
+): Promise<TransactionInstruction> {
+  const parsed = parseVaa(signedVaa);
+
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { payer, custodian, postedVaa, governanceReceipt, governanceAuthority } =
+    accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (postedVaa === undefined) {
+    postedVaa = coreBridge.derivePostedVaaKey(
+      CORE_BRIDGE_PROGRAM_ID,
+      parsed.hash
+    );
+  }
+
+  if (governanceReceipt === undefined) {
+    governanceReceipt = getGovernanceReceiptPDA(
+      parsed.emitterChain,
+      parsed.emitterAddress,
+      parsed.sequence
+    );
+  }
+
+  if (governanceAuthority === undefined) {
+    governanceAuthority = getGovernanceAuthorityPDA();
+  }
+
+  return program.methods
+    .executeGovernanceVaa(Array.from(parsed.hash))
+    .accounts({
+      payer,
+      custodian,
+      postedVaa,
+      governanceReceipt,
+      governanceAuthority,
+      coreBridgeProgram: CORE_BRIDGE_PROGRAM_ID,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .remainingAccounts(
+      ix.keys.map((key) => ({
+        pubkey: key.pubkey,
+        isSigner: key.isSigner && !key.pubkey.equals(governanceAuthority),
+        isWritable: key.isWritable,
+      }))
+    )
+    .instruction();
+}
+
+type GuardianContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
+};
+
+export async function addGuardianIx(
+  accounts: GuardianContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, auditLog, authority, guardianInfo, guardian } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
+  }
+
+  return program.methods
+    .addGuardian()
+    .accounts({
+      custodian,
+      auditLog,
+      authority,
+      guardianInfo,
+      guardian,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+export async function removeGuardianIx(
+  accounts: GuardianContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, auditLog, authority, guardianInfo, guardian } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
+  }
+
+  return program.methods
+    .removeGuardian()
+    .accounts({
+      custodian,
+      auditLog,
+      authority,
+      guardianInfo,
+      guardian,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type PauseContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
+};
+
+export async function pauseIx(
+  accounts: PauseContext,
+  flags: Partial<PauseFlags>
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, auditLog, guardianInfo, guardian } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
+  }
+
+  return program.methods
+    .pause({ ...NO_PAUSE_FLAGS, ...flags })
+    .accounts({
+      custodian,
+      auditLog,
+      guardianInfo,
+      guardian,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type UnpauseContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function unpauseIx(
+  accounts: UnpauseContext,
+  flags: Partial<PauseFlags>
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, auditLog, authority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  return program.methods
+    .unpause({ ...NO_PAUSE_FLAGS, ...flags })
+    .accounts({
+      custodian,
+      auditLog,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type CancelPendingReceiptContext = {
+  auditLog?: PublicKey;
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
+  pendingReceipt: PublicKey;
+};
+
+export async function cancelPendingReceiptIx(
+  accounts: CancelPendingReceiptContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { auditLog, guardianInfo, guardian, pendingReceipt } = accounts;
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
+  }
+
+  return program.methods
+    .cancelPendingReceipt()
+    .accounts({
+      auditLog,
+      guardianInfo,
+      guardian,
+      pendingReceipt,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
+    })
+    .instruction();
+}
+
+type RefundPendingReceiptContext = {
+  custodian?: PublicKey;
+  auditLog?: PublicKey;
+  pendingReceipt: PublicKey;
+  receiptPayer?: PublicKey;
+  wrappedTmewcToken?: PublicKey;
+  wrappedTmewcMint?: PublicKey;
+  recipient: PublicKey;
+  recipientWrappedToken?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function refundPendingReceiptIx(
+  accounts: RefundPendingReceiptContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let {
+    custodian,
+    auditLog,
+    pendingReceipt,
+    receiptPayer,
+    wrappedTmewcToken,
+    wrappedTmewcMint,
+    recipient,
+    recipientWrappedToken,
+    authority,
+  } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (auditLog === undefined) {
+    auditLog = getAuditLogPDA();
+  }
+
+  if (receiptPayer === undefined) {
+    receiptPayer = await program.account.pendingReceipt
+      .fetch(pendingReceipt)
+      .then((data) => data.payer);
+  }
+
+  if (wrappedTmewcToken === undefined) {
+    wrappedTmewcToken = getWrappedTmewcTokenPDA();
+  }
+
+  if (wrappedTmewcMint === undefined) {
+    wrappedTmewcMint = WRAPPED_TMEWC_MINT;
+  }
+
+  if (recipientWrappedToken === undefined) {
+    recipientWrappedToken = getAssociatedTokenAddressSync(
+      wrappedTmewcMint,
+      recipient
+    );
+  }
+
+  return program.methods
+    .refundPendingReceipt()
+    .accounts({
+      custodian,
+      auditLog,
+      pendingReceipt,
+      receiptPayer,
+      wrappedTmewcToken,
+      wrappedTmewcMint,
+      recipient,
+      recipientWrappedToken,
+      authority,
+      eventAuthority: getEventAuthorityPDA(),
+      program: program.programId,
     })
     .instruction();
 }